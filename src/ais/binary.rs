@@ -0,0 +1,223 @@
+//! Binary application messages: addressed (type 6) and broadcast (type 8), with a registry
+//! of application specific decoders selected by DAC/FID.
+
+use std::{any::Any, collections::HashMap, fmt::Debug};
+
+use super::{imo289, Payload, PayloadBuilder};
+
+/// Destination of addressed binary message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Addressee {
+    /// Sequence number (0-3).
+    pub sequence: u32,
+    pub mmsi: u32,
+    /// Message is retransmitted.
+    pub retransmit: bool,
+}
+
+impl Addressee {
+    /// Appends 33 bits of sequence number, destination MMSI and retransmit flag.
+    pub(crate) fn encode(&self, b: &mut PayloadBuilder) {
+        b.unsigned(self.sequence, 2)
+            .unsigned(self.mmsi, 30)
+            .flag(self.retransmit);
+    }
+}
+
+/// Addressed (type 6) or broadcast (type 8) binary message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BinaryMessage {
+    pub repeat: u32,
+    pub mmsi: u32,
+    /// Destination of addressed message, None for broadcast.
+    pub addressee: Option<Addressee>,
+    /// Designated area code (1 - international, otherwise MID of country).
+    pub dac: u32,
+    /// Function identifier.
+    pub fid: u32,
+    /// Application data following FID.
+    pub data: Payload,
+}
+
+impl BinaryMessage {
+    /// Decodes addressed binary message (type 6).
+    pub fn decode_addressed(p: &Payload) -> Option<Self> {
+        Some(BinaryMessage {
+            repeat: p.unsigned(6, 2)?,
+            mmsi: p.unsigned(8, 30)?,
+            addressee: Some(Addressee {
+                sequence: p.unsigned(38, 2)?,
+                mmsi: p.unsigned(40, 30)?,
+                retransmit: p.flag(70)?,
+            }),
+            dac: p.unsigned(72, 10)?,
+            fid: p.unsigned(82, 6)?,
+            data: p.bits_from(88),
+        })
+    }
+
+    /// Decodes broadcast binary message (type 8).
+    pub fn decode_broadcast(p: &Payload) -> Option<Self> {
+        Some(BinaryMessage {
+            repeat: p.unsigned(6, 2)?,
+            mmsi: p.unsigned(8, 30)?,
+            addressee: None,
+            dac: p.unsigned(40, 10)?,
+            fid: p.unsigned(50, 6)?,
+            data: p.bits_from(56),
+        })
+    }
+
+    pub fn encode(&self) -> Payload {
+        let mut b = PayloadBuilder::new();
+        b.unsigned(self.message_type(), 6)
+            .unsigned(self.repeat, 2)
+            .unsigned(self.mmsi, 30);
+        match &self.addressee {
+            Some(addressee) => {
+                addressee.encode(&mut b);
+                b.spare(1);
+            }
+            None => {
+                b.spare(2);
+            }
+        }
+        b.unsigned(self.dac, 10)
+            .unsigned(self.fid, 6)
+            .payload(&self.data)
+            .build()
+    }
+
+    pub fn message_type(&self) -> u32 {
+        if self.addressee.is_some() {
+            6
+        } else {
+            8
+        }
+    }
+}
+
+/// Decoded application data. Use `as_any()` to downcast to concrete type.
+pub trait ApplicationData: Debug {
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// Decoder of application data of a DAC/FID.
+pub type ApplicationDecoder = fn(&Payload) -> Option<Box<dyn ApplicationData>>;
+
+/// Application specific decoders by DAC/FID.
+#[derive(Debug)]
+pub struct ApplicationRegistry {
+    decoders: HashMap<(u32, u32), ApplicationDecoder>,
+}
+
+impl ApplicationRegistry {
+    /// Registry without decoders.
+    pub fn empty() -> Self {
+        ApplicationRegistry {
+            decoders: HashMap::new(),
+        }
+    }
+
+    /// Registry with international applications of IMO SN.1/Circ.289.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        registry.register(1, 31, |p| {
+            Some(Box::new(imo289::MetHydroData::decode(p)?) as Box<dyn ApplicationData>)
+        });
+        registry.register(1, 22, |p| {
+            Some(Box::new(imo289::AreaNotice::decode(p)?) as Box<dyn ApplicationData>)
+        });
+        registry
+    }
+
+    /// Adds or replaces decoder of DAC/FID.
+    pub fn register(&mut self, dac: u32, fid: u32, decoder: ApplicationDecoder) {
+        self.decoders.insert((dac, fid), decoder);
+    }
+
+    pub fn contains(&self, dac: u32, fid: u32) -> bool {
+        self.decoders.contains_key(&(dac, fid))
+    }
+
+    /// Decodes application data. None if DAC/FID is not registered or data is malformed.
+    pub fn decode(&self, msg: &BinaryMessage) -> Option<Box<dyn ApplicationData>> {
+        self.decoders.get(&(msg.dac, msg.fid))?(&msg.data)
+    }
+}
+
+impl Default for ApplicationRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use crate::ais::{decode_armored, AisMessage, Payload};
+
+    use super::{Addressee, ApplicationData, ApplicationRegistry};
+
+    #[test]
+    fn type6() {
+        let Some(AisMessage::BinaryMessage(m)) = decode_armored("6B?n;be:cbapalgc;i6?Ow4", 2)
+        else {
+            panic!()
+        };
+        assert_eq!(m.message_type(), 6);
+        assert_eq!(m.mmsi, 150834090);
+        assert_eq!(
+            m.addressee,
+            Some(Addressee {
+                sequence: 3,
+                mmsi: 313240222,
+                retransmit: false
+            })
+        );
+        assert_eq!((m.dac, m.fid), (669, 11));
+        assert_eq!(m.data.len(), 136 - 88);
+    }
+
+    #[test]
+    fn type8() {
+        let Some(AisMessage::BinaryMessage(m)) =
+            decode_armored("85Mwp`1Kf3aCnsNvBWLi=wQuNhA5t43N`5nCuI=p<IBfVqnMgPGs", 0)
+        else {
+            panic!()
+        };
+        assert_eq!(m.message_type(), 8);
+        assert_eq!(m.mmsi, 366999712);
+        assert_eq!(m.addressee, None);
+        assert_eq!((m.dac, m.fid), (366, 56));
+        assert_eq!(m.data.len(), 312 - 56);
+        assert!(ApplicationRegistry::new().decode(&m).is_none());
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Counter(u32);
+
+    impl ApplicationData for Counter {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn custom_decoder() {
+        let mut registry = ApplicationRegistry::empty();
+        assert!(!registry.contains(366, 56));
+        registry.register(366, 56, |p: &Payload| {
+            Some(Box::new(Counter(p.unsigned(0, 8)?)) as Box<dyn ApplicationData>)
+        });
+        let Some(AisMessage::BinaryMessage(m)) =
+            decode_armored("85Mwp`1Kf3aCnsNvBWLi=wQuNhA5t43N`5nCuI=p<IBfVqnMgPGs", 0)
+        else {
+            panic!()
+        };
+        let data = registry.decode(&m).unwrap();
+        let counter = data.as_any().downcast_ref::<Counter>().unwrap();
+        assert_eq!(*counter, Counter(m.data.unsigned(0, 8).unwrap()));
+    }
+}
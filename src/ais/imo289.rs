@@ -0,0 +1,487 @@
+//! International application data of IMO SN.1/Circ.289: meteorological and hydrographic
+//! data (DAC 1, FID 31) and area notice (DAC 1, FID 22).
+
+use std::any::Any;
+
+use crate::fields::{
+    Distance, DistanceUnit, Pressure, PressureUnit, Speed, SpeedUnit, Temperature, TemperatureUnit,
+};
+
+use super::{ApplicationData, Payload};
+
+/// Position in 1/1000 minutes (25 bits longitude, 24 bits latitude) as (longitude, latitude).
+fn position(p: &Payload, start: usize) -> Option<(Option<f64>, Option<f64>)> {
+    let lon = f64::from(p.signed(start, 25)?) / 60_000.0;
+    let lat = f64::from(p.signed(start + 25, 24)?) / 60_000.0;
+    Some((
+        (lon.abs() <= 180.0).then_some(lon),
+        (lat.abs() <= 90.0).then_some(lat),
+    ))
+}
+
+fn knots(value: u32, scale: f64) -> Speed {
+    Speed::new(f64::from(value) * scale, SpeedUnit::Knots)
+}
+
+fn meters(value: f64) -> Distance {
+    Distance::new(value, DistanceUnit::Meters)
+}
+
+fn celsius(value: i32) -> Temperature {
+    Temperature::new(f64::from(value) / 10.0, TemperatureUnit::Celsius)
+}
+
+fn direction(value: u32) -> Option<u32> {
+    (value < 360).then_some(value)
+}
+
+ais_enum! {
+    /// Tendency of air pressure or water level.
+    pub enum Trend {
+        Steady = 0,
+        Decreasing = 1,
+        Increasing = 2,
+    }
+}
+
+ais_enum! {
+    /// Type of precipitation.
+    pub enum Precipitation {
+        Rain = 1,
+        Thunderstorm = 2,
+        FreezingRain = 3,
+        MixedIce = 4,
+        Snow = 5,
+    }
+}
+
+/// Water current at a level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Current {
+    pub speed: Speed,
+    pub direction: Option<u32>,
+    /// Measurement depth. None for surface current.
+    pub level: Option<Distance>,
+}
+
+/// Waves or swell.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Waves {
+    pub height: Distance,
+    /// Period in seconds.
+    pub period: Option<u32>,
+    pub direction: Option<u32>,
+}
+
+/// Meteorological and hydrographic data (DAC 1, FID 31). Not available values are None.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetHydroData {
+    pub longitude: Option<f64>,
+    pub latitude: Option<f64>,
+    pub position_accuracy: bool,
+    /// UTC day and time of the observation.
+    pub day: Option<u32>,
+    pub hour: Option<u32>,
+    pub minute: Option<u32>,
+    /// Average wind speed of last 10 minutes. 126 knots means 126 knots or higher.
+    pub wind_speed: Option<Speed>,
+    pub wind_gust: Option<Speed>,
+    pub wind_direction: Option<u32>,
+    pub wind_gust_direction: Option<u32>,
+    pub air_temperature: Option<Temperature>,
+    /// Relative humidity in percents.
+    pub humidity: Option<u32>,
+    pub dew_point: Option<Temperature>,
+    pub air_pressure: Option<Pressure>,
+    pub air_pressure_tendency: Option<Trend>,
+    pub visibility: Option<Distance>,
+    /// Visibility is greater than the reported value.
+    pub visibility_greater: bool,
+    /// Water level deviation from the local chart datum.
+    pub water_level: Option<Distance>,
+    pub water_level_trend: Option<Trend>,
+    pub surface_current: Option<Current>,
+    pub current_2: Option<Current>,
+    pub current_3: Option<Current>,
+    pub waves: Option<Waves>,
+    pub swell: Option<Waves>,
+    /// Sea state by Beaufort scale (0-12).
+    pub sea_state: Option<u32>,
+    pub water_temperature: Option<Temperature>,
+    pub precipitation: Option<Precipitation>,
+    /// Salinity in parts per thousand.
+    pub salinity: Option<f64>,
+    pub ice: Option<bool>,
+}
+
+impl MetHydroData {
+    /// Decodes application data (bits after FID).
+    pub fn decode(p: &Payload) -> Option<Self> {
+        if p.len() < 304 {
+            return None;
+        }
+        let (longitude, latitude) = position(p, 0)?;
+        let wind = |start| {
+            p.unsigned(start, 7)
+                .filter(|v| *v < 127)
+                .map(|v| knots(v, 1.0))
+        };
+        let current = |start, level: bool| -> Option<Current> {
+            let speed = p.unsigned(start, 8).filter(|v| *v <= 250)?;
+            let level = match level {
+                true => Some(meters(f64::from(
+                    p.unsigned(start + 17, 5).filter(|v| *v <= 30)?,
+                ))),
+                false => None,
+            };
+            Some(Current {
+                speed: knots(speed, 0.1),
+                direction: direction(p.unsigned(start + 8, 9)?),
+                level,
+            })
+        };
+        let waves = |start| -> Option<Waves> {
+            let height = p.unsigned(start, 8).filter(|v| *v <= 250)?;
+            Some(Waves {
+                height: meters(f64::from(height) / 10.0),
+                period: p.unsigned(start + 8, 6).filter(|v| *v <= 60),
+                direction: direction(p.unsigned(start + 14, 9)?),
+            })
+        };
+        let visibility = p.unsigned(137, 8)?;
+        Some(MetHydroData {
+            longitude,
+            latitude,
+            position_accuracy: p.flag(49)?,
+            day: p.unsigned(50, 5).filter(|d| *d != 0),
+            hour: p.unsigned(55, 5).filter(|h| *h < 24),
+            minute: p.unsigned(60, 6).filter(|m| *m < 60),
+            wind_speed: wind(66),
+            wind_gust: wind(73),
+            wind_direction: direction(p.unsigned(80, 9)?),
+            wind_gust_direction: direction(p.unsigned(89, 9)?),
+            air_temperature: p
+                .signed(98, 11)
+                .filter(|t| (-600..=600).contains(t))
+                .map(celsius),
+            humidity: p.unsigned(109, 7).filter(|h| *h <= 100),
+            dew_point: p
+                .signed(116, 10)
+                .filter(|t| (-200..=500).contains(t))
+                .map(celsius),
+            air_pressure: p
+                .unsigned(126, 9)
+                .filter(|v| *v <= 402)
+                .map(|v| Pressure::new(f64::from(v + 800) / 1000.0, PressureUnit::Bars)),
+            air_pressure_tendency: Trend::from_value(p.unsigned(135, 2)?),
+            visibility: (visibility & 0x7F != 127).then(|| {
+                Distance::new(
+                    f64::from(visibility & 0x7F) / 10.0,
+                    DistanceUnit::NauticalMiles,
+                )
+            }),
+            visibility_greater: visibility & 0x80 != 0,
+            water_level: p
+                .unsigned(145, 12)
+                .filter(|v| *v <= 4000)
+                .map(|v| meters(f64::from(v) / 100.0 - 10.0)),
+            water_level_trend: Trend::from_value(p.unsigned(157, 2)?),
+            surface_current: current(159, false),
+            current_2: current(176, true),
+            current_3: current(198, true),
+            waves: waves(220),
+            swell: waves(243),
+            sea_state: p.unsigned(266, 4).filter(|s| *s <= 12),
+            water_temperature: p
+                .signed(270, 10)
+                .filter(|t| (-100..=500).contains(t))
+                .map(celsius),
+            precipitation: Precipitation::from_value(p.unsigned(280, 3)?),
+            salinity: p
+                .unsigned(283, 9)
+                .filter(|s| *s <= 500)
+                .map(|s| f64::from(s) / 10.0),
+            ice: match p.unsigned(292, 2)? {
+                0 => Some(false),
+                1 => Some(true),
+                _ => None,
+            },
+        })
+    }
+
+    pub fn position(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+}
+
+impl ApplicationData for MetHydroData {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Point of polyline or polygon relative to the previous point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolyPoint {
+    /// Bearing from the previous point in degrees.
+    pub bearing: f64,
+    pub distance: Distance,
+}
+
+/// Sub-area of area notice. Distances are already scaled.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubArea {
+    /// Circle, or point if radius is zero.
+    Circle {
+        longitude: Option<f64>,
+        latitude: Option<f64>,
+        /// Number of decimal places of position minutes (0-4).
+        precision: u32,
+        radius: Distance,
+    },
+    Rectangle {
+        longitude: Option<f64>,
+        latitude: Option<f64>,
+        precision: u32,
+        east: Distance,
+        north: Distance,
+        /// Orientation in degrees.
+        orientation: u32,
+    },
+    Sector {
+        longitude: Option<f64>,
+        latitude: Option<f64>,
+        precision: u32,
+        radius: Distance,
+        left_bound: u32,
+        right_bound: u32,
+    },
+    /// Polyline continuing from the position of the previous sub-area.
+    Polyline(Vec<PolyPoint>),
+    /// Polygon continuing from the position of the previous sub-area.
+    Polygon(Vec<PolyPoint>),
+    /// Text associated with previous sub-areas.
+    Text(String),
+}
+
+const SUB_AREA_BITS: usize = 87;
+
+impl SubArea {
+    fn decode(p: &Payload, start: usize) -> Option<Self> {
+        let scale = [1.0, 10.0, 100.0, 1000.0][p.unsigned(start + 3, 2)? as usize];
+        let scaled = |start, len| Some(meters(f64::from(p.unsigned(start, len)?) * scale));
+        let points = || -> Option<Vec<PolyPoint>> {
+            let mut points = Vec::new();
+            for i in 0..4 {
+                let angle = p.unsigned(start + 5 + i * 20, 10)?;
+                if angle >= 720 {
+                    break;
+                }
+                points.push(PolyPoint {
+                    bearing: f64::from(angle) / 2.0,
+                    distance: scaled(start + 15 + i * 20, 10)?,
+                });
+            }
+            Some(points)
+        };
+        let shape = p.unsigned(start, 3)?;
+        if shape > 2 {
+            return match shape {
+                3 => Some(SubArea::Polyline(points()?)),
+                4 => Some(SubArea::Polygon(points()?)),
+                5 => Some(SubArea::Text(p.text(start + 3, 84)?)),
+                _ => None,
+            };
+        }
+        let (longitude, latitude) = position(p, start + 5)?;
+        let precision = p.unsigned(start + 54, 3)?;
+        match shape {
+            0 => Some(SubArea::Circle {
+                longitude,
+                latitude,
+                precision,
+                radius: scaled(start + 57, 12)?,
+            }),
+            1 => Some(SubArea::Rectangle {
+                longitude,
+                latitude,
+                precision,
+                east: scaled(start + 57, 8)?,
+                north: scaled(start + 65, 8)?,
+                orientation: p.unsigned(start + 73, 9)?,
+            }),
+            _ => Some(SubArea::Sector {
+                longitude,
+                latitude,
+                precision,
+                radius: scaled(start + 57, 12)?,
+                left_bound: p.unsigned(start + 69, 9)?,
+                right_bound: p.unsigned(start + 78, 9)?,
+            }),
+        }
+    }
+}
+
+/// Area notice (DAC 1, FID 22).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AreaNotice {
+    /// Identifier linking related messages.
+    pub linkage_id: u32,
+    /// Notice description code (e.g. 0 - caution area: marine mammals habitat).
+    pub notice_type: u32,
+    /// UTC start date and time.
+    pub month: Option<u32>,
+    pub day: Option<u32>,
+    pub hour: Option<u32>,
+    pub minute: Option<u32>,
+    /// Duration in minutes. None if the notice is valid until cancelled.
+    pub duration: Option<u32>,
+    pub sub_areas: Vec<SubArea>,
+}
+
+impl AreaNotice {
+    /// Decodes application data (bits after FID).
+    pub fn decode(p: &Payload) -> Option<Self> {
+        let mut sub_areas = Vec::new();
+        let mut start = 55;
+        while start + SUB_AREA_BITS <= p.len() {
+            sub_areas.push(SubArea::decode(p, start)?);
+            start += SUB_AREA_BITS;
+        }
+        Some(AreaNotice {
+            linkage_id: p.unsigned(0, 10)?,
+            notice_type: p.unsigned(10, 7)?,
+            month: p.unsigned(17, 4).filter(|m| (1..=12).contains(m)),
+            day: p.unsigned(21, 5).filter(|d| *d != 0),
+            hour: p.unsigned(26, 5).filter(|h| *h < 24),
+            minute: p.unsigned(31, 6).filter(|m| *m < 60),
+            duration: p.unsigned(37, 18).filter(|d| *d != 262_143),
+            sub_areas,
+        })
+    }
+}
+
+impl ApplicationData for AreaNotice {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ais::{decode_armored, AisMessage, ApplicationRegistry},
+        fields::{Distance, DistanceUnit},
+        test_util::assert_near,
+    };
+
+    use super::{AreaNotice, MetHydroData, PolyPoint, Precipitation, SubArea, Trend};
+
+    #[test]
+    fn met_hydro() {
+        let Some(AisMessage::BinaryMessage(m)) = decode_armored(
+            "802R5Ph0GhEbbiaU4ErWQQ9hfH?e0Fnc:b<H6;Cwe7wvlO3iVp:4ga?iuN00",
+            0,
+        ) else {
+            panic!()
+        };
+        assert_eq!((m.dac, m.fid), (1, 31));
+        let data = ApplicationRegistry::new().decode(&m).unwrap();
+        let met = data.as_any().downcast_ref::<MetHydroData>().unwrap();
+        assert_near(met.longitude, 11.8329);
+        assert_near(met.latitude, 57.6603);
+        assert!(met.position_accuracy);
+        assert_eq!(
+            (met.day, met.hour, met.minute),
+            (Some(15), Some(10), Some(30))
+        );
+        assert_eq!(met.wind_speed.unwrap().knots(), 12.0);
+        assert_eq!(met.wind_gust.unwrap().knots(), 18.0);
+        assert_eq!(met.wind_direction, Some(225));
+        assert_eq!(met.wind_gust_direction, Some(230));
+        assert_near(met.air_temperature.map(|t| t.celsius()), 12.5);
+        assert_eq!(met.humidity, Some(80));
+        assert_near(met.dew_point.map(|t| t.celsius()), 9.1);
+        assert_near(met.air_pressure.map(|p| p.pascals()), 101_300.0);
+        assert_eq!(met.air_pressure_tendency, Some(Trend::Increasing));
+        assert_near(
+            met.visibility.map(|v| v.to(DistanceUnit::NauticalMiles)),
+            8.5,
+        );
+        assert!(!met.visibility_greater);
+        assert_near(met.water_level.map(|l| l.meters()), 1.23);
+        assert_eq!(met.water_level_trend, Some(Trend::Steady));
+        let current = met.surface_current.unwrap();
+        assert_near(Some(current.speed.knots()), 1.2);
+        assert_eq!((current.direction, current.level), (Some(180), None));
+        assert_eq!((met.current_2, met.current_3), (None, None));
+        let waves = met.waves.unwrap();
+        assert_near(Some(waves.height.meters()), 1.5);
+        assert_eq!((waves.period, waves.direction), (Some(6), Some(220)));
+        let swell = met.swell.unwrap();
+        assert_near(Some(swell.height.meters()), 2.0);
+        assert_eq!((swell.period, swell.direction), (Some(9), Some(250)));
+        assert_eq!(met.sea_state, Some(4));
+        assert_near(met.water_temperature.map(|t| t.celsius()), -1.5);
+        assert_eq!(met.precipitation, None::<Precipitation>);
+        assert_near(met.salinity, 35.0);
+        assert_eq!(met.ice, Some(false));
+    }
+
+    #[test]
+    fn area_notice() {
+        let Some(AisMessage::BinaryMessage(m)) = decode_armored(
+            "802E3400EP5AeA000?0@9Eb1QJH40j000ait09hI1rA9hp041J6@e38e00;@00",
+            0,
+        ) else {
+            panic!()
+        };
+        assert_eq!((m.dac, m.fid), (1, 22));
+        let data = ApplicationRegistry::new().decode(&m).unwrap();
+        let notice = data.as_any().downcast_ref::<AreaNotice>().unwrap();
+        assert_eq!(notice.linkage_id, 5);
+        assert_eq!(notice.notice_type, 35);
+        assert_eq!(
+            (notice.month, notice.day, notice.hour, notice.minute),
+            (Some(6), Some(20), Some(8), Some(0))
+        );
+        assert_eq!(notice.duration, Some(120));
+        assert_eq!(notice.sub_areas.len(), 3);
+        let SubArea::Circle {
+            longitude,
+            latitude,
+            precision,
+            radius,
+        } = notice.sub_areas[0]
+        else {
+            panic!()
+        };
+        assert_near(longitude, 5.1);
+        assert_near(latitude, 53.2);
+        assert_eq!(precision, 4);
+        assert_eq!(radius, Distance::new(500.0, DistanceUnit::Meters));
+        assert_eq!(notice.sub_areas[1], SubArea::Text("NO ANCHORING".into()));
+        assert_eq!(
+            notice.sub_areas[2],
+            SubArea::Polygon(vec![
+                PolyPoint {
+                    bearing: 45.0,
+                    distance: Distance::new(100.0, DistanceUnit::Meters)
+                },
+                PolyPoint {
+                    bearing: 90.0,
+                    distance: Distance::new(200.0, DistanceUnit::Meters)
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        let Some(AisMessage::BinaryMessage(m)) = decode_armored("802R5Ph0GhEbbiaU4ErWQQ9h", 0)
+        else {
+            panic!()
+        };
+        assert!(ApplicationRegistry::new().decode(&m).is_none());
+    }
+}
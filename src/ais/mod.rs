@@ -0,0 +1,585 @@
+//! AIS messages encapsulated in VDM/VDO sentences: 6-bit payload decoding into typed reports.
+
+/// Declares enum of AIS numeric codes with `from_value` and `value` conversions.
+/// Codes not listed (reserved, not available) convert to None.
+macro_rules! ais_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$vmeta:meta])* $variant:ident = $value:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$vmeta])* $variant,)+
+        }
+
+        impl $name {
+            pub fn from_value(value: u32) -> Option<Self> {
+                match value {
+                    $($value => Some($name::$variant),)+
+                    _ => None,
+                }
+            }
+
+            pub fn value(&self) -> u32 {
+                match self {
+                    $($name::$variant => $value,)+
+                }
+            }
+        }
+    };
+}
+
+mod binary;
+mod imo289;
+mod payload;
+mod position;
+mod safety;
+mod static_data;
+mod stations;
+mod transmit;
+mod vessels;
+
+pub use binary::{
+    Addressee, ApplicationData, ApplicationDecoder, ApplicationRegistry, BinaryMessage,
+};
+pub use imo289::{
+    AreaNotice, Current, MetHydroData, PolyPoint, Precipitation, SubArea, Trend, Waves,
+};
+pub use payload::{Payload, PayloadBuilder};
+pub use position::{
+    ClassBExtendedReport, ClassBPositionReport, LongRangePositionReport, NavigationStatus,
+    PositionReport, RateOfTurn,
+};
+pub use safety::{AcknowledgedMessage, Acknowledgement, SafetyMessage};
+pub use static_data::{
+    Dimensions, EpfdType, Eta, ShipType, StaticDataPart, StaticDataReport, StaticVoyageData,
+};
+pub use stations::{AtonReport, AtonType, BaseStationReport, SarAircraftReport};
+pub use transmit::{Transmission, TransmitRequest, TransmitResult, TransmitTracker};
+pub use vessels::{AisTarget, AisVesselTable, StationClass, TargetEvent};
+
+use std::collections::HashMap;
+
+use crate::{
+    encode_sentence,
+    generated::nmea3::{NmeaVdmMessage, NmeaVdoMessage},
+    EXCLAMATION,
+};
+
+/// Decoded AIS message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AisMessage {
+    /// Class A position report (types 1, 2, 3).
+    PositionReport(PositionReport),
+    /// Standard class B position report (type 18).
+    ClassBPositionReport(ClassBPositionReport),
+    /// Extended class B position report (type 19).
+    ClassBExtendedReport(ClassBExtendedReport),
+    /// Static and voyage related data (type 5).
+    StaticVoyageData(StaticVoyageData),
+    /// Class B static data report, part A or B (type 24).
+    StaticDataReport(StaticDataReport),
+    /// Base station report (type 4) or UTC and date response (type 11).
+    BaseStationReport(BaseStationReport),
+    /// Standard SAR aircraft position report (type 9).
+    SarAircraftReport(SarAircraftReport),
+    /// Aid-to-navigation report (type 21).
+    AtonReport(AtonReport),
+    /// Addressed (type 6) or broadcast (type 8) binary message.
+    BinaryMessage(BinaryMessage),
+    /// Binary (type 7) or safety related (type 13) acknowledge.
+    Acknowledgement(Acknowledgement),
+    /// Addressed (type 12) or broadcast (type 14) safety related text message.
+    SafetyMessage(SafetyMessage),
+    /// Long-range position report (type 27).
+    LongRangePositionReport(LongRangePositionReport),
+}
+
+impl AisMessage {
+    /// Message type (1-27).
+    pub fn message_type(&self) -> u32 {
+        match self {
+            AisMessage::PositionReport(m) => m.message_type,
+            AisMessage::ClassBPositionReport(_) => 18,
+            AisMessage::ClassBExtendedReport(_) => 19,
+            AisMessage::StaticVoyageData(_) => 5,
+            AisMessage::StaticDataReport(_) => 24,
+            AisMessage::BaseStationReport(m) => m.message_type,
+            AisMessage::SarAircraftReport(_) => 9,
+            AisMessage::AtonReport(_) => 21,
+            AisMessage::BinaryMessage(m) => m.message_type(),
+            AisMessage::Acknowledgement(m) => m.message_type,
+            AisMessage::SafetyMessage(m) => m.message_type(),
+            AisMessage::LongRangePositionReport(_) => 27,
+        }
+    }
+
+    /// MMSI of transmitting station.
+    pub fn mmsi(&self) -> u32 {
+        match self {
+            AisMessage::PositionReport(m) => m.mmsi,
+            AisMessage::ClassBPositionReport(m) => m.mmsi,
+            AisMessage::ClassBExtendedReport(m) => m.mmsi,
+            AisMessage::StaticVoyageData(m) => m.mmsi,
+            AisMessage::StaticDataReport(m) => m.mmsi,
+            AisMessage::BaseStationReport(m) => m.mmsi,
+            AisMessage::SarAircraftReport(m) => m.mmsi,
+            AisMessage::AtonReport(m) => m.mmsi,
+            AisMessage::BinaryMessage(m) => m.mmsi,
+            AisMessage::Acknowledgement(m) => m.mmsi,
+            AisMessage::SafetyMessage(m) => m.mmsi,
+            AisMessage::LongRangePositionReport(m) => m.mmsi,
+        }
+    }
+
+    /// Encodes message into binary payload.
+    pub fn encode(&self) -> Payload {
+        match self {
+            AisMessage::PositionReport(m) => m.encode(),
+            AisMessage::ClassBPositionReport(m) => m.encode(),
+            AisMessage::ClassBExtendedReport(m) => m.encode(),
+            AisMessage::StaticVoyageData(m) => m.encode(),
+            AisMessage::StaticDataReport(m) => m.encode(),
+            AisMessage::BaseStationReport(m) => m.encode(),
+            AisMessage::SarAircraftReport(m) => m.encode(),
+            AisMessage::AtonReport(m) => m.encode(),
+            AisMessage::BinaryMessage(m) => m.encode(),
+            AisMessage::Acknowledgement(m) => m.encode(),
+            AisMessage::SafetyMessage(m) => m.encode(),
+            AisMessage::LongRangePositionReport(m) => m.encode(),
+        }
+    }
+}
+
+/// Decodes AIS message from payload. None for unsupported types and malformed payloads.
+pub fn decode(payload: &Payload) -> Option<AisMessage> {
+    match payload.unsigned(0, 6)? {
+        1..=3 => PositionReport::decode(payload).map(AisMessage::PositionReport),
+        18 => ClassBPositionReport::decode(payload).map(AisMessage::ClassBPositionReport),
+        19 => ClassBExtendedReport::decode(payload).map(AisMessage::ClassBExtendedReport),
+        5 => StaticVoyageData::decode(payload).map(AisMessage::StaticVoyageData),
+        24 => StaticDataReport::decode(payload).map(AisMessage::StaticDataReport),
+        4 | 11 => BaseStationReport::decode(payload).map(AisMessage::BaseStationReport),
+        9 => SarAircraftReport::decode(payload).map(AisMessage::SarAircraftReport),
+        21 => AtonReport::decode(payload).map(AisMessage::AtonReport),
+        6 => BinaryMessage::decode_addressed(payload).map(AisMessage::BinaryMessage),
+        8 => BinaryMessage::decode_broadcast(payload).map(AisMessage::BinaryMessage),
+        7 | 13 => Acknowledgement::decode(payload).map(AisMessage::Acknowledgement),
+        12 => SafetyMessage::decode_addressed(payload).map(AisMessage::SafetyMessage),
+        14 => SafetyMessage::decode_broadcast(payload).map(AisMessage::SafetyMessage),
+        27 => LongRangePositionReport::decode(payload).map(AisMessage::LongRangePositionReport),
+        _ => None,
+    }
+}
+
+/// Decodes armoured payload with fill bits, as it is found in VDM/VDO sentences.
+pub fn decode_armored(payload: &str, fill_bits: u32) -> Option<AisMessage> {
+    decode(&Payload::from_armored(payload, fill_bits)?)
+}
+
+/// VDM/VDO sentence fields of a message fragment.
+#[derive(Debug)]
+struct Fragment<'a> {
+    /// Own vessel message (VDO).
+    own: bool,
+    count: u32,
+    number: u32,
+    sequence_id: Option<u32>,
+    channel: Option<&'a str>,
+    payload: &'a str,
+    fill_bits: u32,
+}
+
+impl Fragment<'_> {
+    /// Decodes single fragment message.
+    fn decode(&self) -> Option<AisMessage> {
+        if self.count != 1 {
+            return None;
+        }
+        decode_armored(self.payload, self.fill_bits)
+    }
+}
+
+impl NmeaVdmMessage {
+    fn fragment(&self) -> Option<Fragment<'_>> {
+        Some(Fragment {
+            own: false,
+            count: self.fragment_count.unwrap_or(1),
+            number: self.fragment_number.unwrap_or(1),
+            sequence_id: self.sequence_id,
+            channel: self.channel.as_deref(),
+            payload: self.payload.as_deref()?,
+            fill_bits: self.fill_bits.unwrap_or(0),
+        })
+    }
+
+    /// True if whole AIS message is in this sentence.
+    pub fn is_single_fragment(&self) -> bool {
+        self.fragment_count.unwrap_or(1) == 1
+    }
+
+    /// Decodes single fragment message. Use [FragmentAssembler] for multi-fragment messages.
+    pub fn decode(&self) -> Option<AisMessage> {
+        self.fragment()?.decode()
+    }
+}
+
+impl NmeaVdoMessage {
+    fn fragment(&self) -> Option<Fragment<'_>> {
+        Some(Fragment {
+            own: true,
+            count: self.fragment_count.unwrap_or(1),
+            number: self.fragment_number.unwrap_or(1),
+            sequence_id: self.sequence_id,
+            channel: self.channel.as_deref(),
+            payload: self.payload.as_deref()?,
+            fill_bits: self.fill_bits.unwrap_or(0),
+        })
+    }
+
+    pub fn is_single_fragment(&self) -> bool {
+        self.fragment_count.unwrap_or(1) == 1
+    }
+
+    /// Decodes single fragment own-vessel message.
+    pub fn decode(&self) -> Option<AisMessage> {
+        self.fragment()?.decode()
+    }
+}
+
+/// Pending multi-fragment payload.
+#[derive(Debug)]
+struct PendingPayload {
+    payload: String,
+    next_fragment: u32,
+}
+
+/// Assembles AIS payloads from multi-fragment VDM/VDO groups.
+/// Groups with different sequential message ids or channels may be interleaved.
+#[derive(Debug, Default)]
+pub struct FragmentAssembler {
+    pending: HashMap<(bool, Option<u32>, Option<String>), PendingPayload>,
+}
+
+impl FragmentAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds VDM fragment. Returns payload when the last fragment is received.
+    /// Fragments received out of order drop the payload being assembled.
+    pub fn update(&mut self, vdm: &NmeaVdmMessage) -> Option<Payload> {
+        self.add(vdm.fragment()?)
+    }
+
+    /// Adds own vessel VDO fragment.
+    pub fn update_vdo(&mut self, vdo: &NmeaVdoMessage) -> Option<Payload> {
+        self.add(vdo.fragment()?)
+    }
+
+    fn add(&mut self, fragment: Fragment<'_>) -> Option<Payload> {
+        if fragment.count == 1 {
+            return Payload::from_armored(fragment.payload, fragment.fill_bits);
+        }
+        let key = (
+            fragment.own,
+            fragment.sequence_id,
+            fragment.channel.map(str::to_string),
+        );
+        if fragment.number == 1 {
+            self.pending.insert(
+                key.clone(),
+                PendingPayload {
+                    payload: String::new(),
+                    next_fragment: 1,
+                },
+            );
+        }
+        let pending = self.pending.get_mut(&key)?;
+        if pending.next_fragment != fragment.number {
+            self.pending.remove(&key);
+            return None;
+        }
+        pending.payload.push_str(fragment.payload);
+        pending.next_fragment += 1;
+
+        if fragment.number >= fragment.count {
+            let pending = self.pending.remove(&key)?;
+            return Payload::from_armored(&pending.payload, fragment.fill_bits);
+        }
+        None
+    }
+}
+
+/// Maximum armoured payload chars of a fragment, which keeps sentences within 82 chars.
+const MAX_FRAGMENT_CHARS: usize = 60;
+
+/// Encodes AIS messages into VDM/VDO sentences. Payloads not fitting into one sentence
+/// are split into fragments with sequential message ids cycling from 0 to 9.
+#[derive(Debug, Clone)]
+pub struct SentenceEncoder {
+    talker_id: String,
+    own_vessel: bool,
+    channel: Option<String>,
+    next_sequence_id: u32,
+}
+
+impl SentenceEncoder {
+    /// Encoder of `!AIVDM` sentences on channel A.
+    pub fn new() -> Self {
+        SentenceEncoder {
+            talker_id: "AI".to_string(),
+            own_vessel: false,
+            channel: Some("A".to_string()),
+            next_sequence_id: 0,
+        }
+    }
+
+    pub fn with_talker_id(mut self, talker_id: &str) -> Self {
+        self.talker_id = talker_id.to_string();
+        self
+    }
+
+    /// Encodes own vessel messages into VDO sentences.
+    pub fn own_vessel(mut self) -> Self {
+        self.own_vessel = true;
+        self
+    }
+
+    /// Sets AIS channel (A, B, 1 or 2). None leaves the field empty.
+    pub fn with_channel(mut self, channel: Option<&str>) -> Self {
+        self.channel = channel.map(str::to_string);
+        self
+    }
+
+    pub fn encode(&mut self, msg: &AisMessage) -> Vec<String> {
+        self.encode_payload(&msg.encode())
+    }
+
+    /// Encodes payload into one or more sentences. Fill bits are set in the last fragment.
+    pub fn encode_payload(&mut self, payload: &Payload) -> Vec<String> {
+        let (armored, fill_bits) = payload.to_armored();
+        let mut fragments: Vec<&str> = armored
+            .as_bytes()
+            .chunks(MAX_FRAGMENT_CHARS)
+            .map(|chunk| std::str::from_utf8(chunk).unwrap())
+            .collect();
+        if fragments.is_empty() {
+            fragments.push("");
+        }
+        let count = fragments.len();
+        let sequence_id = if count > 1 {
+            let id = self.next_sequence_id;
+            self.next_sequence_id = (id + 1) % 10;
+            id.to_string()
+        } else {
+            String::new()
+        };
+        let addr = format!(
+            "{}{}",
+            self.talker_id,
+            if self.own_vessel { "VDO" } else { "VDM" }
+        );
+        let channel = self.channel.as_deref().unwrap_or("");
+        fragments
+            .iter()
+            .enumerate()
+            .map(|(i, fragment)| {
+                let fill_bits = if i + 1 == count { fill_bits } else { 0 };
+                let fields = [
+                    count.to_string(),
+                    (i + 1).to_string(),
+                    sequence_id.clone(),
+                    channel.to_string(),
+                    fragment.to_string(),
+                    fill_bits.to_string(),
+                ];
+                encode_sentence(EXCLAMATION, &addr, &fields)
+            })
+            .collect()
+    }
+}
+
+impl Default for SentenceEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generated::nmea3::{NmeaVdmMessage, NmeaVdoMessage},
+        test_util::parse,
+    };
+
+    use super::{decode, decode_armored, AisMessage, FragmentAssembler, Payload, SentenceEncoder};
+
+    #[test]
+    fn vdm_single_fragment() {
+        let vdm: NmeaVdmMessage = parse("!AIVDM,1,1,,B,15M67FC000G?ufbE`FepT@3n00Sa,0*5C");
+        assert_eq!(vdm.channel.as_deref(), Some("B"));
+        assert_eq!(vdm.sequence_id, None);
+        let msg = vdm.decode().unwrap();
+        assert_eq!(msg.message_type(), 1);
+        assert_eq!(msg.mmsi(), 366053209);
+    }
+
+    #[test]
+    fn vdo_own_vessel() {
+        let vdo: NmeaVdoMessage = parse("!AIVDO,1,1,,,B52K>;h00Fc>jpUlNV@ikwpUoP06,0*0F");
+        let msg = vdo.decode().unwrap();
+        assert!(matches!(msg, AisMessage::ClassBPositionReport(_)));
+        assert_eq!(msg.mmsi(), 338087471);
+    }
+
+    #[test]
+    fn multi_fragment_is_not_decoded() {
+        let vdm: NmeaVdmMessage =
+            parse("!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E");
+        assert!(!vdm.is_single_fragment());
+        assert_eq!(vdm.decode(), None);
+    }
+
+    #[test]
+    fn unsupported_and_malformed() {
+        assert_eq!(decode_armored("?", 0), None);
+        // Position report truncated to 27 chars
+        assert_eq!(decode_armored("15M67FC000G?ufbE`FepT@3n00S", 0), None);
+    }
+
+    #[test]
+    fn two_fragments() {
+        let mut assembler = FragmentAssembler::new();
+        let part1: NmeaVdmMessage = parse(
+            "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+        );
+        let part2: NmeaVdmMessage = parse("!AIVDM,2,2,1,A,88888888880,2*25");
+        let position: NmeaVdmMessage = parse("!AIVDM,1,1,,B,15M67FC000G?ufbE`FepT@3n00Sa,0*5C");
+
+        assert_eq!(assembler.update(&part1), None);
+        assert_eq!(assembler.update(&position).unwrap().len(), 168);
+        let payload = assembler.update(&part2).unwrap();
+        assert_eq!(payload.len(), 424);
+        let Some(AisMessage::StaticVoyageData(m)) = decode(&payload) else {
+            panic!()
+        };
+        assert_eq!(m.name, "EVER DIADEM");
+    }
+
+    #[test]
+    fn interleaved_and_out_of_order_fragments() {
+        let mut assembler = FragmentAssembler::new();
+        let a1: NmeaVdmMessage = parse(
+            "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+        );
+        let a2: NmeaVdmMessage = parse("!AIVDM,2,2,1,A,88888888880,2*25");
+        let b1: NmeaVdmMessage =
+            parse("!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E");
+        let b2: NmeaVdmMessage = parse("!AIVDM,2,2,3,B,1@0000000000000,2*55");
+
+        assert_eq!(assembler.update(&a1), None);
+        assert_eq!(assembler.update(&b1), None);
+        let b = decode(&assembler.update(&b2).unwrap()).unwrap();
+        assert_eq!(b.mmsi(), 369190000);
+        let a = decode(&assembler.update(&a2).unwrap()).unwrap();
+        assert_eq!(a.mmsi(), 351759000);
+
+        // Second fragment without the first one
+        assert_eq!(assembler.update(&a2), None);
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        // Encoded payload is identical to the received one
+        let samples = [
+            ("15M67FC000G?ufbE`FepT@3n00Sa", 0),
+            ("13u?etPv2;0n:dDPwUM1U1Cb069D", 0),
+            ("B52K>;h00Fc>jpUlNV@ikwpUoP06", 0),
+            ("H42O55i18tMET00000000000000", 2),
+            ("H42O55lti4hhhilD3nink000?050", 0),
+            ("403OviQuMGCqWrRO9>E6fE700@GO", 0),
+            ("91b55wi;hbOS@OdQAC062Ch2089h", 0),
+            ("6B?n;be:cbapalgc;i6?Ow4", 2),
+            ("85Mwp`1Kf3aCnsNvBWLi=wQuNhA5t43N`5nCuI=p<IBfVqnMgPGs", 0),
+            ("702R5`hwCjq8", 0),
+            ("=39UOj0jFs9R", 0),
+            ("<5?SIj1;GbD07??4", 0),
+            (">5?Per18=HB1U:1@E=B0m<L", 2),
+            ("KC5E2b@U19PFdLbL", 0),
+        ];
+        for (armored, fill_bits) in samples {
+            let msg = decode_armored(armored, fill_bits).unwrap();
+            let payload = msg.encode();
+            assert_eq!(
+                payload.to_armored(),
+                (armored.to_string(), fill_bits),
+                "{armored}"
+            );
+            assert_eq!(decode(&payload), Some(msg));
+        }
+
+        // Set reserved bits, text padded with spaces, or name extension without byte alignment
+        let samples = [
+            ("C5N3SRgPEnJGEBT>NhWAwwo862PaLELTBJ:V00000000S0D:R220", 0),
+            (
+                "55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp888888888880",
+                2,
+            ),
+            ("E>jHC=c6:W2h22R`@1:WdP00000Opa@H?KTcP00000000002", 4),
+            ("E>jQMtTW7a:4@192PUcPb2a@64SwuKL8>Ms9000003vh1258<LS@", 4),
+        ];
+        for (armored, fill_bits) in samples {
+            let msg = decode_armored(armored, fill_bits).unwrap();
+            assert_eq!(decode(&msg.encode()), Some(msg), "{armored}");
+        }
+    }
+
+    #[test]
+    fn encode_sentences() {
+        let mut encoder = SentenceEncoder::new().own_vessel().with_channel(None);
+        let msg = decode_armored("B52K>;h00Fc>jpUlNV@ikwpUoP06", 0).unwrap();
+        assert_eq!(
+            encoder.encode(&msg),
+            vec!["!AIVDO,1,1,,,B52K>;h00Fc>jpUlNV@ikwpUoP06,0*0F\r\n"]
+        );
+
+        let mut encoder = SentenceEncoder::new();
+        let msg = decode_armored(
+            "55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp888888888880",
+            2,
+        )
+        .unwrap();
+        let mut assembler = FragmentAssembler::new();
+        for sequence_id in [0, 1] {
+            let sentences = encoder.encode(&msg);
+            assert_eq!(sentences.len(), 2);
+            let first: NmeaVdmMessage = parse(&sentences[0]);
+            assert_eq!(
+                (
+                    first.fragment_count,
+                    first.fragment_number,
+                    first.sequence_id
+                ),
+                (Some(2), Some(1), Some(sequence_id))
+            );
+            assert_eq!(first.channel.as_deref(), Some("A"));
+            assert_eq!(first.payload.as_ref().map(String::len), Some(60));
+            assert_eq!(first.fill_bits, Some(0));
+            let second: NmeaVdmMessage = parse(&sentences[1]);
+            assert_eq!(second.fill_bits, Some(2));
+            assert!(sentences.iter().all(|s| s.len() <= 82));
+
+            assert_eq!(assembler.update(&first), None);
+            let payload = assembler.update(&second).unwrap();
+            assert_eq!(decode(&payload).as_ref(), Some(&msg));
+        }
+
+        // Single fragment has no sequential message id
+        let sentences = encoder.encode_payload(&Payload::from_armored("702R5`hwCjq8", 0).unwrap());
+        assert_eq!(sentences, vec!["!AIVDM,1,1,,A,702R5`hwCjq8,0*6B\r\n"]);
+    }
+}
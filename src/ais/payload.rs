@@ -0,0 +1,269 @@
+//! AIS 6-bit armoured payload: (de)armouring, bit field access and building.
+
+/// Decodes armoured payload char into 6-bit value.
+fn dearmor(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'W' => Some(c - b'0'),
+        b'`'..=b'w' => Some(c - b'0' - 8),
+        _ => None,
+    }
+}
+
+/// Encodes 6-bit value into armoured payload char.
+fn armor(v: u8) -> char {
+    char::from(if v < 40 { v + b'0' } else { v + b'0' + 8 })
+}
+
+/// Encodes char into 6-bit ASCII value. Lowercase letters are converted to uppercase,
+/// unsupported chars are replaced with '?'.
+fn sixbit_value(c: char) -> u8 {
+    match c.to_ascii_uppercase() {
+        c @ ' '..='?' => c as u8,
+        c @ '@'..='_' => c as u8 - 64,
+        _ => b'?',
+    }
+}
+
+/// Decodes 6-bit ASCII value into a char.
+fn sixbit_char(v: u8) -> char {
+    char::from(if v < 32 { v + 64 } else { v })
+}
+
+/// Binary AIS message. Bits are numbered from 0, most significant bit first.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Payload {
+    /// 6-bit values.
+    data: Vec<u8>,
+    /// Number of valid bits.
+    len: usize,
+}
+
+impl Payload {
+    /// Decodes armoured payload of VDM/VDO sentences. `fill_bits` are dropped from the end.
+    /// Returns None if payload has invalid chars.
+    pub fn from_armored(payload: &str, fill_bits: u32) -> Option<Self> {
+        let mut data = payload.bytes().map(dearmor).collect::<Option<Vec<u8>>>()?;
+        let fill_bits = fill_bits.min(5);
+        let len = (data.len() * 6).saturating_sub(fill_bits as usize);
+        // Clear fill bits, so payloads with equal bits compare equal
+        if let Some(last) = data.last_mut() {
+            *last &= 0x3F << fill_bits;
+        }
+        Some(Payload { data, len })
+    }
+
+    /// Encodes payload into armoured string and number of fill bits for VDM/VDO sentences.
+    pub fn to_armored(&self) -> (String, u32) {
+        let payload = self.data.iter().map(|v| armor(*v)).collect();
+        (payload, (self.data.len() * 6 - self.len) as u32)
+    }
+
+    /// Number of bits in payload.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn bit(&self, idx: usize) -> bool {
+        self.data[idx / 6] & (0x20 >> (idx % 6)) != 0
+    }
+
+    /// Unsigned value of `len` bits (up to 32) starting at `start`. None if payload is too short.
+    pub fn unsigned(&self, start: usize, len: usize) -> Option<u32> {
+        debug_assert!(len <= 32);
+        if start + len > self.len {
+            return None;
+        }
+        Some((start..start + len).fold(0u32, |v, i| (v << 1) | u32::from(self.bit(i))))
+    }
+
+    /// Two's complement signed value of `len` bits.
+    pub fn signed(&self, start: usize, len: usize) -> Option<i32> {
+        let v = self.unsigned(start, len)?;
+        let shift = 32 - len as u32;
+        Some(((v << shift) as i32) >> shift)
+    }
+
+    pub fn flag(&self, start: usize) -> Option<bool> {
+        Some(self.unsigned(start, 1)? == 1)
+    }
+
+    /// Payload with bits from `start` to the end (e.g. application data of binary messages).
+    pub fn bits_from(&self, start: usize) -> Payload {
+        let len = self.len.saturating_sub(start);
+        let mut data = vec![0u8; len.div_ceil(6)];
+        for i in 0..len {
+            if self.bit(start + i) {
+                data[i / 6] |= 0x20 >> (i % 6);
+            }
+        }
+        Payload { data, len }
+    }
+
+    /// 6-bit ASCII text of `len` bits. Trailing `@` padding and spaces are trimmed.
+    /// Text truncated by the payload end is decoded up to the last complete char.
+    pub fn text(&self, start: usize, len: usize) -> Option<String> {
+        let end = (start + len).min(self.len);
+        if start + 6 > end {
+            return None;
+        }
+        let text: String = (start..end - 5)
+            .step_by(6)
+            .map(|i| sixbit_char(self.unsigned(i, 6).unwrap() as u8))
+            .collect();
+        let text = text.trim_end_matches(['@', ' ']);
+        let text = match text.find('@') {
+            Some(idx) => &text[..idx],
+            None => text,
+        };
+        Some(text.to_string())
+    }
+}
+
+/// Builds payload by appending bit fields in transmission order. Values are truncated to
+/// field length.
+#[derive(Debug, Clone, Default)]
+pub struct PayloadBuilder {
+    payload: Payload,
+}
+
+impl PayloadBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(&mut self, bit: bool) {
+        let p = &mut self.payload;
+        if p.len.is_multiple_of(6) {
+            p.data.push(0);
+        }
+        if bit {
+            p.data[p.len / 6] |= 0x20 >> (p.len % 6);
+        }
+        p.len += 1;
+    }
+
+    /// Appends unsigned value of `len` bits (up to 32).
+    pub fn unsigned(&mut self, value: u32, len: usize) -> &mut Self {
+        for i in (0..len).rev() {
+            self.push(i < 32 && value >> i & 1 == 1);
+        }
+        self
+    }
+
+    /// Appends two's complement signed value of `len` bits.
+    pub fn signed(&mut self, value: i32, len: usize) -> &mut Self {
+        self.unsigned(value as u32, len)
+    }
+
+    pub fn flag(&mut self, value: bool) -> &mut Self {
+        self.push(value);
+        self
+    }
+
+    /// Appends zero spare bits.
+    pub fn spare(&mut self, len: usize) -> &mut Self {
+        self.unsigned(0, len)
+    }
+
+    /// Appends 6-bit ASCII text of `len` bits, truncated or padded with `@`.
+    pub fn text(&mut self, text: &str, len: usize) -> &mut Self {
+        let mut chars = text.chars();
+        for _ in 0..len / 6 {
+            self.unsigned(u32::from(chars.next().map_or(0, sixbit_value)), 6);
+        }
+        self
+    }
+
+    /// Appends all bits of other payload.
+    pub fn payload(&mut self, payload: &Payload) -> &mut Self {
+        for i in 0..payload.len {
+            self.push(payload.bit(i));
+        }
+        self
+    }
+
+    /// Number of bits appended so far.
+    pub fn len(&self) -> usize {
+        self.payload.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.payload.len == 0
+    }
+
+    pub fn build(&self) -> Payload {
+        self.payload.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Payload, PayloadBuilder};
+
+    #[test]
+    fn bit_fields() {
+        let p = Payload::from_armored("15M67FC000G?ufbE`FepT@3n00Sa", 0).unwrap();
+        assert_eq!(p.len(), 168);
+        assert_eq!(p.unsigned(0, 6), Some(1));
+        assert_eq!(p.unsigned(8, 30), Some(366053209));
+        assert_eq!(p.signed(61, 28), Some(-73404971));
+        assert_eq!(p.flag(148), Some(false));
+        assert_eq!(p.unsigned(160, 8), Some(0xE9));
+        assert_eq!(p.unsigned(161, 8), None);
+
+        let tail = p.bits_from(160);
+        assert_eq!(tail.len(), 8);
+        assert_eq!(tail.unsigned(0, 8), Some(0xE9));
+        assert!(p.bits_from(200).is_empty());
+    }
+
+    #[test]
+    fn fill_bits() {
+        let p = Payload::from_armored("w7", 2).unwrap();
+        assert_eq!(p.len(), 10);
+        assert_eq!(p.unsigned(0, 10), Some(0b11_1111_0001));
+        assert_eq!(p.unsigned(0, 11), None);
+        assert_eq!(Some(p), Payload::from_armored("w4", 2));
+        assert!(Payload::from_armored("1x", 0).is_none());
+        assert!(Payload::from_armored("", 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn text() {
+        // "CAPT.J.RIMES" followed by '@' padding in AIS message 19
+        let p = Payload::from_armored("C5N3SRgPEnJGEBT>NhWAwwo862PaLELTBJ:V00000000S0D:R220", 0)
+            .unwrap();
+        assert_eq!(p.text(143, 120).as_deref(), Some("CAPT.J.RIMES"));
+        assert_eq!(p.text(300, 120).as_deref(), Some("B"));
+        assert_eq!(p.text(310, 6), None);
+    }
+
+    #[test]
+    fn build() {
+        let p = PayloadBuilder::new()
+            .unsigned(1, 6)
+            .spare(2)
+            .unsigned(366053209, 30)
+            .signed(-73404971, 28)
+            .flag(true)
+            .text("ab@", 18)
+            .build();
+        assert_eq!(p.len(), 85);
+        assert_eq!(p.unsigned(8, 30), Some(366053209));
+        assert_eq!(p.signed(38, 28), Some(-73404971));
+        assert_eq!(p.flag(66), Some(true));
+        assert_eq!(p.text(67, 18).as_deref(), Some("AB"));
+        let (armored, fill) = p.to_armored();
+        assert_eq!((armored.len(), fill), (15, 5));
+        assert_eq!(Payload::from_armored(&armored, fill), Some(p));
+
+        let armored = "15M67FC000G?ufbE`FepT@3n00Sa";
+        let p = Payload::from_armored(armored, 0).unwrap();
+        let copy = PayloadBuilder::new().payload(&p).build();
+        assert_eq!(copy.to_armored(), (armored.to_string(), 0));
+    }
+}
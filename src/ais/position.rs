@@ -0,0 +1,581 @@
+//! Position reports of class A (types 1, 2, 3) and class B (types 18, 19) stations, and
+//! long-range position reports (type 27).
+
+use crate::fields::{Speed, SpeedUnit};
+
+use super::{Dimensions, EpfdType, Payload, PayloadBuilder, ShipType};
+
+ais_enum! {
+    /// Navigational status of class A station.
+    pub enum NavigationStatus {
+        UnderWayUsingEngine = 0,
+        AtAnchor = 1,
+        NotUnderCommand = 2,
+        RestrictedManoeuvrability = 3,
+        ConstrainedByDraught = 4,
+        Moored = 5,
+        Aground = 6,
+        EngagedInFishing = 7,
+        UnderWaySailing = 8,
+        /// AIS-SART, MOB-AIS or EPIRB-AIS active.
+        AisSartActive = 14,
+    }
+}
+
+/// Rate of turn decoded from its non-linear AIS encoding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RateOfTurn {
+    /// Degrees per minute from turn indicator, positive to starboard. Up to 708 °/min.
+    Rate(f64),
+    /// Turning to starboard at more than 5° per 30 s, no turn indicator available.
+    FastStarboard,
+    /// Turning to port at more than 5° per 30 s, no turn indicator available.
+    FastPort,
+}
+
+impl RateOfTurn {
+    /// Decodes ROT field (ROTais = 4.733 * sqrt(ROTsensor)). None if not available.
+    pub fn from_raw(raw: i32) -> Option<Self> {
+        match raw {
+            -128 => None,
+            127 => Some(RateOfTurn::FastStarboard),
+            -127 => Some(RateOfTurn::FastPort),
+            r => {
+                let rate = (f64::from(r) / 4.733).powi(2);
+                Some(RateOfTurn::Rate(if r < 0 { -rate } else { rate }))
+            }
+        }
+    }
+
+    /// Encodes ROT field, -128 if not available.
+    pub fn to_raw(rot: Option<Self>) -> i32 {
+        match rot {
+            None => -128,
+            Some(RateOfTurn::FastStarboard) => 127,
+            Some(RateOfTurn::FastPort) => -127,
+            Some(RateOfTurn::Rate(rate)) => {
+                let raw = (4.733 * rate.abs().sqrt()).round().min(126.0) as i32;
+                if rate < 0.0 {
+                    -raw
+                } else {
+                    raw
+                }
+            }
+        }
+    }
+}
+
+/// Speed over ground in 0.1 knot. 1022 means 102.2 knots or higher.
+pub(super) fn sog(raw: u32) -> Option<Speed> {
+    (raw != 1023).then(|| Speed::new(f64::from(raw) / 10.0, SpeedUnit::Knots))
+}
+
+/// Longitude in 1/10000 minute, degrees.
+pub(super) fn longitude(raw: i32) -> Option<f64> {
+    let lon = f64::from(raw) / 600_000.0;
+    (-180.0..=180.0).contains(&lon).then_some(lon)
+}
+
+/// Latitude in 1/10000 minute, degrees.
+pub(super) fn latitude(raw: i32) -> Option<f64> {
+    let lat = f64::from(raw) / 600_000.0;
+    (-90.0..=90.0).contains(&lat).then_some(lat)
+}
+
+/// Course over ground in 0.1 degree.
+pub(super) fn cog(raw: u32) -> Option<f64> {
+    (raw < 3600).then(|| f64::from(raw) / 10.0)
+}
+
+/// True heading, degrees.
+pub(super) fn heading(raw: u32) -> Option<u32> {
+    (raw < 360).then_some(raw)
+}
+
+/// UTC second of position report. Values above 59 mean not available (60), manual input (61),
+/// dead reckoning (62) or inoperative positioning system (63).
+pub(super) fn second(timestamp: u32) -> Option<u32> {
+    (timestamp < 60).then_some(timestamp)
+}
+
+pub(super) fn sog_raw(sog: Option<Speed>) -> u32 {
+    sog.map_or(1023, |s| {
+        (s.knots() * 10.0).round().clamp(0.0, 1022.0) as u32
+    })
+}
+
+pub(super) fn longitude_raw(lon: Option<f64>) -> i32 {
+    (lon.unwrap_or(181.0) * 600_000.0).round() as i32
+}
+
+pub(super) fn latitude_raw(lat: Option<f64>) -> i32 {
+    (lat.unwrap_or(91.0) * 600_000.0).round() as i32
+}
+
+pub(super) fn cog_raw(cog: Option<f64>) -> u32 {
+    cog.map_or(3600, |c| (c * 10.0).round() as u32 % 3600)
+}
+
+pub(super) fn heading_raw(heading: Option<u32>) -> u32 {
+    heading.unwrap_or(511)
+}
+
+/// Class A position report (types 1, 2, 3).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionReport {
+    /// 1, 2 - scheduled, 3 - response to interrogation.
+    pub message_type: u32,
+    pub repeat: u32,
+    pub mmsi: u32,
+    pub nav_status: Option<NavigationStatus>,
+    pub rate_of_turn: Option<RateOfTurn>,
+    pub sog: Option<Speed>,
+    /// High (< 10 m) position accuracy.
+    pub position_accuracy: bool,
+    /// Degrees, negative to the west.
+    pub longitude: Option<f64>,
+    /// Degrees, negative to the south.
+    pub latitude: Option<f64>,
+    /// Course over ground, degrees.
+    pub cog: Option<f64>,
+    pub heading: Option<u32>,
+    /// UTC second (0-59) or special value (60-63).
+    pub timestamp: u32,
+    /// 1 - no special manoeuvre, 2 - special manoeuvre. 0 - not available.
+    pub maneuver: u32,
+    pub raim: bool,
+    /// Radio status for SOTDMA or ITDMA.
+    pub radio_status: u32,
+}
+
+impl PositionReport {
+    pub fn decode(p: &Payload) -> Option<Self> {
+        Some(PositionReport {
+            message_type: p.unsigned(0, 6)?,
+            repeat: p.unsigned(6, 2)?,
+            mmsi: p.unsigned(8, 30)?,
+            nav_status: NavigationStatus::from_value(p.unsigned(38, 4)?),
+            rate_of_turn: RateOfTurn::from_raw(p.signed(42, 8)?),
+            sog: sog(p.unsigned(50, 10)?),
+            position_accuracy: p.flag(60)?,
+            longitude: longitude(p.signed(61, 28)?),
+            latitude: latitude(p.signed(89, 27)?),
+            cog: cog(p.unsigned(116, 12)?),
+            heading: heading(p.unsigned(128, 9)?),
+            timestamp: p.unsigned(137, 6)?,
+            maneuver: p.unsigned(143, 2)?,
+            raim: p.flag(148)?,
+            radio_status: p.unsigned(149, 19)?,
+        })
+    }
+
+    pub fn encode(&self) -> Payload {
+        PayloadBuilder::new()
+            .unsigned(self.message_type, 6)
+            .unsigned(self.repeat, 2)
+            .unsigned(self.mmsi, 30)
+            .unsigned(self.nav_status.map_or(15, |s| s.value()), 4)
+            .signed(RateOfTurn::to_raw(self.rate_of_turn), 8)
+            .unsigned(sog_raw(self.sog), 10)
+            .flag(self.position_accuracy)
+            .signed(longitude_raw(self.longitude), 28)
+            .signed(latitude_raw(self.latitude), 27)
+            .unsigned(cog_raw(self.cog), 12)
+            .unsigned(heading_raw(self.heading), 9)
+            .unsigned(self.timestamp, 6)
+            .unsigned(self.maneuver, 2)
+            .spare(3)
+            .flag(self.raim)
+            .unsigned(self.radio_status, 19)
+            .build()
+    }
+
+    /// Position as (latitude, longitude) in degrees.
+    pub fn position(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+
+    /// UTC second of report, None if not available.
+    pub fn second(&self) -> Option<u32> {
+        second(self.timestamp)
+    }
+}
+
+/// Standard class B position report (type 18).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassBPositionReport {
+    pub repeat: u32,
+    pub mmsi: u32,
+    pub sog: Option<Speed>,
+    pub position_accuracy: bool,
+    pub longitude: Option<f64>,
+    pub latitude: Option<f64>,
+    pub cog: Option<f64>,
+    pub heading: Option<u32>,
+    pub timestamp: u32,
+    /// Carrier sense unit, otherwise SOTDMA unit.
+    pub cs_unit: bool,
+    /// Has display for messages 12 and 14.
+    pub display: bool,
+    /// Has DSC function.
+    pub dsc: bool,
+    /// Can use whole marine band.
+    pub band: bool,
+    /// Frequency management via message 22 is accepted.
+    pub msg22: bool,
+    /// Station is in assigned mode.
+    pub assigned: bool,
+    pub raim: bool,
+    pub radio_status: u32,
+}
+
+impl ClassBPositionReport {
+    pub fn decode(p: &Payload) -> Option<Self> {
+        Some(ClassBPositionReport {
+            repeat: p.unsigned(6, 2)?,
+            mmsi: p.unsigned(8, 30)?,
+            sog: sog(p.unsigned(46, 10)?),
+            position_accuracy: p.flag(56)?,
+            longitude: longitude(p.signed(57, 28)?),
+            latitude: latitude(p.signed(85, 27)?),
+            cog: cog(p.unsigned(112, 12)?),
+            heading: heading(p.unsigned(124, 9)?),
+            timestamp: p.unsigned(133, 6)?,
+            cs_unit: p.flag(141)?,
+            display: p.flag(142)?,
+            dsc: p.flag(143)?,
+            band: p.flag(144)?,
+            msg22: p.flag(145)?,
+            assigned: p.flag(146)?,
+            raim: p.flag(147)?,
+            radio_status: p.unsigned(148, 20)?,
+        })
+    }
+
+    pub fn encode(&self) -> Payload {
+        PayloadBuilder::new()
+            .unsigned(18, 6)
+            .unsigned(self.repeat, 2)
+            .unsigned(self.mmsi, 30)
+            .spare(8)
+            .unsigned(sog_raw(self.sog), 10)
+            .flag(self.position_accuracy)
+            .signed(longitude_raw(self.longitude), 28)
+            .signed(latitude_raw(self.latitude), 27)
+            .unsigned(cog_raw(self.cog), 12)
+            .unsigned(heading_raw(self.heading), 9)
+            .unsigned(self.timestamp, 6)
+            .spare(2)
+            .flag(self.cs_unit)
+            .flag(self.display)
+            .flag(self.dsc)
+            .flag(self.band)
+            .flag(self.msg22)
+            .flag(self.assigned)
+            .flag(self.raim)
+            .unsigned(self.radio_status, 20)
+            .build()
+    }
+
+    pub fn position(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+
+    pub fn second(&self) -> Option<u32> {
+        second(self.timestamp)
+    }
+}
+
+/// Extended class B position report (type 19) with static data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassBExtendedReport {
+    pub repeat: u32,
+    pub mmsi: u32,
+    pub sog: Option<Speed>,
+    pub position_accuracy: bool,
+    pub longitude: Option<f64>,
+    pub latitude: Option<f64>,
+    pub cog: Option<f64>,
+    pub heading: Option<u32>,
+    pub timestamp: u32,
+    pub name: String,
+    pub ship_type: Option<ShipType>,
+    pub dimensions: Option<Dimensions>,
+    pub epfd: Option<EpfdType>,
+    pub raim: bool,
+    /// Data terminal equipment is not ready.
+    pub dte: bool,
+    pub assigned: bool,
+}
+
+impl ClassBExtendedReport {
+    pub fn decode(p: &Payload) -> Option<Self> {
+        Some(ClassBExtendedReport {
+            repeat: p.unsigned(6, 2)?,
+            mmsi: p.unsigned(8, 30)?,
+            sog: sog(p.unsigned(46, 10)?),
+            position_accuracy: p.flag(56)?,
+            longitude: longitude(p.signed(57, 28)?),
+            latitude: latitude(p.signed(85, 27)?),
+            cog: cog(p.unsigned(112, 12)?),
+            heading: heading(p.unsigned(124, 9)?),
+            timestamp: p.unsigned(133, 6)?,
+            name: p.text(143, 120)?,
+            ship_type: ShipType::from_value(p.unsigned(263, 8)?),
+            dimensions: Dimensions::decode(p, 271)?,
+            epfd: EpfdType::from_value(p.unsigned(301, 4)?),
+            raim: p.flag(305)?,
+            dte: p.flag(306)?,
+            assigned: p.flag(307)?,
+        })
+    }
+
+    pub fn encode(&self) -> Payload {
+        let mut b = PayloadBuilder::new();
+        b.unsigned(19, 6)
+            .unsigned(self.repeat, 2)
+            .unsigned(self.mmsi, 30)
+            .spare(8)
+            .unsigned(sog_raw(self.sog), 10)
+            .flag(self.position_accuracy)
+            .signed(longitude_raw(self.longitude), 28)
+            .signed(latitude_raw(self.latitude), 27)
+            .unsigned(cog_raw(self.cog), 12)
+            .unsigned(heading_raw(self.heading), 9)
+            .unsigned(self.timestamp, 6)
+            .spare(4)
+            .text(&self.name, 120)
+            .unsigned(self.ship_type.map_or(0, |t| t.value()), 8);
+        Dimensions::encode(self.dimensions, &mut b);
+        b.unsigned(self.epfd.map_or(0, |e| e.value()), 4)
+            .flag(self.raim)
+            .flag(self.dte)
+            .flag(self.assigned)
+            .spare(4)
+            .build()
+    }
+
+    pub fn position(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+
+    pub fn second(&self) -> Option<u32> {
+        second(self.timestamp)
+    }
+}
+
+/// Long-range position report (type 27) with reduced precision, broadcast for satellite reception.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LongRangePositionReport {
+    pub repeat: u32,
+    pub mmsi: u32,
+    pub position_accuracy: bool,
+    pub raim: bool,
+    pub nav_status: Option<NavigationStatus>,
+    /// Degrees in 1/10 minute precision.
+    pub longitude: Option<f64>,
+    pub latitude: Option<f64>,
+    /// Speed over ground in 1 knot steps. 62 means 62 knots or higher.
+    pub sog: Option<Speed>,
+    /// Course over ground in 1 degree steps.
+    pub cog: Option<u32>,
+    /// Position is from current GNSS fix, otherwise it is older.
+    pub current_gnss: bool,
+}
+
+impl LongRangePositionReport {
+    pub fn decode(p: &Payload) -> Option<Self> {
+        let lon = f64::from(p.signed(44, 18)?) / 600.0;
+        let lat = f64::from(p.signed(62, 17)?) / 600.0;
+        let sog = p.unsigned(79, 6)?;
+        Some(LongRangePositionReport {
+            repeat: p.unsigned(6, 2)?,
+            mmsi: p.unsigned(8, 30)?,
+            position_accuracy: p.flag(38)?,
+            raim: p.flag(39)?,
+            nav_status: NavigationStatus::from_value(p.unsigned(40, 4)?),
+            longitude: (-180.0..=180.0).contains(&lon).then_some(lon),
+            latitude: (-90.0..=90.0).contains(&lat).then_some(lat),
+            sog: (sog != 63).then(|| Speed::new(f64::from(sog), SpeedUnit::Knots)),
+            cog: heading(p.unsigned(85, 9)?),
+            current_gnss: !p.flag(94)?,
+        })
+    }
+
+    pub fn encode(&self) -> Payload {
+        PayloadBuilder::new()
+            .unsigned(27, 6)
+            .unsigned(self.repeat, 2)
+            .unsigned(self.mmsi, 30)
+            .flag(self.position_accuracy)
+            .flag(self.raim)
+            .unsigned(self.nav_status.map_or(15, |s| s.value()), 4)
+            .signed((self.longitude.unwrap_or(181.0) * 600.0).round() as i32, 18)
+            .signed((self.latitude.unwrap_or(91.0) * 600.0).round() as i32, 17)
+            .unsigned(
+                self.sog
+                    .map_or(63, |s| s.knots().round().clamp(0.0, 62.0) as u32),
+                6,
+            )
+            .unsigned(heading_raw(self.cog), 9)
+            .flag(!self.current_gnss)
+            .spare(1)
+            .build()
+    }
+
+    pub fn position(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ais::{decode_armored, AisMessage, EpfdType, ShipType},
+        fields::{Speed, SpeedUnit},
+        test_util::assert_near,
+    };
+
+    use super::{NavigationStatus, RateOfTurn};
+
+    #[test]
+    fn type1() {
+        let Some(AisMessage::PositionReport(m)) = decode_armored("15M67FC000G?ufbE`FepT@3n00Sa", 0)
+        else {
+            panic!()
+        };
+        assert_eq!(m.message_type, 1);
+        assert_eq!(m.mmsi, 366053209);
+        assert_eq!(
+            m.nav_status,
+            Some(NavigationStatus::RestrictedManoeuvrability)
+        );
+        assert_eq!(m.rate_of_turn, Some(RateOfTurn::Rate(0.0)));
+        assert_eq!(m.sog, Some(Speed::new(0.0, SpeedUnit::Knots)));
+        assert!(!m.position_accuracy);
+        assert_near(m.longitude, -122.341618);
+        assert_near(m.latitude, 37.802118);
+        assert_eq!(m.cog, Some(219.3));
+        assert_eq!(m.heading, Some(1));
+        assert_eq!(m.second(), Some(59));
+        assert!(!m.raim);
+        assert_eq!(m.radio_status, 2281);
+    }
+
+    #[test]
+    fn rate_of_turn() {
+        let Some(AisMessage::PositionReport(m)) = decode_armored("13u?etPv2;0n:dDPwUM1U1Cb069D", 0)
+        else {
+            panic!()
+        };
+        assert_eq!(m.mmsi, 265547250);
+        let Some(RateOfTurn::Rate(rot)) = m.rate_of_turn else {
+            panic!()
+        };
+        assert!((rot + 2.857).abs() < 1e-3);
+        assert_eq!(m.sog.unwrap().knots(), 13.9);
+        assert_eq!(m.heading, Some(41));
+
+        let Some(AisMessage::PositionReport(m)) = decode_armored("13HOI:0P0000VOHLCnHQKwvL05Ip", 0)
+        else {
+            panic!()
+        };
+        assert_eq!(m.rate_of_turn, None);
+        assert_eq!(m.heading, None);
+        assert_eq!(m.nav_status, Some(NavigationStatus::UnderWayUsingEngine));
+
+        let Some(AisMessage::PositionReport(m)) = decode_armored("15RTgt0PAso;90TKcjM8h6g208CQ", 0)
+        else {
+            panic!()
+        };
+        assert_eq!(m.rate_of_turn, Some(RateOfTurn::FastPort));
+        assert!(m.position_accuracy);
+        assert_eq!(RateOfTurn::from_raw(127), Some(RateOfTurn::FastStarboard));
+        assert_eq!(
+            RateOfTurn::from_raw(126),
+            Some(RateOfTurn::Rate((126.0f64 / 4.733).powi(2)))
+        );
+    }
+
+    #[test]
+    fn type1_not_defined_status() {
+        let Some(AisMessage::PositionReport(m)) = decode_armored("133m@ogP00PD;88MD5MTDww@2D7k", 0)
+        else {
+            panic!()
+        };
+        assert_eq!(m.nav_status, None);
+        assert!(m.raim);
+        assert_eq!(m.second(), Some(40));
+    }
+
+    #[test]
+    fn type18() {
+        let Some(AisMessage::ClassBPositionReport(m)) =
+            decode_armored("B52K>;h00Fc>jpUlNV@ikwpUoP06", 0)
+        else {
+            panic!()
+        };
+        assert_eq!(m.mmsi, 338087471);
+        assert_eq!(m.sog.unwrap().knots(), 0.1);
+        assert_near(m.longitude, -74.072132);
+        assert_near(m.latitude, 40.68454);
+        assert_eq!(m.cog, Some(79.6));
+        assert_eq!(m.heading, None);
+        assert_eq!(m.second(), Some(49));
+        assert!(m.cs_unit);
+        assert!(!m.display);
+        assert!(m.dsc && m.band && m.msg22);
+        assert!(!m.assigned);
+        assert!(m.raim);
+        assert_eq!(m.radio_status, 917510);
+    }
+
+    #[test]
+    fn type19() {
+        let Some(AisMessage::ClassBExtendedReport(m)) =
+            decode_armored("C5N3SRgPEnJGEBT>NhWAwwo862PaLELTBJ:V00000000S0D:R220", 0)
+        else {
+            panic!()
+        };
+        assert_eq!(m.mmsi, 367059850);
+        assert_eq!(m.sog.unwrap().knots(), 8.7);
+        assert_near(m.longitude, -88.810392);
+        assert_near(m.latitude, 29.543695);
+        assert_eq!(m.cog, Some(335.9));
+        assert_eq!(m.second(), Some(46));
+        assert_eq!(m.name, "CAPT.J.RIMES");
+        assert_eq!(m.ship_type, Some(ShipType::Cargo(0)));
+        let dims = m.dimensions.unwrap();
+        assert_eq!(
+            (dims.to_bow, dims.to_stern, dims.to_port, dims.to_starboard),
+            (5, 21, 4, 4)
+        );
+        assert_eq!(m.epfd, Some(EpfdType::Gps));
+        assert!(!m.dte);
+    }
+
+    #[test]
+    fn type27() {
+        let Some(AisMessage::LongRangePositionReport(m)) = decode_armored("KC5E2b@U19PFdLbL", 0)
+        else {
+            panic!()
+        };
+        assert_eq!(m.mmsi, 206914217);
+        assert!(!m.position_accuracy);
+        assert!(!m.raim);
+        assert_eq!(m.nav_status, Some(NavigationStatus::NotUnderCommand));
+        assert_near(m.longitude, 137.023333);
+        assert_near(m.latitude, 4.84);
+        assert_eq!(m.sog, Some(Speed::new(57.0, SpeedUnit::Knots)));
+        assert_eq!(m.cog, Some(167));
+        assert!(m.current_gnss);
+    }
+
+    #[test]
+    fn fill_bits() {
+        // Type 18 (168 bits) in 29 chars with 5 fill bits
+        assert!(decode_armored("B52K>;h00Fc>jpUlNV@ikwpUoP060", 5).is_some());
+        // Fill bits cut required radio status bits
+        assert_eq!(decode_armored("B52K>;h00Fc>jpUlNV@ikwpUoP06", 2), None);
+    }
+}
@@ -0,0 +1,191 @@
+//! Safety related text messages (types 12, 14) and acknowledgements of addressed binary and
+//! safety messages (types 7, 13).
+
+use super::{Addressee, Payload, PayloadBuilder};
+
+/// Acknowledged message of a destination station.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AcknowledgedMessage {
+    pub mmsi: u32,
+    /// Sequence number of the acknowledged message (0-3).
+    pub sequence: u32,
+}
+
+/// Binary acknowledge (type 7) or safety related acknowledge (type 13).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Acknowledgement {
+    pub message_type: u32,
+    pub repeat: u32,
+    pub mmsi: u32,
+    /// 1 to 4 acknowledged messages.
+    pub acks: Vec<AcknowledgedMessage>,
+}
+
+impl Acknowledgement {
+    pub fn decode(p: &Payload) -> Option<Self> {
+        let mut acks = Vec::new();
+        let mut start = 40;
+        while acks.len() < 4 && start + 32 <= p.len() {
+            acks.push(AcknowledgedMessage {
+                mmsi: p.unsigned(start, 30)?,
+                sequence: p.unsigned(start + 30, 2)?,
+            });
+            start += 32;
+        }
+        if acks.is_empty() {
+            return None;
+        }
+        Some(Acknowledgement {
+            message_type: p.unsigned(0, 6)?,
+            repeat: p.unsigned(6, 2)?,
+            mmsi: p.unsigned(8, 30)?,
+            acks,
+        })
+    }
+
+    pub fn encode(&self) -> Payload {
+        let mut b = PayloadBuilder::new();
+        b.unsigned(self.message_type, 6)
+            .unsigned(self.repeat, 2)
+            .unsigned(self.mmsi, 30)
+            .spare(2);
+        for ack in self.acks.iter().take(4) {
+            b.unsigned(ack.mmsi, 30).unsigned(ack.sequence, 2);
+        }
+        b.build()
+    }
+}
+
+/// Addressed (type 12) or broadcast (type 14) safety related text message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SafetyMessage {
+    pub repeat: u32,
+    pub mmsi: u32,
+    /// Destination of addressed message, None for broadcast.
+    pub addressee: Option<Addressee>,
+    pub text: String,
+}
+
+impl SafetyMessage {
+    /// Decodes addressed safety related message (type 12).
+    pub fn decode_addressed(p: &Payload) -> Option<Self> {
+        Some(SafetyMessage {
+            repeat: p.unsigned(6, 2)?,
+            mmsi: p.unsigned(8, 30)?,
+            addressee: Some(Addressee {
+                sequence: p.unsigned(38, 2)?,
+                mmsi: p.unsigned(40, 30)?,
+                retransmit: p.flag(70)?,
+            }),
+            text: p.text(72, p.len().saturating_sub(72)).unwrap_or_default(),
+        })
+    }
+
+    /// Decodes safety related broadcast message (type 14).
+    pub fn decode_broadcast(p: &Payload) -> Option<Self> {
+        Some(SafetyMessage {
+            repeat: p.unsigned(6, 2)?,
+            mmsi: p.unsigned(8, 30)?,
+            addressee: None,
+            text: p.text(40, p.len().saturating_sub(40)).unwrap_or_default(),
+        })
+    }
+
+    /// Encodes message. Text is truncated to 156 (addressed) or 161 (broadcast) chars.
+    pub fn encode(&self) -> Payload {
+        let mut b = PayloadBuilder::new();
+        b.unsigned(self.message_type(), 6)
+            .unsigned(self.repeat, 2)
+            .unsigned(self.mmsi, 30);
+        let max_chars = match &self.addressee {
+            Some(addressee) => {
+                addressee.encode(&mut b);
+                b.spare(1);
+                156
+            }
+            None => {
+                b.spare(2);
+                161
+            }
+        };
+        let len = self.text.chars().count().min(max_chars) * 6;
+        b.text(&self.text, len).build()
+    }
+
+    pub fn message_type(&self) -> u32 {
+        if self.addressee.is_some() {
+            12
+        } else {
+            14
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ais::{decode_armored, Addressee, AisMessage};
+
+    use super::AcknowledgedMessage;
+
+    #[test]
+    fn type7() {
+        let Some(AisMessage::Acknowledgement(m)) = decode_armored("702R5`hwCjq8", 0) else {
+            panic!()
+        };
+        assert_eq!(m.message_type, 7);
+        assert_eq!(m.mmsi, 2655651);
+        assert_eq!(
+            m.acks,
+            vec![AcknowledgedMessage {
+                mmsi: 265538450,
+                sequence: 0
+            }]
+        );
+    }
+
+    #[test]
+    fn type13() {
+        let Some(AisMessage::Acknowledgement(m)) = decode_armored("=39UOj0jFs9R", 0) else {
+            panic!()
+        };
+        assert_eq!(m.message_type, 13);
+        assert_eq!(m.mmsi, 211378120);
+        assert_eq!(
+            m.acks,
+            vec![AcknowledgedMessage {
+                mmsi: 211217560,
+                sequence: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn type12() {
+        let Some(AisMessage::SafetyMessage(m)) = decode_armored("<5?SIj1;GbD07??4", 0) else {
+            panic!()
+        };
+        assert_eq!(m.message_type(), 12);
+        assert_eq!(m.mmsi, 351853000);
+        assert_eq!(
+            m.addressee,
+            Some(Addressee {
+                sequence: 0,
+                mmsi: 316123456,
+                retransmit: false
+            })
+        );
+        assert_eq!(m.text, "GOOD");
+    }
+
+    #[test]
+    fn type14() {
+        let Some(AisMessage::SafetyMessage(m)) = decode_armored(">5?Per18=HB1U:1@E=B0m<L", 2)
+        else {
+            panic!()
+        };
+        assert_eq!(m.message_type(), 14);
+        assert_eq!(m.mmsi, 351809000);
+        assert_eq!(m.addressee, None);
+        assert_eq!(m.text, "RCVD YR TEST MSG");
+    }
+}
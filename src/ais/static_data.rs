@@ -0,0 +1,477 @@
+//! Static and voyage related data (type 5) and class B static data reports (type 24).
+
+use crate::fields::{Distance, DistanceUnit};
+
+use super::{Payload, PayloadBuilder};
+
+/// Ship and cargo type. Hazard category digit: 0 - all ships of type, 1-4 - hazard category
+/// X, Y, Z, OS (or A-D), 5-8 - reserved, 9 - no additional information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShipType {
+    /// Wing in ground (20-29), hazard category.
+    WingInGround(u32),
+    Fishing,
+    Towing,
+    /// Towing with length over 200 m or breadth over 25 m.
+    TowingLarge,
+    Dredging,
+    Diving,
+    Military,
+    Sailing,
+    PleasureCraft,
+    /// High speed craft (40-49), hazard category.
+    HighSpeedCraft(u32),
+    PilotVessel,
+    SearchAndRescue,
+    Tug,
+    PortTender,
+    AntiPollution,
+    LawEnforcement,
+    /// Local vessel (56, 57).
+    Local(u32),
+    MedicalTransport,
+    /// Noncombatant ship according to RR Resolution No. 18.
+    Noncombatant,
+    /// Passenger (60-69), hazard category.
+    Passenger(u32),
+    /// Cargo (70-79), hazard category.
+    Cargo(u32),
+    /// Tanker (80-89), hazard category.
+    Tanker(u32),
+    /// Other type (90-99), hazard category.
+    Other(u32),
+    /// Reserved or regional code.
+    Reserved(u32),
+}
+
+impl ShipType {
+    /// Type from its code. None if not available (0).
+    pub fn from_value(value: u32) -> Option<Self> {
+        Some(match value {
+            0 => return None,
+            20..=29 => ShipType::WingInGround(value - 20),
+            30 => ShipType::Fishing,
+            31 => ShipType::Towing,
+            32 => ShipType::TowingLarge,
+            33 => ShipType::Dredging,
+            34 => ShipType::Diving,
+            35 => ShipType::Military,
+            36 => ShipType::Sailing,
+            37 => ShipType::PleasureCraft,
+            40..=49 => ShipType::HighSpeedCraft(value - 40),
+            50 => ShipType::PilotVessel,
+            51 => ShipType::SearchAndRescue,
+            52 => ShipType::Tug,
+            53 => ShipType::PortTender,
+            54 => ShipType::AntiPollution,
+            55 => ShipType::LawEnforcement,
+            56 | 57 => ShipType::Local(value),
+            58 => ShipType::MedicalTransport,
+            59 => ShipType::Noncombatant,
+            60..=69 => ShipType::Passenger(value - 60),
+            70..=79 => ShipType::Cargo(value - 70),
+            80..=89 => ShipType::Tanker(value - 80),
+            90..=99 => ShipType::Other(value - 90),
+            _ => ShipType::Reserved(value),
+        })
+    }
+
+    pub fn value(&self) -> u32 {
+        match *self {
+            ShipType::WingInGround(c) => 20 + c,
+            ShipType::Fishing => 30,
+            ShipType::Towing => 31,
+            ShipType::TowingLarge => 32,
+            ShipType::Dredging => 33,
+            ShipType::Diving => 34,
+            ShipType::Military => 35,
+            ShipType::Sailing => 36,
+            ShipType::PleasureCraft => 37,
+            ShipType::HighSpeedCraft(c) => 40 + c,
+            ShipType::PilotVessel => 50,
+            ShipType::SearchAndRescue => 51,
+            ShipType::Tug => 52,
+            ShipType::PortTender => 53,
+            ShipType::AntiPollution => 54,
+            ShipType::LawEnforcement => 55,
+            ShipType::Local(v) => v,
+            ShipType::MedicalTransport => 58,
+            ShipType::Noncombatant => 59,
+            ShipType::Passenger(c) => 60 + c,
+            ShipType::Cargo(c) => 70 + c,
+            ShipType::Tanker(c) => 80 + c,
+            ShipType::Other(c) => 90 + c,
+            ShipType::Reserved(v) => v,
+        }
+    }
+}
+
+ais_enum! {
+    /// Type of electronic position fixing device.
+    pub enum EpfdType {
+        Gps = 1,
+        Glonass = 2,
+        CombinedGpsGlonass = 3,
+        LoranC = 4,
+        Chayka = 5,
+        IntegratedNavigation = 6,
+        Surveyed = 7,
+        Galileo = 8,
+        InternalGnss = 15,
+    }
+}
+
+/// Distances from position reference point to ship sides, meters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Dimensions {
+    pub to_bow: u32,
+    pub to_stern: u32,
+    pub to_port: u32,
+    pub to_starboard: u32,
+}
+
+impl Dimensions {
+    /// Decodes 30 bits of dimension fields. None if reference point is not available.
+    pub(crate) fn decode(p: &Payload, start: usize) -> Option<Option<Self>> {
+        let dims = Dimensions {
+            to_bow: p.unsigned(start, 9)?,
+            to_stern: p.unsigned(start + 9, 9)?,
+            to_port: p.unsigned(start + 18, 6)?,
+            to_starboard: p.unsigned(start + 24, 6)?,
+        };
+        Some((dims != Dimensions::default()).then_some(dims))
+    }
+
+    /// Appends 30 bits of dimensions, zero if not available.
+    pub(crate) fn encode(dims: Option<Self>, b: &mut PayloadBuilder) {
+        let dims = dims.unwrap_or_default();
+        b.unsigned(dims.to_bow.min(511), 9)
+            .unsigned(dims.to_stern.min(511), 9)
+            .unsigned(dims.to_port.min(63), 6)
+            .unsigned(dims.to_starboard.min(63), 6);
+    }
+
+    pub fn length(&self) -> Distance {
+        Distance::new(f64::from(self.to_bow + self.to_stern), DistanceUnit::Meters)
+    }
+
+    pub fn beam(&self) -> Distance {
+        Distance::new(
+            f64::from(self.to_port + self.to_starboard),
+            DistanceUnit::Meters,
+        )
+    }
+}
+
+/// Estimated time of arrival in UTC. Not available values are None.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Eta {
+    pub month: Option<u32>,
+    pub day: Option<u32>,
+    pub hour: Option<u32>,
+    pub minute: Option<u32>,
+}
+
+/// Static and voyage related data (type 5).
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticVoyageData {
+    pub repeat: u32,
+    pub mmsi: u32,
+    /// 0 - ITU-R M.1371-1, 1 - M.1371-3, 2 - M.1371-5.
+    pub ais_version: u32,
+    pub imo: Option<u32>,
+    pub callsign: String,
+    pub name: String,
+    pub ship_type: Option<ShipType>,
+    pub dimensions: Option<Dimensions>,
+    pub epfd: Option<EpfdType>,
+    pub eta: Eta,
+    /// Maximum present static draught.
+    pub draught: Option<Distance>,
+    pub destination: String,
+    /// Data terminal equipment is not ready.
+    pub dte: bool,
+}
+
+impl StaticVoyageData {
+    /// Decodes message of 424 bits. Messages truncated by fill bits of the last fragment
+    /// (422 bits and more) are accepted.
+    pub fn decode(p: &Payload) -> Option<Self> {
+        if p.len() < 422 {
+            return None;
+        }
+        let draught = p.unsigned(294, 8)?;
+        Some(StaticVoyageData {
+            repeat: p.unsigned(6, 2)?,
+            mmsi: p.unsigned(8, 30)?,
+            ais_version: p.unsigned(38, 2)?,
+            imo: Some(p.unsigned(40, 30)?).filter(|imo| *imo != 0),
+            callsign: p.text(70, 42)?,
+            name: p.text(112, 120)?,
+            ship_type: ShipType::from_value(p.unsigned(232, 8)?),
+            dimensions: Dimensions::decode(p, 240)?,
+            epfd: EpfdType::from_value(p.unsigned(270, 4)?),
+            eta: Eta {
+                month: Some(p.unsigned(274, 4)?).filter(|m| (1..=12).contains(m)),
+                day: Some(p.unsigned(278, 5)?).filter(|d| *d != 0),
+                hour: Some(p.unsigned(283, 5)?).filter(|h| *h < 24),
+                minute: Some(p.unsigned(288, 6)?).filter(|m| *m < 60),
+            },
+            draught: (draught != 0)
+                .then(|| Distance::new(f64::from(draught) / 10.0, DistanceUnit::Meters)),
+            destination: p.text(302, 120)?,
+            dte: p.flag(422).unwrap_or(false),
+        })
+    }
+
+    pub fn encode(&self) -> Payload {
+        let mut b = PayloadBuilder::new();
+        b.unsigned(5, 6)
+            .unsigned(self.repeat, 2)
+            .unsigned(self.mmsi, 30)
+            .unsigned(self.ais_version, 2)
+            .unsigned(self.imo.unwrap_or(0), 30)
+            .text(&self.callsign, 42)
+            .text(&self.name, 120)
+            .unsigned(self.ship_type.map_or(0, |t| t.value()), 8);
+        Dimensions::encode(self.dimensions, &mut b);
+        b.unsigned(self.epfd.map_or(0, |e| e.value()), 4)
+            .unsigned(self.eta.month.unwrap_or(0), 4)
+            .unsigned(self.eta.day.unwrap_or(0), 5)
+            .unsigned(self.eta.hour.unwrap_or(24), 5)
+            .unsigned(self.eta.minute.unwrap_or(60), 6)
+            .unsigned(
+                self.draught
+                    .map_or(0, |d| (d.meters() * 10.0).round().clamp(0.0, 255.0) as u32),
+                8,
+            )
+            .text(&self.destination, 120)
+            .flag(self.dte)
+            .spare(1)
+            .build()
+    }
+}
+
+/// Part of class B static data report.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StaticDataPart {
+    A {
+        name: String,
+    },
+    B {
+        ship_type: Option<ShipType>,
+        /// Manufacturer ID (3 chars).
+        vendor_id: String,
+        /// Unit model code.
+        model: u32,
+        /// Unit serial number.
+        serial: u32,
+        callsign: String,
+        /// Dimensions of ship. None for auxiliary craft.
+        dimensions: Option<Dimensions>,
+        /// MMSI of mother ship for auxiliary craft.
+        mothership_mmsi: Option<u32>,
+    },
+}
+
+/// Class B static data report (type 24), sent as separate parts A and B.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticDataReport {
+    pub repeat: u32,
+    pub mmsi: u32,
+    pub part: StaticDataPart,
+}
+
+impl StaticDataReport {
+    pub fn decode(p: &Payload) -> Option<Self> {
+        let mmsi = p.unsigned(8, 30)?;
+        let part = match p.unsigned(38, 2)? {
+            0 => StaticDataPart::A {
+                name: p.text(40, 120)?,
+            },
+            1 => {
+                // Auxiliary craft MMSI is 98XXXYYYY
+                let auxiliary = mmsi / 10_000_000 == 98;
+                StaticDataPart::B {
+                    ship_type: ShipType::from_value(p.unsigned(40, 8)?),
+                    vendor_id: p.text(48, 18)?,
+                    model: p.unsigned(66, 4)?,
+                    serial: p.unsigned(70, 20)?,
+                    callsign: p.text(90, 42)?,
+                    dimensions: if auxiliary {
+                        None
+                    } else {
+                        Dimensions::decode(p, 132)?
+                    },
+                    mothership_mmsi: if auxiliary {
+                        Some(p.unsigned(132, 30)?)
+                    } else {
+                        None
+                    },
+                }
+            }
+            _ => return None,
+        };
+        Some(StaticDataReport {
+            repeat: p.unsigned(6, 2)?,
+            mmsi,
+            part,
+        })
+    }
+
+    pub fn encode(&self) -> Payload {
+        let mut b = PayloadBuilder::new();
+        b.unsigned(24, 6)
+            .unsigned(self.repeat, 2)
+            .unsigned(self.mmsi, 30);
+        match &self.part {
+            StaticDataPart::A { name } => {
+                b.unsigned(0, 2).text(name, 120);
+            }
+            StaticDataPart::B {
+                ship_type,
+                vendor_id,
+                model,
+                serial,
+                callsign,
+                dimensions,
+                mothership_mmsi,
+            } => {
+                b.unsigned(1, 2)
+                    .unsigned(ship_type.map_or(0, |t| t.value()), 8)
+                    .text(vendor_id, 18)
+                    .unsigned(*model, 4)
+                    .unsigned(*serial, 20)
+                    .text(callsign, 42);
+                match mothership_mmsi {
+                    Some(mmsi) => {
+                        b.unsigned(*mmsi, 30);
+                    }
+                    None => Dimensions::encode(*dimensions, &mut b),
+                }
+                b.spare(6);
+            }
+        }
+        b.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ais::{decode_armored, AisMessage},
+        fields::{Distance, DistanceUnit},
+    };
+
+    use super::{Dimensions, EpfdType, Eta, ShipType, StaticDataPart};
+
+    #[test]
+    fn ship_type_codes() {
+        assert_eq!(ShipType::from_value(0), None);
+        assert_eq!(ShipType::from_value(70), Some(ShipType::Cargo(0)));
+        assert_eq!(ShipType::from_value(84), Some(ShipType::Tanker(4)));
+        assert_eq!(ShipType::from_value(52), Some(ShipType::Tug));
+        assert_eq!(ShipType::from_value(57), Some(ShipType::Local(57)));
+        assert_eq!(ShipType::from_value(150), Some(ShipType::Reserved(150)));
+        for code in 1..256 {
+            assert_eq!(ShipType::from_value(code).unwrap().value(), code);
+        }
+    }
+
+    #[test]
+    fn type5() {
+        let Some(AisMessage::StaticVoyageData(m)) = decode_armored(
+            "55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp888888888880",
+            2,
+        ) else {
+            panic!()
+        };
+        assert_eq!(m.mmsi, 351759000);
+        assert_eq!(m.ais_version, 0);
+        assert_eq!(m.imo, Some(9134270));
+        assert_eq!(m.callsign, "3FOF8");
+        assert_eq!(m.name, "EVER DIADEM");
+        assert_eq!(m.ship_type, Some(ShipType::Cargo(0)));
+        let dims = m.dimensions.unwrap();
+        assert_eq!(
+            dims,
+            Dimensions {
+                to_bow: 225,
+                to_stern: 70,
+                to_port: 1,
+                to_starboard: 31
+            }
+        );
+        assert_eq!(dims.length().meters(), 295.0);
+        assert_eq!(dims.beam().meters(), 32.0);
+        assert_eq!(m.epfd, Some(EpfdType::Gps));
+        assert_eq!(
+            m.eta,
+            Eta {
+                month: Some(5),
+                day: Some(15),
+                hour: Some(14),
+                minute: Some(0)
+            }
+        );
+        assert_eq!(m.draught, Some(Distance::new(12.2, DistanceUnit::Meters)));
+        assert_eq!(m.destination, "NEW YORK");
+        assert!(!m.dte);
+    }
+
+    #[test]
+    fn type5_at_padding() {
+        let Some(AisMessage::StaticVoyageData(m)) = decode_armored(
+            "55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E531@0000000000000",
+            2,
+        ) else {
+            panic!()
+        };
+        assert_eq!(m.mmsi, 369190000);
+        assert_eq!(m.callsign, "WDA9674");
+        assert_eq!(m.name, "MT.MITCHELL");
+        assert_eq!(m.destination, "SEATTLE");
+        assert_eq!(m.ship_type, Some(ShipType::Other(9)));
+        assert_eq!(m.draught.unwrap().meters(), 6.0);
+    }
+
+    #[test]
+    fn type24() {
+        let Some(AisMessage::StaticDataReport(a)) =
+            decode_armored("H42O55i18tMET00000000000000", 2)
+        else {
+            panic!()
+        };
+        assert_eq!(a.mmsi, 271041815);
+        assert_eq!(
+            a.part,
+            StaticDataPart::A {
+                name: "PROGUY".to_string()
+            }
+        );
+
+        let Some(AisMessage::StaticDataReport(b)) =
+            decode_armored("H42O55lti4hhhilD3nink000?050", 0)
+        else {
+            panic!()
+        };
+        assert_eq!(b.mmsi, 271041815);
+        assert_eq!(
+            b.part,
+            StaticDataPart::B {
+                ship_type: Some(ShipType::Passenger(0)),
+                vendor_id: "1D0".to_string(),
+                model: 12,
+                serial: 199796,
+                callsign: "TC6163".to_string(),
+                dimensions: Some(Dimensions {
+                    to_bow: 0,
+                    to_stern: 15,
+                    to_port: 0,
+                    to_starboard: 5
+                }),
+                mothership_mmsi: None,
+            }
+        );
+    }
+}
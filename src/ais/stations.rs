@@ -0,0 +1,368 @@
+//! Reports of base stations (types 4, 11), SAR aircraft (type 9) and aids to navigation (type 21).
+
+use crate::fields::{Distance, DistanceUnit, Speed, SpeedUnit};
+
+use super::{
+    position::{cog, cog_raw, latitude, latitude_raw, longitude, longitude_raw, second},
+    Dimensions, EpfdType, Payload, PayloadBuilder,
+};
+
+/// Base station report (type 4) or UTC and date response (type 11).
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaseStationReport {
+    pub message_type: u32,
+    pub repeat: u32,
+    pub mmsi: u32,
+    /// UTC date and time. Not available values are None.
+    pub year: Option<u32>,
+    pub month: Option<u32>,
+    pub day: Option<u32>,
+    pub hour: Option<u32>,
+    pub minute: Option<u32>,
+    pub second: Option<u32>,
+    pub position_accuracy: bool,
+    pub longitude: Option<f64>,
+    pub latitude: Option<f64>,
+    pub epfd: Option<EpfdType>,
+    pub raim: bool,
+    pub radio_status: u32,
+}
+
+impl BaseStationReport {
+    pub fn decode(p: &Payload) -> Option<Self> {
+        Some(BaseStationReport {
+            message_type: p.unsigned(0, 6)?,
+            repeat: p.unsigned(6, 2)?,
+            mmsi: p.unsigned(8, 30)?,
+            year: Some(p.unsigned(38, 14)?).filter(|y| *y != 0),
+            month: Some(p.unsigned(52, 4)?).filter(|m| (1..=12).contains(m)),
+            day: Some(p.unsigned(56, 5)?).filter(|d| *d != 0),
+            hour: Some(p.unsigned(61, 5)?).filter(|h| *h < 24),
+            minute: Some(p.unsigned(66, 6)?).filter(|m| *m < 60),
+            second: Some(p.unsigned(72, 6)?).filter(|s| *s < 60),
+            position_accuracy: p.flag(78)?,
+            longitude: longitude(p.signed(79, 28)?),
+            latitude: latitude(p.signed(107, 27)?),
+            epfd: EpfdType::from_value(p.unsigned(134, 4)?),
+            raim: p.flag(148)?,
+            radio_status: p.unsigned(149, 19)?,
+        })
+    }
+
+    pub fn encode(&self) -> Payload {
+        PayloadBuilder::new()
+            .unsigned(self.message_type, 6)
+            .unsigned(self.repeat, 2)
+            .unsigned(self.mmsi, 30)
+            .unsigned(self.year.unwrap_or(0), 14)
+            .unsigned(self.month.unwrap_or(0), 4)
+            .unsigned(self.day.unwrap_or(0), 5)
+            .unsigned(self.hour.unwrap_or(24), 5)
+            .unsigned(self.minute.unwrap_or(60), 6)
+            .unsigned(self.second.unwrap_or(60), 6)
+            .flag(self.position_accuracy)
+            .signed(longitude_raw(self.longitude), 28)
+            .signed(latitude_raw(self.latitude), 27)
+            .unsigned(self.epfd.map_or(0, |e| e.value()), 4)
+            .spare(10)
+            .flag(self.raim)
+            .unsigned(self.radio_status, 19)
+            .build()
+    }
+
+    pub fn position(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+}
+
+/// Standard SAR aircraft position report (type 9).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SarAircraftReport {
+    pub repeat: u32,
+    pub mmsi: u32,
+    /// Altitude from GNSS. 4094 m means 4094 m or higher.
+    pub altitude: Option<Distance>,
+    /// Speed over ground in 1 knot steps. 1022 means 1022 knots or higher.
+    pub sog: Option<Speed>,
+    pub position_accuracy: bool,
+    pub longitude: Option<f64>,
+    pub latitude: Option<f64>,
+    pub cog: Option<f64>,
+    pub timestamp: u32,
+    /// Altitude sensor is barometric, otherwise GNSS (since ITU-R M.1371-4).
+    pub dte: bool,
+    pub assigned: bool,
+    pub raim: bool,
+    pub radio_status: u32,
+}
+
+impl SarAircraftReport {
+    pub fn decode(p: &Payload) -> Option<Self> {
+        let altitude = p.unsigned(38, 12)?;
+        let sog = p.unsigned(50, 10)?;
+        Some(SarAircraftReport {
+            repeat: p.unsigned(6, 2)?,
+            mmsi: p.unsigned(8, 30)?,
+            altitude: (altitude != 4095)
+                .then(|| Distance::new(f64::from(altitude), DistanceUnit::Meters)),
+            sog: (sog != 1023).then(|| Speed::new(f64::from(sog), SpeedUnit::Knots)),
+            position_accuracy: p.flag(60)?,
+            longitude: longitude(p.signed(61, 28)?),
+            latitude: latitude(p.signed(89, 27)?),
+            cog: cog(p.unsigned(116, 12)?),
+            timestamp: p.unsigned(128, 6)?,
+            dte: p.flag(142)?,
+            assigned: p.flag(146)?,
+            raim: p.flag(147)?,
+            radio_status: p.unsigned(148, 20)?,
+        })
+    }
+
+    pub fn encode(&self) -> Payload {
+        PayloadBuilder::new()
+            .unsigned(9, 6)
+            .unsigned(self.repeat, 2)
+            .unsigned(self.mmsi, 30)
+            .unsigned(
+                self.altitude
+                    .map_or(4095, |a| a.meters().round().clamp(0.0, 4094.0) as u32),
+                12,
+            )
+            .unsigned(
+                self.sog
+                    .map_or(1023, |s| s.knots().round().clamp(0.0, 1022.0) as u32),
+                10,
+            )
+            .flag(self.position_accuracy)
+            .signed(longitude_raw(self.longitude), 28)
+            .signed(latitude_raw(self.latitude), 27)
+            .unsigned(cog_raw(self.cog), 12)
+            .unsigned(self.timestamp, 6)
+            .spare(8)
+            .flag(self.dte)
+            .spare(3)
+            .flag(self.assigned)
+            .flag(self.raim)
+            .unsigned(self.radio_status, 20)
+            .build()
+    }
+
+    pub fn position(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+
+    pub fn second(&self) -> Option<u32> {
+        second(self.timestamp)
+    }
+}
+
+ais_enum! {
+    /// Type of aid to navigation.
+    pub enum AtonType {
+        ReferencePoint = 1,
+        Racon = 2,
+        /// Fixed structure off shore, such as oil platforms and wind farms.
+        FixedStructure = 3,
+        EmergencyWreckMarkingBuoy = 4,
+        LightWithoutSectors = 5,
+        LightWithSectors = 6,
+        LeadingLightFront = 7,
+        LeadingLightRear = 8,
+        BeaconCardinalNorth = 9,
+        BeaconCardinalEast = 10,
+        BeaconCardinalSouth = 11,
+        BeaconCardinalWest = 12,
+        BeaconPortHand = 13,
+        BeaconStarboardHand = 14,
+        BeaconPreferredChannelPortHand = 15,
+        BeaconPreferredChannelStarboardHand = 16,
+        BeaconIsolatedDanger = 17,
+        BeaconSafeWater = 18,
+        BeaconSpecialMark = 19,
+        CardinalMarkNorth = 20,
+        CardinalMarkEast = 21,
+        CardinalMarkSouth = 22,
+        CardinalMarkWest = 23,
+        PortHandMark = 24,
+        StarboardHandMark = 25,
+        PreferredChannelPortHand = 26,
+        PreferredChannelStarboardHand = 27,
+        IsolatedDanger = 28,
+        SafeWater = 29,
+        SpecialMark = 30,
+        LightVessel = 31,
+    }
+}
+
+/// Aid-to-navigation report (type 21).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtonReport {
+    pub repeat: u32,
+    pub mmsi: u32,
+    pub aid_type: Option<AtonType>,
+    /// Name including extension.
+    pub name: String,
+    pub position_accuracy: bool,
+    pub longitude: Option<f64>,
+    pub latitude: Option<f64>,
+    pub dimensions: Option<Dimensions>,
+    pub epfd: Option<EpfdType>,
+    pub timestamp: u32,
+    /// Floating aid is off its charted position. Valid only if timestamp is below 60.
+    pub off_position: bool,
+    pub raim: bool,
+    /// Virtual aid, which has no physical presence.
+    pub virtual_aid: bool,
+    pub assigned: bool,
+}
+
+impl AtonReport {
+    pub fn decode(p: &Payload) -> Option<Self> {
+        let mut name = p.text(43, 120)?;
+        // Name extension up to 14 chars fills bits after 272
+        if let Some(ext) = p.text(272, 84) {
+            name.push_str(&ext);
+        }
+        Some(AtonReport {
+            repeat: p.unsigned(6, 2)?,
+            mmsi: p.unsigned(8, 30)?,
+            aid_type: AtonType::from_value(p.unsigned(38, 5)?),
+            name,
+            position_accuracy: p.flag(163)?,
+            longitude: longitude(p.signed(164, 28)?),
+            latitude: latitude(p.signed(192, 27)?),
+            dimensions: Dimensions::decode(p, 219)?,
+            epfd: EpfdType::from_value(p.unsigned(249, 4)?),
+            timestamp: p.unsigned(253, 6)?,
+            off_position: p.flag(259)?,
+            raim: p.flag(268)?,
+            virtual_aid: p.flag(269)?,
+            assigned: p.flag(270)?,
+        })
+    }
+
+    /// Encodes report. Name exceeding 20 chars is encoded in the name extension.
+    pub fn encode(&self) -> Payload {
+        let mut b = PayloadBuilder::new();
+        b.unsigned(21, 6)
+            .unsigned(self.repeat, 2)
+            .unsigned(self.mmsi, 30)
+            .unsigned(self.aid_type.map_or(0, |t| t.value()), 5)
+            .text(&self.name, 120)
+            .flag(self.position_accuracy)
+            .signed(longitude_raw(self.longitude), 28)
+            .signed(latitude_raw(self.latitude), 27);
+        Dimensions::encode(self.dimensions, &mut b);
+        b.unsigned(self.epfd.map_or(0, |e| e.value()), 4)
+            .unsigned(self.timestamp, 6)
+            .flag(self.off_position)
+            .spare(8)
+            .flag(self.raim)
+            .flag(self.virtual_aid)
+            .flag(self.assigned)
+            .spare(1);
+        let ext: String = self.name.chars().skip(20).take(14).collect();
+        if !ext.is_empty() {
+            b.text(&ext, ext.chars().count() * 6);
+            // Extension is padded to byte boundary
+            b.spare((8 - b.len() % 8) % 8);
+        }
+        b.build()
+    }
+
+    pub fn position(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+
+    /// Off position flag if it is valid.
+    pub fn is_off_position(&self) -> Option<bool> {
+        second(self.timestamp).map(|_| self.off_position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ais::{decode_armored, AisMessage, EpfdType},
+        fields::{Distance, DistanceUnit},
+        test_util::assert_near,
+    };
+
+    use super::AtonType;
+
+    #[test]
+    fn type4() {
+        let Some(AisMessage::BaseStationReport(m)) =
+            decode_armored("403OviQuMGCqWrRO9>E6fE700@GO", 0)
+        else {
+            panic!()
+        };
+        assert_eq!(m.message_type, 4);
+        assert_eq!(m.mmsi, 3669702);
+        assert_eq!(
+            (m.year, m.month, m.day, m.hour, m.minute, m.second),
+            (Some(2007), Some(5), Some(14), Some(19), Some(57), Some(39))
+        );
+        assert!(m.position_accuracy);
+        assert_near(m.longitude, -76.352362);
+        assert_near(m.latitude, 36.883767);
+        assert_eq!(m.epfd, Some(EpfdType::Surveyed));
+        assert!(!m.raim);
+        assert_eq!(m.radio_status, 67039);
+    }
+
+    #[test]
+    fn type9() {
+        let Some(AisMessage::SarAircraftReport(m)) =
+            decode_armored("91b55wi;hbOS@OdQAC062Ch2089h", 0)
+        else {
+            panic!()
+        };
+        assert_eq!(m.mmsi, 111232511);
+        assert_eq!(m.altitude, Some(Distance::new(303.0, DistanceUnit::Meters)));
+        assert_eq!(m.sog.unwrap().knots(), 42.0);
+        assert_near(m.longitude, -6.278843);
+        assert_near(m.latitude, 58.144);
+        assert_eq!(m.cog, Some(154.5));
+        assert_eq!(m.second(), Some(15));
+        assert!(m.dte);
+        assert!(!m.assigned);
+    }
+
+    #[test]
+    fn type21() {
+        let Some(AisMessage::AtonReport(m)) =
+            decode_armored("E>jHC=c6:W2h22R`@1:WdP00000Opa@H?KTcP00000000002", 4)
+        else {
+            panic!()
+        };
+        assert_eq!(m.mmsi, 992351030);
+        assert_eq!(m.aid_type, Some(AtonType::CardinalMarkSouth));
+        assert_eq!(m.name, "LUNE DEEP BUOY");
+        assert!(m.position_accuracy);
+        assert_near(m.longitude, -3.213613);
+        assert_near(m.latitude, 53.93466);
+        assert_eq!(m.dimensions, None);
+        assert_eq!(m.is_off_position(), Some(false));
+        assert!(!m.virtual_aid);
+    }
+
+    #[test]
+    fn type21_name_extension() {
+        let Some(AisMessage::AtonReport(m)) =
+            decode_armored("E>jQMtTW7a:4@192PUcPb2a@64SwuKL8>Ms9000003vh1258<LS@", 4)
+        else {
+            panic!()
+        };
+        assert_eq!(m.mmsi, 992501234);
+        assert_eq!(m.aid_type, Some(AtonType::BeaconCardinalNorth));
+        assert_eq!(m.name, "NORTH BREAKWATER LIGHT 12M");
+        assert_near(m.longitude, -1.1234);
+        assert_near(m.latitude, 50.5678);
+        assert_eq!(m.epfd, Some(EpfdType::Surveyed));
+        // Off position flag is not valid with manual input timestamp
+        assert!(m.off_position);
+        assert_eq!(m.is_off_position(), None);
+        assert!(m.virtual_aid);
+    }
+}
@@ -0,0 +1,462 @@
+//! Transmission by own AIS station: ABM/BBM/AIR sentence builders and correlation of
+//! ABK acknowledgements with sent requests.
+
+use crate::{
+    fields::{AbkAckType, AisTxChannel},
+    generated::nmea3::{NmeaAbkMessage, NmeaAbmMessage, NmeaAirMessage, NmeaBbmMessage},
+    messages::MessageFields,
+};
+
+use super::{BinaryMessage, Payload, PayloadBuilder, SafetyMessage};
+
+/// Maximum encapsulated chars of ABM and BBM sentences within 82 chars.
+const MAX_ABM_CHARS: usize = 47;
+const MAX_BBM_CHARS: usize = 57;
+/// Maximum number of ABM and BBM sentences of a message.
+const MAX_FRAGMENTS: usize = 9;
+
+/// ITU-R M.1371 message ID of interrogation.
+const INTERROGATION: u32 = 15;
+
+/// Message to be transmitted by own station. The transponder adds message header
+/// (message ID, repeat indicator, source MMSI and addressing).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransmitRequest {
+    /// Destination of addressed message (ABM), None for broadcast (BBM).
+    pub destination: Option<u32>,
+    /// ITU-R M.1371 message ID (6, 12, 25, 26 addressed; 8, 14, 25, 26 broadcast).
+    pub message_id: u32,
+    pub channel: AisTxChannel,
+    /// Binary data or text part of the message.
+    pub data: Payload,
+}
+
+impl TransmitRequest {
+    /// Binary message (6 or 8). DAC and FID are encoded before application data.
+    pub fn binary(msg: &BinaryMessage) -> Self {
+        TransmitRequest {
+            destination: msg.addressee.map(|a| a.mmsi),
+            message_id: msg.message_type(),
+            channel: AisTxChannel::NoPreference,
+            data: PayloadBuilder::new()
+                .unsigned(msg.dac, 10)
+                .unsigned(msg.fid, 6)
+                .payload(&msg.data)
+                .build(),
+        }
+    }
+
+    /// Safety related text message (12 or 14).
+    pub fn safety(msg: &SafetyMessage) -> Self {
+        TransmitRequest {
+            destination: msg.addressee.map(|a| a.mmsi),
+            message_id: msg.message_type(),
+            channel: AisTxChannel::NoPreference,
+            data: PayloadBuilder::new()
+                .text(&msg.text, msg.text.chars().count() * 6)
+                .build(),
+        }
+    }
+
+    pub fn with_channel(mut self, channel: AisTxChannel) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    /// Encodes request into ABM (addressed) or BBM (broadcast) sentences.
+    /// `sequence_id` is 0-3 for ABM and 0-9 for BBM.
+    /// None if data does not fit into 9 sentences.
+    pub fn to_sentences(&self, talker_id: &str, sequence_id: u32) -> Option<Vec<String>> {
+        let (armored, fill_bits) = self.data.to_armored();
+        let max_chars = match self.destination {
+            Some(_) => MAX_ABM_CHARS,
+            None => MAX_BBM_CHARS,
+        };
+        let mut fragments: Vec<&str> = armored
+            .as_bytes()
+            .chunks(max_chars)
+            .map(|chunk| std::str::from_utf8(chunk).unwrap())
+            .collect();
+        if fragments.is_empty() {
+            fragments.push("");
+        }
+        if fragments.len() > MAX_FRAGMENTS {
+            return None;
+        }
+        let count = fragments.len() as u32;
+        let sentences = fragments
+            .iter()
+            .zip(1..)
+            .map(|(fragment, number)| {
+                let fill_bits = if number == count { fill_bits } else { 0 };
+                match self.destination {
+                    Some(mmsi) => NmeaAbmMessage {
+                        fragment_count: Some(count),
+                        fragment_number: Some(number),
+                        sequence_id: Some(sequence_id),
+                        destination_mmsi: Some(mmsi),
+                        channel: Some(self.channel),
+                        message_id: Some(self.message_id),
+                        payload: Some(fragment.to_string()),
+                        fill_bits: Some(fill_bits),
+                    }
+                    .to_encapsulated_sentence(talker_id),
+                    None => NmeaBbmMessage {
+                        fragment_count: Some(count),
+                        fragment_number: Some(number),
+                        sequence_id: Some(sequence_id),
+                        channel: Some(self.channel),
+                        message_id: Some(self.message_id),
+                        payload: Some(fragment.to_string()),
+                        fill_bits: Some(fill_bits),
+                    }
+                    .to_encapsulated_sentence(talker_id),
+                }
+            })
+            .collect();
+        Some(sentences)
+    }
+}
+
+impl NmeaAirMessage {
+    /// Interrogation of a station for a message (e.g. 5 for static and voyage data).
+    pub fn interrogate(mmsi: u32, message_id: u32) -> Self {
+        NmeaAirMessage {
+            mmsi: Some(mmsi),
+            message_id: Some(message_id),
+            ..Default::default()
+        }
+    }
+
+    /// Requests second message from the same station.
+    pub fn with_second_message(mut self, message_id: u32) -> Self {
+        self.second_message_id = Some(message_id);
+        self
+    }
+
+    /// Requests a message from second station.
+    pub fn with_second_station(mut self, mmsi: u32, message_id: u32) -> Self {
+        self.second_mmsi = Some(mmsi);
+        self.second_station_message_id = Some(message_id);
+        self
+    }
+
+    /// Sets channel of interrogation (A or B).
+    pub fn with_channel(mut self, channel: &str) -> Self {
+        self.channel = Some(channel.to_string());
+        self
+    }
+}
+
+impl NmeaAbkMessage {
+    /// Message was received or transmitted, no further acknowledgement is expected.
+    pub fn is_success(&self) -> bool {
+        matches!(
+            self.ack_type,
+            Some(AbkAckType::Received | AbkAckType::Broadcast)
+        )
+    }
+}
+
+/// Sent ABM, BBM or AIR request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transmission {
+    /// Destination of addressed message or interrogated station.
+    pub destination: Option<u32>,
+    pub message_id: u32,
+    pub sequence_id: u32,
+}
+
+/// Result of a transmission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransmitResult {
+    Acknowledged(AbkAckType),
+    /// No ABK received in time.
+    TimedOut,
+}
+
+/// Correlates ABM, BBM and AIR requests with ABK acknowledgements.
+/// Assigns sequential message identifiers: 0-3 for ABM, 0-9 for BBM and AIR.
+#[derive(Debug)]
+pub struct TransmitTracker {
+    talker_id: String,
+    /// Acknowledgement timeout, seconds.
+    timeout: f64,
+    next_abm_id: u32,
+    next_bbm_id: u32,
+    pending: Vec<(Transmission, f64)>,
+}
+
+impl TransmitTracker {
+    pub fn new(timeout: f64) -> Self {
+        TransmitTracker {
+            talker_id: "AI".to_string(),
+            timeout,
+            next_abm_id: 0,
+            next_bbm_id: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn with_talker_id(mut self, talker_id: &str) -> Self {
+        self.talker_id = talker_id.to_string();
+        self
+    }
+
+    /// Encodes request into sentences and starts waiting for its acknowledgement.
+    /// `time` is in seconds. None if request is too long to be sent.
+    pub fn send(&mut self, request: &TransmitRequest, time: f64) -> Option<Vec<String>> {
+        let (next, modulo) = match request.destination {
+            Some(_) => (&mut self.next_abm_id, 4),
+            None => (&mut self.next_bbm_id, 10),
+        };
+        let sentences = request.to_sentences(&self.talker_id, *next)?;
+        let sequence_id = next_id(next, modulo);
+        self.pending.push((
+            Transmission {
+                destination: request.destination,
+                message_id: request.message_id,
+                sequence_id,
+            },
+            time,
+        ));
+        Some(sentences)
+    }
+
+    /// Encodes interrogation and starts waiting for its acknowledgement.
+    /// Interrogations are acknowledged with sequential identifier of broadcast messages.
+    pub fn send_interrogation(&mut self, air: &NmeaAirMessage, time: f64) -> String {
+        let sequence_id = next_id(&mut self.next_bbm_id, 10);
+        self.pending.push((
+            Transmission {
+                destination: air.mmsi,
+                message_id: INTERROGATION,
+                sequence_id,
+            },
+            time,
+        ));
+        air.to_sentence(&self.talker_id)
+    }
+
+    /// Matches acknowledgement to pending transmission. Returns None for unexpected
+    /// acknowledgements, e.g. late acknowledgement of a transmission reported before.
+    pub fn update(
+        &mut self,
+        abk: &NmeaAbkMessage,
+        time: f64,
+    ) -> Option<(Transmission, TransmitResult)> {
+        let ack_type = abk.ack_type?;
+        let message_id = abk.message_id?;
+        let sequence_id = abk.sequence_id?;
+        let idx = self.pending.iter().position(|(t, sent_at)| {
+            t.message_id == message_id
+                && t.sequence_id == sequence_id
+                // Broadcast acknowledgements have no MMSI
+                && (abk.mmsi.is_none() || t.destination == abk.mmsi)
+                && time - sent_at <= self.timeout
+        })?;
+        let (transmission, _) = self.pending.remove(idx);
+        Some((transmission, TransmitResult::Acknowledged(ack_type)))
+    }
+
+    /// Removes transmissions not acknowledged within timeout.
+    pub fn expire(&mut self, time: f64) -> Vec<(Transmission, TransmitResult)> {
+        let mut expired = Vec::new();
+        self.pending.retain(|(t, sent_at)| {
+            let keep = time - sent_at <= self.timeout;
+            if !keep {
+                expired.push((*t, TransmitResult::TimedOut));
+            }
+            keep
+        });
+        expired
+    }
+
+    /// Number of transmissions waiting for acknowledgement.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+fn next_id(next: &mut u32, modulo: u32) -> u32 {
+    let id = *next;
+    *next = (id + 1) % modulo;
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ais::{decode, Addressee, AisMessage, BinaryMessage, Payload, SafetyMessage},
+        fields::{AbkAckType, AisTxChannel},
+        generated::nmea3::{NmeaAbkMessage, NmeaAbmMessage, NmeaAirMessage, NmeaBbmMessage},
+        messages::MessageFields,
+        test_util::parse,
+    };
+
+    use super::{Transmission, TransmitRequest, TransmitResult, TransmitTracker};
+
+    fn safety_text(mmsi: Option<u32>, text: &str) -> SafetyMessage {
+        SafetyMessage {
+            repeat: 0,
+            mmsi: 0,
+            addressee: mmsi.map(|mmsi| Addressee {
+                sequence: 0,
+                mmsi,
+                retransmit: false,
+            }),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn abm() {
+        let request = TransmitRequest::safety(&safety_text(Some(316123456), "GOOD"))
+            .with_channel(AisTxChannel::A);
+        let sentences = request.to_sentences("AI", 2).unwrap();
+        assert_eq!(sentences, vec!["!AIABM,1,1,2,316123456,1,12,7??4,0*46\r\n"]);
+        let abm: NmeaAbmMessage = parse(&sentences[0]);
+        assert_eq!(abm.destination_mmsi, Some(316123456));
+        assert_eq!(abm.channel, Some(AisTxChannel::A));
+        assert_eq!(abm.message_id, Some(12));
+    }
+
+    #[test]
+    fn bbm_fragments() {
+        let data = Payload::from_armored(&"w".repeat(70), 2).unwrap();
+        let msg = BinaryMessage {
+            repeat: 0,
+            mmsi: 0,
+            addressee: None,
+            dac: 1,
+            fid: 31,
+            data,
+        };
+        let sentences = TransmitRequest::binary(&msg).to_sentences("AI", 7).unwrap();
+        assert_eq!(sentences.len(), 2);
+        assert!(sentences.iter().all(|s| s.len() <= 82));
+        let first: NmeaBbmMessage = parse(&sentences[0]);
+        let second: NmeaBbmMessage = parse(&sentences[1]);
+        assert_eq!(
+            (first.fragment_count, first.fragment_number),
+            (Some(2), Some(1))
+        );
+        assert_eq!((second.sequence_id, second.message_id), (Some(7), Some(8)));
+        assert_eq!((first.fill_bits, second.fill_bits), (Some(0), Some(4)));
+
+        // Transponder adds header to encapsulated data
+        let payload = first.payload.unwrap() + second.payload.as_deref().unwrap();
+        let data = Payload::from_armored(&payload, 4).unwrap();
+        let header = Payload::from_armored("8000000", 2).unwrap();
+        let mut b = crate::ais::PayloadBuilder::new();
+        b.payload(&header).payload(&data);
+        let Some(AisMessage::BinaryMessage(decoded)) = decode(&b.build()) else {
+            panic!()
+        };
+        assert_eq!(decoded, msg);
+    }
+
+    #[test]
+    fn too_many_fragments() {
+        let request = |destination, chars| TransmitRequest {
+            destination,
+            message_id: 8,
+            channel: AisTxChannel::NoPreference,
+            data: Payload::from_armored(&"w".repeat(chars), 0).unwrap(),
+        };
+        let sentences = request(None, 9 * 57).to_sentences("AI", 0).unwrap();
+        assert_eq!(sentences.len(), 9);
+        assert!(sentences[8].starts_with("!AIBBM,9,9,"));
+        assert_eq!(request(None, 9 * 57 + 1).to_sentences("AI", 0), None);
+        assert_eq!(
+            request(Some(316123456), 9 * 47)
+                .to_sentences("AI", 0)
+                .map(|s| s.len()),
+            Some(9)
+        );
+        assert_eq!(
+            request(Some(316123456), 9 * 47 + 1).to_sentences("AI", 0),
+            None
+        );
+
+        // Request which is not sent does not take sequence id
+        let mut tracker = TransmitTracker::new(10.0);
+        assert_eq!(tracker.send(&request(None, 9 * 57 + 1), 0.0), None);
+        assert_eq!(tracker.pending(), 0);
+        let sentences = tracker.send(&request(None, 10), 0.0).unwrap();
+        assert!(sentences[0].starts_with("!AIBBM,1,1,0,"));
+    }
+
+    #[test]
+    fn air() {
+        let air = NmeaAirMessage::interrogate(316123456, 5)
+            .with_second_message(24)
+            .with_channel("B");
+        let sentence = air.to_sentence("AI");
+        assert_eq!(sentence, "$AIAIR,316123456,5,,24,,,,,B,,,*10\r\n");
+        let parsed: NmeaAirMessage = parse(&sentence);
+        assert_eq!(parsed.mmsi, Some(316123456));
+        assert_eq!(
+            (parsed.message_id, parsed.second_message_id),
+            (Some(5), Some(24))
+        );
+        assert_eq!(parsed.channel.as_deref(), Some("B"));
+    }
+
+    #[test]
+    fn tracker() {
+        let mut tracker = TransmitTracker::new(10.0);
+        let abm = TransmitRequest::safety(&safety_text(Some(316123456), "GOOD"));
+        let bbm = TransmitRequest::safety(&safety_text(None, "SECURITE"));
+        assert!(tracker.send(&abm, 0.0).unwrap()[0].starts_with("!AIABM,1,1,0,"));
+        assert!(tracker.send(&abm, 1.0).unwrap()[0].starts_with("!AIABM,1,1,1,"));
+        assert!(tracker.send(&bbm, 1.0).unwrap()[0].starts_with("!AIBBM,1,1,0,"));
+        let air = tracker.send_interrogation(&NmeaAirMessage::interrogate(316123456, 5), 2.0);
+        assert!(air.starts_with("$AIAIR,"));
+        assert_eq!(tracker.pending(), 4);
+
+        let abk: NmeaAbkMessage = parse("$AIABK,316123456,A,12,1,0*1C");
+        assert!(abk.is_success());
+        assert_eq!(
+            tracker.update(&abk, 3.0),
+            Some((
+                Transmission {
+                    destination: Some(316123456),
+                    message_id: 12,
+                    sequence_id: 1
+                },
+                TransmitResult::Acknowledged(AbkAckType::Received)
+            ))
+        );
+        // Repeated acknowledgement is unexpected
+        assert_eq!(tracker.update(&abk, 3.0), None);
+
+        let abk: NmeaAbkMessage = parse("$AIABK,,B,14,0,3*28");
+        assert_eq!(
+            tracker.update(&abk, 4.0).map(|(t, _)| t.message_id),
+            Some(14)
+        );
+        let abk: NmeaAbkMessage = parse("$AIABK,316123456,A,15,1,3*18");
+        assert_eq!(
+            tracker.update(&abk, 4.0).map(|(t, r)| (t.destination, r)),
+            Some((
+                Some(316123456),
+                TransmitResult::Acknowledged(AbkAckType::Broadcast)
+            ))
+        );
+
+        assert!(tracker.expire(5.0).is_empty());
+        assert_eq!(
+            tracker.expire(10.5),
+            vec![(
+                Transmission {
+                    destination: Some(316123456),
+                    message_id: 12,
+                    sequence_id: 0
+                },
+                TransmitResult::TimedOut
+            )]
+        );
+        assert_eq!(tracker.pending(), 0);
+    }
+}
@@ -0,0 +1,465 @@
+//! Table of AIS targets merging dynamic (position) and static (type 5, 19, 24) data by MMSI.
+
+use std::collections::BTreeMap;
+
+use crate::fields::{Distance, Speed};
+
+use super::{AisMessage, Dimensions, Eta, NavigationStatus, RateOfTurn, ShipType, StaticDataPart};
+
+/// Reporting interval of static data and of stations without position reports, seconds.
+const STATIC_INTERVAL: f64 = 360.0;
+
+/// Kind of AIS station.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StationClass {
+    ClassA,
+    /// Class B using self-organized TDMA.
+    ClassBSotdma,
+    /// Class B using carrier sense TDMA.
+    ClassBCs,
+    BaseStation,
+    AidToNavigation,
+    SarAircraft,
+    /// Class A station received only by long-range broadcast (type 27), e.g. via satellite.
+    LongRange,
+}
+
+/// AIS target state. Values not reported yet are None.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AisTarget {
+    pub mmsi: u32,
+    pub class: StationClass,
+    pub name: Option<String>,
+    pub callsign: Option<String>,
+    pub imo: Option<u32>,
+    pub ship_type: Option<ShipType>,
+    pub dimensions: Option<Dimensions>,
+    pub destination: Option<String>,
+    pub eta: Option<Eta>,
+    pub draught: Option<Distance>,
+    /// Vendor ID of class B unit.
+    pub vendor_id: Option<String>,
+    pub nav_status: Option<NavigationStatus>,
+    /// Position as (latitude, longitude) in degrees.
+    pub position: Option<(f64, f64)>,
+    pub sog: Option<Speed>,
+    pub cog: Option<f64>,
+    pub heading: Option<u32>,
+    pub rate_of_turn: Option<RateOfTurn>,
+    /// Time of last position report, seconds.
+    pub position_time: Option<f64>,
+    /// Time of last message, seconds.
+    pub last_seen: f64,
+}
+
+impl AisTarget {
+    fn new(mmsi: u32, class: StationClass, time: f64) -> Self {
+        AisTarget {
+            mmsi,
+            class,
+            name: None,
+            callsign: None,
+            imo: None,
+            ship_type: None,
+            dimensions: None,
+            destination: None,
+            eta: None,
+            draught: None,
+            vendor_id: None,
+            nav_status: None,
+            position: None,
+            sog: None,
+            cog: None,
+            heading: None,
+            rate_of_turn: None,
+            position_time: None,
+            last_seen: time,
+        }
+    }
+
+    /// Nominal reporting interval of position reports by class, speed and navigational
+    /// status (ITU-R M.1371), seconds. Course changes are not taken into account.
+    pub fn reporting_interval(&self) -> f64 {
+        if self.position_time.is_none() {
+            return STATIC_INTERVAL;
+        }
+        let sog = self.sog.map_or(0.0, |s| s.knots());
+        match self.class {
+            StationClass::ClassA => {
+                let stationary = matches!(
+                    self.nav_status,
+                    Some(NavigationStatus::AtAnchor | NavigationStatus::Moored)
+                );
+                match sog {
+                    s if stationary && s <= 3.0 => 180.0,
+                    s if s > 23.0 => 2.0,
+                    s if s > 14.0 => 6.0,
+                    _ => 10.0,
+                }
+            }
+            StationClass::ClassBSotdma => match sog {
+                s if s <= 2.0 => 180.0,
+                s if s > 23.0 => 5.0,
+                s if s > 14.0 => 15.0,
+                _ => 30.0,
+            },
+            StationClass::ClassBCs => {
+                if sog <= 2.0 {
+                    180.0
+                } else {
+                    30.0
+                }
+            }
+            StationClass::BaseStation | StationClass::SarAircraft => 10.0,
+            StationClass::AidToNavigation | StationClass::LongRange => 180.0,
+        }
+    }
+
+    fn set_position(&mut self, position: Option<(f64, f64)>, time: f64) {
+        self.position = position;
+        self.position_time = Some(time);
+    }
+}
+
+/// Change of AIS target table.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TargetEvent {
+    /// First message from MMSI.
+    New(u32),
+    /// No message received within timeout.
+    Lost(u32),
+    NameChanged {
+        mmsi: u32,
+        old: Option<String>,
+        new: String,
+    },
+}
+
+/// Table of AIS targets by MMSI. Targets are removed when no message is received for a number
+/// of their reporting intervals.
+#[derive(Debug)]
+pub struct AisVesselTable {
+    targets: BTreeMap<u32, AisTarget>,
+    /// Number of missed reporting intervals after which target is lost.
+    lost_intervals: f64,
+}
+
+impl AisVesselTable {
+    /// Table losing targets after 5 missed reporting intervals.
+    pub fn new() -> Self {
+        AisVesselTable {
+            targets: BTreeMap::new(),
+            lost_intervals: 5.0,
+        }
+    }
+
+    pub fn with_lost_intervals(mut self, intervals: f64) -> Self {
+        self.lost_intervals = intervals;
+        self
+    }
+
+    /// Merges message into target of its MMSI. `time` is in seconds.
+    /// Acknowledgements, binary and safety messages only refresh known targets.
+    pub fn update(&mut self, msg: &AisMessage, time: f64) -> Vec<TargetEvent> {
+        let mmsi = msg.mmsi();
+        let class = match msg {
+            AisMessage::PositionReport(_) | AisMessage::StaticVoyageData(_) => StationClass::ClassA,
+            AisMessage::LongRangePositionReport(_) => StationClass::LongRange,
+            AisMessage::ClassBPositionReport(m) if m.cs_unit => StationClass::ClassBCs,
+            AisMessage::ClassBPositionReport(_) | AisMessage::ClassBExtendedReport(_) => {
+                StationClass::ClassBSotdma
+            }
+            AisMessage::StaticDataReport(_) => StationClass::ClassBCs,
+            AisMessage::BaseStationReport(m) if m.message_type == 4 => StationClass::BaseStation,
+            AisMessage::SarAircraftReport(_) => StationClass::SarAircraft,
+            AisMessage::AtonReport(_) => StationClass::AidToNavigation,
+            _ => {
+                if let Some(target) = self.targets.get_mut(&mmsi) {
+                    target.last_seen = time;
+                }
+                return Vec::new();
+            }
+        };
+
+        let mut events = Vec::new();
+        let target = self.targets.entry(mmsi).or_insert_with(|| {
+            events.push(TargetEvent::New(mmsi));
+            AisTarget::new(mmsi, class, time)
+        });
+        target.last_seen = time;
+        let mut name = None;
+        match msg {
+            AisMessage::PositionReport(m) => {
+                target.class = class;
+                target.nav_status = m.nav_status;
+                target.rate_of_turn = m.rate_of_turn;
+                target.sog = m.sog;
+                target.cog = m.cog;
+                target.heading = m.heading;
+                target.set_position(m.position(), time);
+            }
+            AisMessage::LongRangePositionReport(m) => {
+                // Position is no longer reported at class A intervals
+                target.class = class;
+                target.nav_status = m.nav_status;
+                target.sog = m.sog;
+                target.cog = m.cog.map(f64::from);
+                target.set_position(m.position(), time);
+            }
+            AisMessage::ClassBPositionReport(m) => {
+                target.class = class;
+                target.sog = m.sog;
+                target.cog = m.cog;
+                target.heading = m.heading;
+                target.set_position(m.position(), time);
+            }
+            AisMessage::ClassBExtendedReport(m) => {
+                target.sog = m.sog;
+                target.cog = m.cog;
+                target.heading = m.heading;
+                target.set_position(m.position(), time);
+                target.ship_type = m.ship_type.or(target.ship_type);
+                target.dimensions = m.dimensions.or(target.dimensions);
+                name = Some(m.name.as_str());
+            }
+            AisMessage::StaticVoyageData(m) => {
+                if target.class != StationClass::LongRange {
+                    target.class = class;
+                }
+                target.imo = m.imo;
+                target.callsign = non_empty(&m.callsign);
+                target.ship_type = m.ship_type;
+                target.dimensions = m.dimensions;
+                target.destination = non_empty(&m.destination);
+                target.eta = Some(m.eta);
+                target.draught = m.draught;
+                name = Some(m.name.as_str());
+            }
+            AisMessage::StaticDataReport(m) => match &m.part {
+                StaticDataPart::A { name: part_name } => name = Some(part_name.as_str()),
+                StaticDataPart::B {
+                    ship_type,
+                    vendor_id,
+                    callsign,
+                    dimensions,
+                    ..
+                } => {
+                    target.ship_type = *ship_type;
+                    target.vendor_id = non_empty(vendor_id);
+                    target.callsign = non_empty(callsign);
+                    target.dimensions = *dimensions;
+                }
+            },
+            AisMessage::BaseStationReport(m) => target.set_position(m.position(), time),
+            AisMessage::SarAircraftReport(m) => {
+                target.sog = m.sog;
+                target.cog = m.cog;
+                target.set_position(m.position(), time);
+            }
+            AisMessage::AtonReport(m) => {
+                target.dimensions = m.dimensions;
+                target.set_position(m.position(), time);
+                name = Some(m.name.as_str());
+            }
+            _ => {}
+        }
+
+        if let Some(new) = name.and_then(non_empty) {
+            if target.name.as_ref() != Some(&new) {
+                events.push(TargetEvent::NameChanged {
+                    mmsi,
+                    old: target.name.replace(new.clone()),
+                    new,
+                });
+            }
+        }
+        events
+    }
+
+    /// Removes targets without messages for `lost_intervals` reporting intervals.
+    pub fn expire(&mut self, time: f64) -> Vec<TargetEvent> {
+        let mut events = Vec::new();
+        self.targets.retain(|mmsi, target| {
+            let keep = time - target.last_seen <= target.reporting_interval() * self.lost_intervals;
+            if !keep {
+                events.push(TargetEvent::Lost(*mmsi));
+            }
+            keep
+        });
+        events
+    }
+
+    pub fn get(&self, mmsi: u32) -> Option<&AisTarget> {
+        self.targets.get(&mmsi)
+    }
+
+    /// Targets sorted by MMSI.
+    pub fn targets(&self) -> impl Iterator<Item = &AisTarget> {
+        self.targets.values()
+    }
+
+    /// Copy of all targets sorted by MMSI.
+    pub fn snapshot(&self) -> Vec<AisTarget> {
+        self.targets.values().cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+}
+
+impl Default for AisVesselTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn non_empty(text: &str) -> Option<String> {
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ais::{decode_armored, AisMessage, ShipType};
+
+    use super::{AisVesselTable, StationClass, TargetEvent};
+
+    fn msg(payload: &str, fill_bits: u32) -> AisMessage {
+        decode_armored(payload, fill_bits).unwrap()
+    }
+
+    #[test]
+    fn merge_position_and_static_data() {
+        let mut table = AisVesselTable::new();
+        let mut position = msg("15M67FC000G?ufbE`FepT@3n00Sa", 0);
+        let AisMessage::PositionReport(report) = &mut position else {
+            panic!()
+        };
+        report.mmsi = 351759000;
+        assert_eq!(
+            table.update(&position, 0.0),
+            vec![TargetEvent::New(351759000)]
+        );
+        let events = table.update(
+            &msg(
+                "55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp888888888880",
+                2,
+            ),
+            1.0,
+        );
+        assert_eq!(
+            events,
+            vec![TargetEvent::NameChanged {
+                mmsi: 351759000,
+                old: None,
+                new: "EVER DIADEM".to_string()
+            }]
+        );
+        // Same name again is not a change
+        assert!(table
+            .update(
+                &msg(
+                    "55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp888888888880",
+                    2,
+                ),
+                2.0,
+            )
+            .is_empty());
+
+        let target = table.get(351759000).unwrap();
+        assert_eq!(target.class, StationClass::ClassA);
+        assert!(target.position.is_some());
+        assert_eq!(target.position_time, Some(0.0));
+        assert_eq!(target.cog, Some(219.3));
+        assert_eq!(target.callsign.as_deref(), Some("3FOF8"));
+        assert_eq!(target.destination.as_deref(), Some("NEW YORK"));
+        assert_eq!(target.imo, Some(9134270));
+        assert_eq!(target.last_seen, 2.0);
+    }
+
+    #[test]
+    fn merge_class_b_parts() {
+        let mut table = AisVesselTable::new();
+        let events = table.update(&msg("H42O55i18tMET00000000000000", 2), 0.0);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0], TargetEvent::New(271041815));
+        table.update(&msg("H42O55lti4hhhilD3nink000?050", 0), 1.0);
+
+        let target = table.get(271041815).unwrap();
+        assert_eq!(target.class, StationClass::ClassBCs);
+        assert_eq!(target.name.as_deref(), Some("PROGUY"));
+        assert_eq!(target.ship_type, Some(ShipType::Passenger(0)));
+        assert_eq!(target.callsign.as_deref(), Some("TC6163"));
+        assert!(target.dimensions.is_some());
+        assert_eq!(target.position, None);
+    }
+
+    #[test]
+    fn lost_targets() {
+        let mut table = AisVesselTable::new();
+        // Class A underway at 0 knots reports every 10 s
+        table.update(&msg("15M67FC000G?ufbE`FepT@3n00Sa", 0), 0.0);
+        // Class B CS at 0.1 knots reports every 3 minutes
+        table.update(&msg("B52K>;h00Fc>jpUlNV@ikwpUoP06", 0), 0.0);
+        assert_eq!(table.get(366053209).unwrap().reporting_interval(), 10.0);
+        let class_b = table.get(338087471).unwrap();
+        assert_eq!(class_b.class, StationClass::ClassBCs);
+        assert_eq!(class_b.reporting_interval(), 180.0);
+
+        assert!(table.expire(50.0).is_empty());
+        assert_eq!(table.expire(51.0), vec![TargetEvent::Lost(366053209)]);
+        assert_eq!(table.len(), 1);
+
+        // Acknowledgement refreshes known target
+        let mut ack = msg("702R5`hwCjq8", 0);
+        let AisMessage::Acknowledgement(a) = &mut ack else {
+            panic!()
+        };
+        a.mmsi = 338087471;
+        assert!(table.update(&ack, 100.0).is_empty());
+        assert!(table.expire(1000.0).is_empty());
+        assert_eq!(table.expire(1001.0), vec![TargetEvent::Lost(338087471)]);
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn long_range_target() {
+        let mut table = AisVesselTable::new();
+        let long_range = msg("KC5E2b@U19PFdLbL", 0);
+        let mmsi = long_range.mmsi();
+        table.update(&long_range, 0.0);
+        let target = table.get(mmsi).unwrap();
+        assert_eq!(target.class, StationClass::LongRange);
+        assert_eq!(target.reporting_interval(), 180.0);
+        assert!(table.expire(900.0).is_empty());
+
+        // Class A target leaving range of regular reports
+        let mut position = msg("15M67FC000G?ufbE`FepT@3n00Sa", 0);
+        let AisMessage::PositionReport(report) = &mut position else {
+            panic!()
+        };
+        report.mmsi = mmsi;
+        table.update(&position, 1000.0);
+        assert_eq!(table.get(mmsi).unwrap().reporting_interval(), 10.0);
+        table.update(&long_range, 1010.0);
+        assert_eq!(table.get(mmsi).unwrap().reporting_interval(), 180.0);
+        assert!(table.expire(1900.0).is_empty());
+        assert_eq!(table.expire(1911.0), vec![TargetEvent::Lost(mmsi)]);
+    }
+
+    #[test]
+    fn snapshot() {
+        let mut table = AisVesselTable::new();
+        table.update(&msg("B52K>;h00Fc>jpUlNV@ikwpUoP06", 0), 0.0);
+        table.update(&msg("15M67FC000G?ufbE`FepT@3n00Sa", 0), 0.0);
+        let snapshot = table.snapshot();
+        table.expire(10_000.0);
+        assert!(table.is_empty());
+        let mmsis: Vec<u32> = snapshot.iter().map(|t| t.mmsi).collect();
+        assert_eq!(mmsis, vec![338087471, 366053209]);
+    }
+}
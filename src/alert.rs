@@ -0,0 +1,505 @@
+//! Bridge alert management (IEC 62923): alert states tracked from ALR\ALF\ALC and ACN\ACK\HBT sentences to send.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    fields::{
+        AckState, AlarmCondition, AlertCategory, AlertCommand, AlertPriority, AlertState,
+        DataStatus,
+    },
+    generated::nmea3::{
+        NmeaAckMessage, NmeaAcnMessage, NmeaAlcMessage, NmeaAlfMessage, NmeaAlrMessage,
+        NmeaArcMessage, NmeaHbtMessage,
+    },
+    messages::MessageFields,
+};
+
+/// Alert identity. Instance distinguishes alerts of the same type (e.g. lost target number).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AlertKey {
+    /// Manufacturer mnemonic code. `None` for standardized alerts.
+    pub manufacturer: Option<String>,
+    pub id: u32,
+    pub instance: Option<u32>,
+}
+
+impl AlertKey {
+    pub fn new(manufacturer: Option<&str>, id: u32, instance: Option<u32>) -> Self {
+        AlertKey {
+            manufacturer: manufacturer.map(String::from),
+            id,
+            instance,
+        }
+    }
+
+    /// Key of legacy ALR alarm, which has neither manufacturer nor instance.
+    pub fn is_legacy(&self) -> bool {
+        self.manufacturer.is_none() && self.instance.is_none()
+    }
+}
+
+/// Alert entry of ALC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlcEntry {
+    pub key: AlertKey,
+    pub revision: Option<u32>,
+}
+
+impl NmeaAlcMessage {
+    /// Alert entries listed in this message.
+    pub fn alerts(&self) -> Vec<AlcEntry> {
+        self.entries
+            .chunks_exact(4)
+            .filter_map(|e| {
+                let manufacturer = Some(e[0].as_str()).filter(|m| !m.is_empty());
+                Some(AlcEntry {
+                    key: AlertKey::new(manufacturer, e[1].parse().ok()?, e[2].parse().ok()),
+                    revision: e[3].parse().ok(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl NmeaAlfMessage {
+    pub fn key(&self) -> Option<AlertKey> {
+        Some(AlertKey::new(
+            self.manufacturer.as_deref(),
+            self.alert_id?,
+            self.instance,
+        ))
+    }
+}
+
+impl NmeaAcnMessage {
+    pub fn key(&self) -> Option<AlertKey> {
+        Some(AlertKey::new(
+            self.manufacturer.as_deref(),
+            self.alert_id?,
+            self.instance,
+        ))
+    }
+}
+
+impl NmeaArcMessage {
+    pub fn key(&self) -> Option<AlertKey> {
+        Some(AlertKey::new(
+            self.manufacturer.as_deref(),
+            self.alert_id?,
+            self.instance,
+        ))
+    }
+}
+
+/// Alert state as seen by operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlertStatus {
+    /// Active and unacknowledged.
+    Active,
+    /// Active and acknowledged (or responsibility is transferred).
+    Acknowledged,
+    /// Active and unacknowledged, audible signal is silenced.
+    Silenced,
+    /// Condition is over, but alert is not acknowledged.
+    Rectified,
+}
+
+impl AlertStatus {
+    /// Converts ALF state. Returns `None` for normal state (alert is over).
+    pub fn from_alert_state(state: AlertState) -> Option<Self> {
+        match state {
+            AlertState::ActiveUnacknowledged => Some(AlertStatus::Active),
+            AlertState::ActiveSilenced => Some(AlertStatus::Silenced),
+            AlertState::ActiveAcknowledged | AlertState::ActiveTransferred => {
+                Some(AlertStatus::Acknowledged)
+            }
+            AlertState::RectifiedUnacknowledged => Some(AlertStatus::Rectified),
+            AlertState::Normal => None,
+        }
+    }
+
+    /// Converts ALR condition and acknowledge state. Returns `None` for acknowledged normal condition.
+    pub fn from_alarm(condition: AlarmCondition, ack: AckState) -> Option<Self> {
+        match (condition, ack) {
+            (AlarmCondition::Exceeded, AckState::Unacknowledged) => Some(AlertStatus::Active),
+            (AlarmCondition::Exceeded, AckState::Acknowledged) => Some(AlertStatus::Acknowledged),
+            (AlarmCondition::Normal, AckState::Unacknowledged) => Some(AlertStatus::Rectified),
+            (AlarmCondition::Normal, AckState::Acknowledged) => None,
+        }
+    }
+}
+
+/// Alert tracked by [`AlertManager`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alert {
+    pub key: AlertKey,
+    pub status: AlertStatus,
+    /// `None` for legacy ALR alarms.
+    pub priority: Option<AlertPriority>,
+    pub category: Option<AlertCategory>,
+    /// Alert title.
+    pub text: Option<String>,
+    /// Alert description from the second ALF sentence.
+    pub description: Option<String>,
+    pub revision: Option<u32>,
+    pub escalation: Option<u32>,
+    /// Time of last change, hhmmss.ss.
+    pub utc: Option<String>,
+}
+
+impl Alert {
+    fn new(key: AlertKey, status: AlertStatus) -> Self {
+        Alert {
+            key,
+            status,
+            priority: None,
+            category: None,
+            text: None,
+            description: None,
+            revision: None,
+            escalation: None,
+            utc: None,
+        }
+    }
+
+    /// Alert can be acknowledged at the bridge: it is not acknowledged yet and is not category C.
+    pub fn can_acknowledge(&self) -> bool {
+        self.status != AlertStatus::Acknowledged && self.category != Some(AlertCategory::C)
+    }
+
+    /// Audible signal of alert can be silenced.
+    pub fn can_silence(&self) -> bool {
+        self.status == AlertStatus::Active
+    }
+}
+
+/// Acknowledge command: ACN for alerts, ACK for legacy ALR alarms.
+#[derive(Debug)]
+pub enum AcknowledgeCommand {
+    Acn(NmeaAcnMessage),
+    Ack(NmeaAckMessage),
+}
+
+impl AcknowledgeCommand {
+    pub fn to_sentence(&self, talker_id: &str) -> String {
+        match self {
+            AcknowledgeCommand::Acn(acn) => acn.to_sentence(talker_id),
+            AcknowledgeCommand::Ack(ack) => ack.to_sentence(talker_id),
+        }
+    }
+}
+
+/// Tracks alerts reported by equipment and builds alert commands and heartbeats to send.
+/// Alerts are removed when they return to normal state.
+#[derive(Debug)]
+pub struct AlertManager {
+    alerts: BTreeMap<AlertKey, Alert>,
+    /// Heartbeat interval, seconds.
+    heartbeat_interval: f64,
+    /// Sequential identifier of the next HBT, 0-9.
+    heartbeat_seq: u32,
+}
+
+impl AlertManager {
+    pub fn new(heartbeat_interval: f64) -> Self {
+        AlertManager {
+            alerts: BTreeMap::new(),
+            heartbeat_interval,
+            heartbeat_seq: 0,
+        }
+    }
+
+    /// Updates legacy alarm from ALR. Returns updated alert or `None` if alarm is over.
+    pub fn update_alr(&mut self, alr: &NmeaAlrMessage) -> Option<&Alert> {
+        let key = AlertKey::new(None, alr.alarm_id?, None);
+        let Some(status) = AlertStatus::from_alarm(alr.condition?, alr.ack_state?) else {
+            self.alerts.remove(&key);
+            return None;
+        };
+        let alert = self
+            .alerts
+            .entry(key.clone())
+            .or_insert_with(|| Alert::new(key, status));
+        alert.status = status;
+        alert.text.clone_from(&alr.text);
+        alert.utc.clone_from(&alr.utc);
+        Some(alert)
+    }
+
+    /// Updates alert from ALF. Returns updated alert or `None` if alert is over.
+    /// The second sentence of a pair only updates description of known alert.
+    pub fn update_alf(&mut self, alf: &NmeaAlfMessage) -> Option<&Alert> {
+        let key = alf.key()?;
+        if alf.sentence_number == Some(2) {
+            let alert = self.alerts.get_mut(&key)?;
+            alert.description.clone_from(&alf.text);
+            return Some(alert);
+        }
+        let Some(status) = AlertStatus::from_alert_state(alf.state?) else {
+            self.alerts.remove(&key);
+            return None;
+        };
+        let alert = self
+            .alerts
+            .entry(key.clone())
+            .or_insert_with(|| Alert::new(key, status));
+        if alert.revision != alf.revision {
+            alert.description = None;
+        }
+        alert.status = status;
+        alert.priority = alf.priority;
+        alert.category = alf.category;
+        alert.text.clone_from(&alf.text);
+        alert.revision = alf.revision;
+        alert.escalation = alf.escalation;
+        alert.utc.clone_from(&alf.utc);
+        Some(alert)
+    }
+
+    /// Checks alert list from ALC. Returns alerts which are unknown or have newer revision,
+    /// their details can be requested with [`AlertManager::request`].
+    pub fn update_alc(&mut self, alc: &NmeaAlcMessage) -> Vec<AlertKey> {
+        alc.alerts()
+            .into_iter()
+            .filter(|e| {
+                self.alerts
+                    .get(&e.key)
+                    .is_none_or(|a| a.revision != e.revision)
+            })
+            .map(|e| e.key)
+            .collect()
+    }
+
+    /// Returns alert and command refused by equipment (ARC).
+    pub fn update_arc(&self, arc: &NmeaArcMessage) -> Option<(&Alert, AlertCommand)> {
+        Some((self.alerts.get(&arc.key()?)?, arc.command?))
+    }
+
+    /// Builds alert command. Alert state is changed when equipment reports it.
+    pub fn command(key: &AlertKey, command: AlertCommand) -> NmeaAcnMessage {
+        NmeaAcnMessage {
+            manufacturer: key.manufacturer.clone(),
+            alert_id: Some(key.id),
+            instance: key.instance,
+            command: Some(command),
+            status: Some(b'C'),
+            ..Default::default()
+        }
+    }
+
+    /// Builds acknowledge command. Returns `None` if alert is unknown or can't be acknowledged.
+    pub fn acknowledge(&self, key: &AlertKey) -> Option<AcknowledgeCommand> {
+        let alert = self.alerts.get(key).filter(|a| a.can_acknowledge())?;
+        if alert.key.is_legacy() {
+            return Some(AcknowledgeCommand::Ack(NmeaAckMessage {
+                alarm_id: Some(alert.key.id),
+            }));
+        }
+        Some(AcknowledgeCommand::Acn(Self::command(
+            &alert.key,
+            AlertCommand::Acknowledge,
+        )))
+    }
+
+    /// Builds silence command. Returns `None` if alert is unknown, is not active
+    /// or is a legacy ALR alarm, which can't be silenced remotely.
+    pub fn silence(&self, key: &AlertKey) -> Option<NmeaAcnMessage> {
+        self.alerts
+            .get(key)
+            .filter(|a| a.can_silence() && !a.key.is_legacy())
+            .map(|a| Self::command(&a.key, AlertCommand::Silence))
+    }
+
+    /// Builds command requesting repeat of alert information.
+    pub fn request(&self, key: &AlertKey) -> NmeaAcnMessage {
+        Self::command(key, AlertCommand::Request)
+    }
+
+    /// Builds next heartbeat. Shall be sent every `heartbeat_interval` seconds.
+    pub fn heartbeat(&mut self, status: DataStatus) -> NmeaHbtMessage {
+        let hbt = NmeaHbtMessage {
+            interval: Some(self.heartbeat_interval),
+            status: Some(status),
+            sequence_id: Some(self.heartbeat_seq),
+        };
+        self.heartbeat_seq = (self.heartbeat_seq + 1) % 10;
+        hbt
+    }
+
+    pub fn get(&self, key: &AlertKey) -> Option<&Alert> {
+        self.alerts.get(key)
+    }
+
+    /// Alerts sorted by key.
+    pub fn alerts(&self) -> impl Iterator<Item = &Alert> {
+        self.alerts.values()
+    }
+
+    /// Alerts which are not acknowledged yet.
+    pub fn unacknowledged(&self) -> impl Iterator<Item = &Alert> {
+        self.alerts
+            .values()
+            .filter(|a| a.status != AlertStatus::Acknowledged)
+    }
+
+    pub fn len(&self) -> usize {
+        self.alerts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.alerts.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fields::{AlertCategory, AlertCommand, AlertPriority, AlertState, DataStatus},
+        generated::nmea3::{
+            NmeaAcnMessage, NmeaAlcMessage, NmeaAlfMessage, NmeaAlrMessage, NmeaArcMessage,
+            NmeaHbtMessage,
+        },
+        messages::MessageFields,
+        test_util::parse,
+    };
+
+    use super::{AlertKey, AlertManager, AlertStatus};
+
+    #[test]
+    fn alf_round_trip() {
+        let sentence = "$HNALF,1,1,0,124304.50,A,W,A,,192,1,1,0,LOST TARGET*12";
+        let alf: NmeaAlfMessage = parse(sentence);
+        assert_eq!(alf.category, Some(AlertCategory::A));
+        assert_eq!(alf.priority, Some(AlertPriority::Warning));
+        assert_eq!(alf.state, Some(AlertState::ActiveAcknowledged));
+        assert_eq!(alf.key(), Some(AlertKey::new(None, 192, Some(1))));
+        assert_eq!(alf.text.as_deref(), Some("LOST TARGET"));
+        assert_eq!(alf.to_sentence("HN"), format!("{sentence}\r\n"));
+    }
+
+    #[test]
+    fn alc_entries() {
+        let alc: NmeaAlcMessage = parse("$HNALC,01,01,00,02,,3015,1,2,FEC,192,1,1*34");
+        assert_eq!(alc.entries_count, Some(2));
+        let alerts = alc.alerts();
+        assert_eq!(alerts.len(), 2);
+        assert_eq!(alerts[0].key, AlertKey::new(None, 3015, Some(1)));
+        assert_eq!(alerts[0].revision, Some(2));
+        assert_eq!(alerts[1].key, AlertKey::new(Some("FEC"), 192, Some(1)));
+    }
+
+    #[test]
+    fn acn_arc_hbt() {
+        let acn: NmeaAcnMessage = parse("$VRACN,124305.00,,192,1,A,C*6E");
+        assert_eq!(acn.command, Some(AlertCommand::Acknowledge));
+        assert_eq!(acn.status, Some(b'C'));
+        let arc: NmeaArcMessage = parse("$HNARC,124305.00,,192,1,S*0D");
+        assert_eq!(arc.key(), acn.key());
+        assert_eq!(arc.command, Some(AlertCommand::Silence));
+        let hbt: NmeaHbtMessage = parse("$HNHBT,60,A,3*00");
+        assert_eq!(hbt.interval, Some(60.0));
+        assert_eq!(hbt.status, Some(DataStatus::Valid));
+        assert_eq!(hbt.sequence_id, Some(3));
+    }
+
+    #[test]
+    fn alr_lifecycle() {
+        let mut manager = AlertManager::new(60.0);
+        let alert = manager
+            .update_alr(&parse("$IIALR,020000,031,A,V,Bilge pump alarm1*4B"))
+            .unwrap();
+        assert_eq!(alert.status, AlertStatus::Active);
+        assert_eq!(alert.text.as_deref(), Some("Bilge pump alarm1"));
+
+        let key = AlertKey::new(None, 31, None);
+        assert!(manager.silence(&key).is_none());
+        let ack = manager.acknowledge(&key).unwrap();
+        assert_eq!(ack.to_sentence("VR"), "$VRACK,031*53\r\n");
+
+        let alr: NmeaAlrMessage = parse("$IIALR,020010,031,V,V,Bilge pump alarm1*5D");
+        assert_eq!(
+            manager.update_alr(&alr).unwrap().status,
+            AlertStatus::Rectified
+        );
+        assert!(manager.silence(&key).is_none());
+        assert!(manager
+            .update_alr(&parse("$IIALR,020020,031,V,A,Bilge pump alarm1*49"))
+            .is_none());
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn alf_lifecycle() {
+        let mut manager = AlertManager::new(60.0);
+        let key = AlertKey::new(None, 192, Some(4));
+        manager.update_alf(&parse(
+            "$HNALF,2,1,1,124304.50,A,W,V,,192,4,1,0,LOST TARGET*02",
+        ));
+        let alert = manager
+            .update_alf(&parse("$HNALF,2,2,1,,,,,,192,4,1,0,Target 4 lost*5E"))
+            .unwrap();
+        assert_eq!(alert.status, AlertStatus::Active);
+        assert_eq!(alert.priority, Some(AlertPriority::Warning));
+        assert_eq!(alert.description.as_deref(), Some("Target 4 lost"));
+        assert_eq!(manager.unacknowledged().count(), 1);
+
+        let silence = manager.silence(&key).unwrap();
+        assert_eq!(silence.command, Some(AlertCommand::Silence));
+        manager.update_alf(&parse(
+            "$HNALF,1,1,2,124310.00,A,W,S,,192,4,2,0,LOST TARGET*04",
+        ));
+        assert_eq!(manager.get(&key).unwrap().status, AlertStatus::Silenced);
+        assert_eq!(manager.get(&key).unwrap().description, None);
+
+        assert!(manager.acknowledge(&key).is_some());
+        manager.update_alf(&parse(
+            "$HNALF,1,1,3,124315.00,A,W,A,,192,4,3,0,LOST TARGET*13",
+        ));
+        assert!(manager.acknowledge(&key).is_none());
+        assert_eq!(manager.unacknowledged().count(), 0);
+
+        let (alert, command) = manager
+            .update_arc(&parse("$HNARC,124316.00,,192,4,S*0A"))
+            .unwrap();
+        assert_eq!(alert.key, key);
+        assert_eq!(command, AlertCommand::Silence);
+
+        let missing = manager.update_alc(&parse("$HNALC,01,01,00,02,,192,4,3,FEC,1,1,1*06"));
+        assert_eq!(missing, vec![AlertKey::new(Some("FEC"), 1, Some(1))]);
+        assert_eq!(
+            manager.request(&missing[0]).to_sentence("VR"),
+            "$VRACN,,FEC,1,1,Q,C*1A\r\n"
+        );
+
+        assert!(manager
+            .update_alf(&parse(
+                "$HNALF,1,1,4,124320.00,A,W,N,,192,4,4,0,LOST TARGET*1A",
+            ))
+            .is_none());
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn category_c_cannot_be_acknowledged() {
+        let mut manager = AlertManager::new(60.0);
+        manager.update_alf(&parse(
+            "$HNALF,1,1,0,124304.50,C,W,V,,192,1,1,0,LOST TARGET*07",
+        ));
+        assert!(manager
+            .acknowledge(&AlertKey::new(None, 192, Some(1)))
+            .is_none());
+    }
+
+    #[test]
+    fn heartbeat_sequence() {
+        let mut manager = AlertManager::new(30.0);
+        let ids: Vec<u32> = (0..12)
+            .map(|_| manager.heartbeat(DataStatus::Valid).sequence_id.unwrap())
+            .collect();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1]);
+        assert_eq!(
+            manager.heartbeat(DataStatus::Valid).to_sentence("VR"),
+            "$VRHBT,30,A,2*06\r\n"
+        );
+    }
+}
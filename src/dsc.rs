@@ -0,0 +1,302 @@
+//! Digital selective calling: typed DSC calls from DSC sentences, completed with DSE expansion
+//! data (enhanced position, speed, course).
+
+use crate::{
+    fields::{DistressNature, DscAcknowledgement, DscCategory, DscFormat},
+    generated::nmea3::{NmeaDscMessage, NmeaDseMessage},
+};
+
+/// Parses 10 digit DSC address (MMSI followed by 0).
+fn parse_mmsi(address: &Option<String>) -> Option<u32> {
+    let address = address.as_deref()?;
+    if address.len() != 10 || !address.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    address[..9].parse().ok()
+}
+
+/// Parses position qDDMMDDDMM as (latitude, longitude). Quadrant: 0-NE, 1-NW, 2-SE, 3-SW.
+fn parse_position(s: &str) -> Option<(f64, f64)> {
+    if s.len() != 10 || !s.bytes().all(|b| b.is_ascii_digit()) || s == "9999999999" {
+        return None;
+    }
+    let value = |range: std::ops::Range<usize>| s[range].parse::<f64>().ok();
+    let lat = value(1..3)? + value(3..5)? / 60.0;
+    let lon = value(5..8)? + value(8..10)? / 60.0;
+    if lat > 90.0 || lon > 180.0 {
+        return None;
+    }
+    match &s[..1] {
+        "0" => Some((lat, lon)),
+        "1" => Some((lat, -lon)),
+        "2" => Some((-lat, lon)),
+        "3" => Some((-lat, -lon)),
+        _ => None,
+    }
+}
+
+/// Parses UTC time hhmm as (hour, minute). 8888 means time is not available.
+fn parse_time(s: &str) -> Option<(u32, u32)> {
+    if s.len() != 4 || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let hour = s[..2].parse().ok()?;
+    let minute = s[2..].parse().ok()?;
+    (hour < 24 && minute < 60).then_some((hour, minute))
+}
+
+/// Decoded DSC call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DscCall {
+    pub format: DscFormat,
+    pub category: Option<DscCategory>,
+    /// MMSI of address field: called station, or ship in distress for distress alert.
+    /// `None` for geographic area calls.
+    pub mmsi: Option<u32>,
+    /// MMSI of ship in distress for distress alert, relay and acknowledgement.
+    pub distress_mmsi: Option<u32>,
+    pub nature: Option<DistressNature>,
+    /// Position as (latitude, longitude) in decimal degrees.
+    pub position: Option<(f64, f64)>,
+    /// UTC time of position as (hour, minute).
+    pub time: Option<(u32, u32)>,
+    pub acknowledgement: Option<DscAcknowledgement>,
+    /// DSE expansion sentence follows.
+    pub expansion: bool,
+    /// Speed in knots from DSE expansion.
+    pub speed: Option<f64>,
+    /// Course in degrees from DSE expansion.
+    pub course: Option<f64>,
+}
+
+impl DscCall {
+    /// Distress alert, distress relay or acknowledgement of distress alert.
+    pub fn is_distress(&self) -> bool {
+        self.format == DscFormat::Distress || self.category == Some(DscCategory::Distress)
+    }
+
+    /// Applies expansion data of DSE sentence.
+    pub fn apply_expansion(&mut self, dse: &NmeaDseMessage) {
+        for (code, data) in dse.expansion_data() {
+            match code {
+                // Enhanced position: 4 more decimals of minutes of latitude and longitude
+                "00" if data.len() == 8 && data.bytes().all(|b| b.is_ascii_digit()) => {
+                    if let Some((lat, lon)) = &mut self.position {
+                        let minutes = |s: &str| s.parse::<f64>().unwrap_or(0.0) / 10000.0 / 60.0;
+                        *lat += lat.signum() * minutes(&data[..4]);
+                        *lon += lon.signum() * minutes(&data[4..]);
+                    }
+                }
+                "02" => self.speed = data.parse::<f64>().ok().map(|v| v / 10.0),
+                "03" => self.course = data.parse::<f64>().ok().map(|v| v / 10.0),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl NmeaDscMessage {
+    /// Typed call. `None` if format specifier is missing or unknown.
+    pub fn call(&self) -> Option<DscCall> {
+        let format = self.format?;
+        let mmsi = match format {
+            DscFormat::GeographicArea => None,
+            _ => parse_mmsi(&self.address),
+        };
+        let (category, distress_mmsi, nature) = if format == DscFormat::Distress {
+            let nature = self
+                .first_telecommand
+                .as_deref()
+                .and_then(|s| DistressNature::from_code(s.as_bytes()));
+            (Some(DscCategory::Distress), mmsi, nature)
+        } else {
+            (
+                self.category,
+                parse_mmsi(&self.distress_mmsi),
+                self.distress_nature,
+            )
+        };
+        Some(DscCall {
+            format,
+            category,
+            mmsi,
+            distress_mmsi,
+            nature,
+            position: self.position.as_deref().and_then(parse_position),
+            time: self.time.as_deref().and_then(parse_time),
+            acknowledgement: self.acknowledgement,
+            expansion: self.expansion.as_deref() == Some("E"),
+            speed: None,
+            course: None,
+        })
+    }
+}
+
+impl NmeaDseMessage {
+    /// MMSI of the station of expanded DSC call.
+    pub fn mmsi(&self) -> Option<u32> {
+        parse_mmsi(&self.address)
+    }
+
+    /// Pairs of expansion data specifier and data.
+    pub fn expansion_data(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.data
+            .chunks_exact(2)
+            .map(|pair| (pair[0].as_str(), pair[1].as_str()))
+    }
+
+    pub fn is_last(&self) -> bool {
+        self.sentence_number >= self.total_sentences
+    }
+}
+
+/// Correlates DSC calls with following DSE expansion sentences.
+/// Calls announcing expansion are held until the last DSE sentence is received or timeout elapses.
+#[derive(Debug)]
+pub struct DscMonitor {
+    /// Time to wait for expansion, seconds.
+    timeout: f64,
+    pending: Vec<(DscCall, f64)>,
+}
+
+impl DscMonitor {
+    pub fn new(timeout: f64) -> Self {
+        DscMonitor {
+            timeout,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Returns complete call, or `None` if call waits for expansion or sentence is malformed.
+    /// `time` is in seconds.
+    pub fn update_dsc(&mut self, dsc: &NmeaDscMessage, time: f64) -> Option<DscCall> {
+        let call = dsc.call()?;
+        if call.expansion && call.mmsi.is_some() {
+            // Repeated call replaces the one waiting for expansion
+            self.pending.retain(|(c, _)| c.mmsi != call.mmsi);
+            self.pending.push((call, time));
+            return None;
+        }
+        Some(call)
+    }
+
+    /// Applies expansion to pending call of the same MMSI. Returns the call completed by the
+    /// last DSE sentence.
+    pub fn update_dse(&mut self, dse: &NmeaDseMessage, time: f64) -> Option<DscCall> {
+        let mmsi = dse.mmsi()?;
+        let idx = self.pending.iter().position(|(c, received_at)| {
+            c.mmsi == Some(mmsi) && time - received_at <= self.timeout
+        })?;
+        self.pending[idx].0.apply_expansion(dse);
+        if !dse.is_last() {
+            return None;
+        }
+        Some(self.pending.remove(idx).0)
+    }
+
+    /// Returns calls which did not receive expansion within timeout.
+    pub fn expire(&mut self, time: f64) -> Vec<DscCall> {
+        let mut expired = Vec::new();
+        self.pending.retain(|(c, received_at)| {
+            let keep = time - received_at <= self.timeout;
+            if !keep {
+                expired.push(c.clone());
+            }
+            keep
+        });
+        expired
+    }
+
+    /// Number of calls waiting for expansion.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fields::{DistressNature, DscAcknowledgement, DscCategory, DscFormat},
+        generated::nmea3::{NmeaDscMessage, NmeaDseMessage},
+        test_util::parse,
+    };
+
+    use super::DscMonitor;
+
+    #[test]
+    fn individual_call() {
+        let dsc: NmeaDscMessage = parse("$CDDSC,20,3380400790,00,21,26,1423108312,2021,,,B,E*73");
+        let call = dsc.call().unwrap();
+        assert_eq!(call.format, DscFormat::Individual);
+        assert_eq!(call.category, Some(DscCategory::Routine));
+        assert_eq!(call.mmsi, Some(338040079));
+        assert_eq!(call.distress_mmsi, None);
+        assert_eq!(call.nature, None);
+        let (lat, lon) = call.position.unwrap();
+        assert!((lat - (42.0 + 31.0 / 60.0)).abs() < 1e-9);
+        assert!((lon + (83.0 + 12.0 / 60.0)).abs() < 1e-9);
+        assert_eq!(call.time, Some((20, 21)));
+        assert_eq!(call.acknowledgement, Some(DscAcknowledgement::Able));
+        assert!(call.expansion);
+        assert!(!call.is_distress());
+    }
+
+    #[test]
+    fn malformed_time() {
+        for sentence in [
+            "$CDDSC,20,3380400790,00,21,26,1423108312,2é1,,,B,E*1B",
+            "$CDDSC,20,3380400790,00,21,26,1423108312,+201,,,B,E*6A",
+        ] {
+            let dsc: NmeaDscMessage = parse(sentence);
+            let call = dsc.call().unwrap();
+            assert_eq!(call.time, None);
+            assert_eq!(call.mmsi, Some(338040079));
+        }
+    }
+
+    #[test]
+    fn distress_relay() {
+        let dsc: NmeaDscMessage =
+            parse("$CDDSC,16,0000000000,12,12,00,0521507420,1123,2320123450,05,S,*2F");
+        let call = dsc.call().unwrap();
+        assert_eq!(call.format, DscFormat::AllShips);
+        assert!(call.is_distress());
+        assert_eq!(call.distress_mmsi, Some(232012345));
+        assert_eq!(call.nature, Some(DistressNature::Sinking));
+        assert!(!call.expansion);
+    }
+
+    #[test]
+    fn distress_alert_with_expansion() {
+        let mut monitor = DscMonitor::new(5.0);
+        let dsc: NmeaDscMessage = parse("$CDDSC,12,3380400790,12,06,00,1423108312,0434,,,S,E*63");
+        assert!(monitor.update_dsc(&dsc, 10.0).is_none());
+        assert_eq!(monitor.pending(), 1);
+
+        let dse: NmeaDseMessage = parse("$CDDSE,1,1,A,3380400790,00,45894494,02,0052,03,2710*19");
+        let call = monitor.update_dse(&dse, 11.0).unwrap();
+        assert_eq!(monitor.pending(), 0);
+        assert!(call.is_distress());
+        assert_eq!(call.distress_mmsi, Some(338040079));
+        assert_eq!(call.nature, Some(DistressNature::DisabledAdrift));
+        assert_eq!(call.time, Some((4, 34)));
+        let (lat, lon) = call.position.unwrap();
+        assert!((lat - (42.0 + 31.4589 / 60.0)).abs() < 1e-9);
+        assert!((lon + (83.0 + 12.4494 / 60.0)).abs() < 1e-9);
+        assert_eq!(call.speed, Some(5.2));
+        assert_eq!(call.course, Some(271.0));
+    }
+
+    #[test]
+    fn expansion_timeout() {
+        let mut monitor = DscMonitor::new(5.0);
+        let dsc: NmeaDscMessage = parse("$CDDSC,20,3380400790,00,21,26,1423108312,2021,,,B,E*73");
+        assert!(monitor.update_dsc(&dsc, 10.0).is_none());
+        let dse: NmeaDseMessage = parse("$CDDSE,1,1,A,3380400790,00,45894494*1B");
+        assert!(monitor.update_dse(&dse, 20.0).is_none());
+        let expired = monitor.expire(20.0);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].mmsi, Some(338040079));
+        assert_eq!(monitor.pending(), 0);
+    }
+}
@@ -0,0 +1,1039 @@
+use std::borrow::Cow;
+
+use crate::messages::FromSlice;
+
+/// Declares an enum for a NMEA field with fixed set of values (e.g. 'A'/'V' or '0'..'8').
+/// Generates conversions from\to field value and `FromSlice` impl for `Option<enum>`.
+/// Unknown or empty values are stored as `None`.
+macro_rules! field_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$vmeta:meta])* $variant:ident = $code:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$vmeta])* $variant,)+
+        }
+
+        impl $name {
+            /// Converts field value to enum. Returns `None` for unknown values.
+            pub fn from_code(value: &[u8]) -> Option<Self> {
+                match value {
+                    $(v if v == $code.as_bytes() => Some($name::$variant),)+
+                    _ => None,
+                }
+            }
+
+            /// Field value as it is transmitted in a sentence.
+            pub fn code(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $code,)+
+                }
+            }
+        }
+
+        impl $crate::messages::FromSlice for Option<$name> {
+            fn set_from_slice(&mut self, value: &[u8]) {
+                *self = $name::from_code(value);
+            }
+
+            fn as_string(&self) -> std::borrow::Cow<'_, str> {
+                match self {
+                    Some(v) => std::borrow::Cow::Borrowed(v.code()),
+                    None => std::borrow::Cow::Borrowed(""),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use field_enum;
+
+//************************ Units and quantities ************************************
+
+/// Unit of measure which can be converted to SI unit.
+pub trait Unit: Copy {
+    /// Multiplier to convert value in this unit to SI unit.
+    fn si_factor(&self) -> f64;
+}
+
+/// Value with unit of measure (e.g. altitude in meters or speed in knots).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity<U: Unit> {
+    pub value: f64,
+    pub unit: U,
+}
+
+impl<U: Unit> Quantity<U> {
+    pub fn new(value: f64, unit: U) -> Self {
+        Quantity { value, unit }
+    }
+
+    /// Builds quantity from value and unit fields of a message. Both fields shall be present.
+    pub fn from_fields(value: Option<f64>, unit: Option<U>) -> Option<Self> {
+        Some(Quantity::new(value?, unit?))
+    }
+
+    /// Value in SI units (meters, m/s, etc.).
+    pub fn si(&self) -> f64 {
+        self.value * self.unit.si_factor()
+    }
+
+    /// Value converted to another unit.
+    pub fn to(&self, unit: U) -> f64 {
+        self.si() / unit.si_factor()
+    }
+}
+
+field_enum! {
+    /// Distance\length unit.
+    pub enum DistanceUnit {
+        Meters = "M",
+        Feet = "f",
+        Fathoms = "F",
+        Kilometers = "K",
+        NauticalMiles = "N",
+        StatuteMiles = "S",
+    }
+}
+
+impl Unit for DistanceUnit {
+    fn si_factor(&self) -> f64 {
+        match self {
+            DistanceUnit::Meters => 1.0,
+            DistanceUnit::Feet => 0.3048,
+            DistanceUnit::Fathoms => 1.8288,
+            DistanceUnit::Kilometers => 1000.0,
+            DistanceUnit::NauticalMiles => 1852.0,
+            DistanceUnit::StatuteMiles => 1609.344,
+        }
+    }
+}
+
+pub type Distance = Quantity<DistanceUnit>;
+
+impl Distance {
+    pub fn meters(&self) -> f64 {
+        self.si()
+    }
+}
+
+field_enum! {
+    /// Speed unit.
+    pub enum SpeedUnit {
+        Knots = "N",
+        KilometersPerHour = "K",
+        MetersPerSecond = "M",
+        MilesPerHour = "S",
+    }
+}
+
+impl Unit for SpeedUnit {
+    fn si_factor(&self) -> f64 {
+        match self {
+            SpeedUnit::Knots => 1852.0 / 3600.0,
+            SpeedUnit::KilometersPerHour => 1000.0 / 3600.0,
+            SpeedUnit::MetersPerSecond => 1.0,
+            SpeedUnit::MilesPerHour => 1609.344 / 3600.0,
+        }
+    }
+}
+
+impl SpeedUnit {
+    /// Speed unit measuring distance units per hour (e.g. nautical miles -> knots).
+    /// Used by radar sentences which set one unit for both distances and speeds.
+    pub fn per_hour(unit: DistanceUnit) -> Option<Self> {
+        match unit {
+            DistanceUnit::NauticalMiles => Some(SpeedUnit::Knots),
+            DistanceUnit::Kilometers => Some(SpeedUnit::KilometersPerHour),
+            DistanceUnit::StatuteMiles => Some(SpeedUnit::MilesPerHour),
+            _ => None,
+        }
+    }
+}
+
+pub type Speed = Quantity<SpeedUnit>;
+
+impl Speed {
+    pub fn knots(&self) -> f64 {
+        self.to(SpeedUnit::Knots)
+    }
+
+    pub fn meters_per_second(&self) -> f64 {
+        self.si()
+    }
+}
+
+field_enum! {
+    /// Pressure unit.
+    pub enum PressureUnit {
+        Bars = "B",
+        Pascals = "P",
+        InchesOfMercury = "I",
+    }
+}
+
+impl Unit for PressureUnit {
+    fn si_factor(&self) -> f64 {
+        match self {
+            PressureUnit::Bars => 100_000.0,
+            PressureUnit::Pascals => 1.0,
+            PressureUnit::InchesOfMercury => 3386.389,
+        }
+    }
+}
+
+pub type Pressure = Quantity<PressureUnit>;
+
+impl Pressure {
+    pub fn pascals(&self) -> f64 {
+        self.si()
+    }
+}
+
+field_enum! {
+    /// Temperature unit. NMEA uses degrees Celsius only, so they are used instead of Kelvin as SI unit.
+    pub enum TemperatureUnit {
+        Celsius = "C",
+    }
+}
+
+impl Unit for TemperatureUnit {
+    fn si_factor(&self) -> f64 {
+        1.0
+    }
+}
+
+pub type Temperature = Quantity<TemperatureUnit>;
+
+impl Temperature {
+    pub fn celsius(&self) -> f64 {
+        self.si()
+    }
+}
+
+field_enum! {
+    /// Reference of a course or bearing.
+    pub enum HeadingReference {
+        True = "T",
+        Magnetic = "M",
+    }
+}
+
+field_enum! {
+    /// Direction of magnetic deviation or variation.
+    pub enum EastWest {
+        East = "E",
+        West = "W",
+    }
+}
+
+impl EastWest {
+    /// Applies direction to angle: easterly is positive, westerly is negative.
+    pub fn signed(&self, value: f64) -> f64 {
+        match self {
+            EastWest::East => value,
+            EastWest::West => -value,
+        }
+    }
+}
+
+field_enum! {
+    /// Wind angle reference (MWV).
+    pub enum WindReference {
+        /// Relative to vessel (apparent wind).
+        Relative = "R",
+        /// Theoretical (true wind), calculated using vessel speed.
+        True = "T",
+    }
+}
+
+field_enum! {
+    /// Data status.
+    pub enum DataStatus {
+        Valid = "A",
+        Invalid = "V",
+    }
+}
+
+field_enum! {
+    /// Heading mode indicator (THS).
+    pub enum HeadingMode {
+        Autonomous = "A",
+        Estimated = "E",
+        Manual = "M",
+        Simulator = "S",
+        NotValid = "V",
+    }
+}
+
+field_enum! {
+    /// Direction to steer to correct cross-track error.
+    pub enum SteerDirection {
+        Left = "L",
+        Right = "R",
+    }
+}
+
+field_enum! {
+    /// Route type (RTE).
+    pub enum RouteType {
+        /// Complete list of waypoints in route.
+        Complete = "c",
+        /// Working route: first listed waypoint is 'from', second is 'to', rest are the remaining.
+        Working = "w",
+    }
+}
+
+field_enum! {
+    /// Reference of a target bearing or course (TTM).
+    pub enum BearingReference {
+        True = "T",
+        Relative = "R",
+    }
+}
+
+field_enum! {
+    /// Radar target status (TTM, TLL).
+    pub enum TargetStatus {
+        Lost = "L",
+        /// Target is being acquired.
+        Query = "Q",
+        Tracking = "T",
+    }
+}
+
+field_enum! {
+    /// Reference target flag (TTM, TLL). Reference target is used to determine own ship speed.
+    pub enum TargetReference {
+        Reference = "R",
+    }
+}
+
+field_enum! {
+    /// Type of target acquisition (TTM).
+    pub enum AcquisitionType {
+        Automatic = "A",
+        Manual = "M",
+        Reported = "R",
+    }
+}
+
+field_enum! {
+    /// Source of own ship course and speed (OSD).
+    pub enum SpeedReference {
+        BottomTrack = "B",
+        Manual = "M",
+        Water = "W",
+        Radar = "R",
+        Positioning = "P",
+    }
+}
+
+field_enum! {
+    /// Radar display rotation (RSD).
+    pub enum DisplayRotation {
+        CourseUp = "C",
+        HeadUp = "H",
+        NorthUp = "N",
+    }
+}
+
+field_enum! {
+    /// Alarm condition (ALR).
+    pub enum AlarmCondition {
+        /// Threshold exceeded.
+        Exceeded = "A",
+        Normal = "V",
+    }
+}
+
+field_enum! {
+    /// Alarm acknowledge state (ALR).
+    pub enum AckState {
+        Acknowledged = "A",
+        Unacknowledged = "V",
+    }
+}
+
+field_enum! {
+    /// Alert category (ALF).
+    pub enum AlertCategory {
+        /// Alert where information at operator unit is required for decision support.
+        A = "A",
+        /// Alert where no additional information for decision support is necessary.
+        B = "B",
+        /// Alert that cannot be acknowledged at the bridge.
+        C = "C",
+    }
+}
+
+field_enum! {
+    /// Alert priority (ALF).
+    pub enum AlertPriority {
+        Emergency = "E",
+        Alarm = "A",
+        Warning = "W",
+        Caution = "C",
+    }
+}
+
+field_enum! {
+    /// Alert state (ALF).
+    pub enum AlertState {
+        ActiveUnacknowledged = "V",
+        ActiveSilenced = "S",
+        ActiveAcknowledged = "A",
+        /// Active, responsibility transferred to another station.
+        ActiveTransferred = "O",
+        RectifiedUnacknowledged = "U",
+        Normal = "N",
+    }
+}
+
+field_enum! {
+    /// Alert command (ACN, ARC).
+    pub enum AlertCommand {
+        Acknowledge = "A",
+        /// Request\repeat information.
+        Request = "Q",
+        ResponsibilityTransfer = "O",
+        Silence = "S",
+    }
+}
+
+/// Converts latitude\longitude from NMEA format (ddmm.mm or dddmm.mm) to signed decimal degrees.
+/// South and west hemispheres are negative.
+pub fn nmea_to_degrees(value: f64, dir: u8) -> f64 {
+    let degrees = (value / 100.0).trunc();
+    let res = degrees + (value - degrees * 100.0) / 60.0;
+    match dir {
+        b'S' | b'W' => -res,
+        _ => res,
+    }
+}
+
+//************************ GNSS specific fields ************************************
+
+field_enum! {
+    /// GPS quality indicator (GGA).
+    pub enum FixQuality {
+        Invalid = "0",
+        Gps = "1",
+        Dgps = "2",
+        Pps = "3",
+        RtkFixed = "4",
+        RtkFloat = "5",
+        Estimated = "6",
+        Manual = "7",
+        Simulation = "8",
+    }
+}
+
+impl FixQuality {
+    /// True if the fix can be used for navigation.
+    pub fn is_valid(&self) -> bool {
+        !matches!(self, FixQuality::Invalid | FixQuality::Simulation)
+    }
+
+    /// True for RTK fixed or float solution.
+    pub fn is_rtk(&self) -> bool {
+        matches!(self, FixQuality::RtkFixed | FixQuality::RtkFloat)
+    }
+}
+
+field_enum! {
+    /// Positioning system mode indicator (NMEA v2.3+ GLL, RMC, VTG; per system in GNS).
+    pub enum PositionMode {
+        Autonomous = "A",
+        Differential = "D",
+        Estimated = "E",
+        RtkFloat = "F",
+        Manual = "M",
+        NotValid = "N",
+        Precise = "P",
+        RtkFixed = "R",
+        Simulator = "S",
+    }
+}
+
+impl PositionMode {
+    /// True if the fix can be used for navigation.
+    pub fn is_valid(&self) -> bool {
+        !matches!(self, PositionMode::NotValid | PositionMode::Simulator)
+    }
+}
+
+field_enum! {
+    /// Navigational status (NMEA v4.10+ RMC, GNS).
+    pub enum NavStatus {
+        Safe = "S",
+        Caution = "C",
+        Unsafe = "U",
+        NotValid = "V",
+    }
+}
+
+field_enum! {
+    /// GNSS system id (NMEA 4.10+ GSA, GRS, GBS).
+    pub enum GnssSystem {
+        Gps = "1",
+        Glonass = "2",
+        Galileo = "3",
+        BeiDou = "4",
+        Qzss = "5",
+        NavIc = "6",
+    }
+}
+
+impl GnssSystem {
+    /// Detects system by talker id. Returns `None` for combined (GN) or non-GNSS talkers.
+    pub fn from_talker_id(talker_id: &str) -> Option<Self> {
+        match talker_id {
+            "GP" => Some(GnssSystem::Gps),
+            "GL" => Some(GnssSystem::Glonass),
+            "GA" => Some(GnssSystem::Galileo),
+            "GB" | "BD" => Some(GnssSystem::BeiDou),
+            "GQ" => Some(GnssSystem::Qzss),
+            "GI" => Some(GnssSystem::NavIc),
+            _ => None,
+        }
+    }
+
+    /// Detects system by satellite id in NMEA numbering (before 4.10, when talker is GN).
+    /// Only GPS\SBAS (1-64) and GLONASS (65-96) ranges are defined.
+    pub fn from_nmea_prn(prn: u32) -> Option<Self> {
+        match prn {
+            1..=64 => Some(GnssSystem::Gps),
+            65..=96 => Some(GnssSystem::Glonass),
+            _ => None,
+        }
+    }
+}
+
+/// GNSS signal ID (NMEA v4.10+). Transmitted as a hex digit, meaning depends on system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignalId(pub u8);
+
+impl FromSlice for Option<SignalId> {
+    fn set_from_slice(&mut self, value: &[u8]) {
+        *self = std::str::from_utf8(value)
+            .ok()
+            .and_then(|v| u8::from_str_radix(v, 16).ok())
+            .map(SignalId);
+    }
+
+    fn as_string(&self) -> Cow<'_, str> {
+        match self {
+            Some(v) => Cow::Owned(format!("{:X}", v.0)),
+            None => Cow::Borrowed(""),
+        }
+    }
+}
+
+field_enum! {
+    /// Fix selection mode (GSA).
+    pub enum SelectionMode {
+        Manual = "M",
+        Automatic = "A",
+    }
+}
+
+field_enum! {
+    /// Fix type (GSA).
+    pub enum FixType {
+        NoFix = "1",
+        Fix2D = "2",
+        Fix3D = "3",
+    }
+}
+
+field_enum! {
+    /// Residuals computation mode (GRS).
+    pub enum GrsMode {
+        /// Residuals were used to calculate the position given in GGA.
+        UsedInGga = "0",
+        /// Residuals were recomputed after GGA position was computed.
+        Recomputed = "1",
+    }
+}
+
+/// Geodetic datum (DTM), IHO S-60 codes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Datum {
+    Wgs84,
+    Wgs72,
+    Sgs85,
+    /// PZ-90 (GLONASS).
+    Pe90,
+    UserDefined,
+    /// Any other datum code of IHO S-60 (e.g. "216" for Ordnance Survey 1936).
+    Other(String),
+}
+
+impl Datum {
+    /// Converts field value to datum. Returns `None` for empty or not UTF-8 values.
+    pub fn from_code(value: &[u8]) -> Option<Self> {
+        Some(match value {
+            b"W84" => Datum::Wgs84,
+            b"W72" => Datum::Wgs72,
+            b"S85" => Datum::Sgs85,
+            b"P90" => Datum::Pe90,
+            b"999" => Datum::UserDefined,
+            b"" => return None,
+            other => Datum::Other(std::str::from_utf8(other).ok()?.to_string()),
+        })
+    }
+
+    /// Field value as it is transmitted in a sentence.
+    pub fn code(&self) -> &str {
+        match self {
+            Datum::Wgs84 => "W84",
+            Datum::Wgs72 => "W72",
+            Datum::Sgs85 => "S85",
+            Datum::Pe90 => "P90",
+            Datum::UserDefined => "999",
+            Datum::Other(code) => code,
+        }
+    }
+}
+
+impl FromSlice for Option<Datum> {
+    fn set_from_slice(&mut self, value: &[u8]) {
+        *self = Datum::from_code(value);
+    }
+
+    fn as_string(&self) -> Cow<'_, str> {
+        match self {
+            Some(v) => Cow::Borrowed(v.code()),
+            None => Cow::Borrowed(""),
+        }
+    }
+}
+
+//************************ Steering and propulsion fields ************************************
+
+field_enum! {
+    /// Location of the station in command (ROR, PRC, TRC, ETL).
+    pub enum OperatingLocation {
+        Bridge = "B",
+        PortWing = "P",
+        StarboardWing = "S",
+        BridgeCentre = "C",
+        EngineRoom = "E",
+        Wing = "W",
+    }
+}
+
+field_enum! {
+    /// Source of revolutions (RPM).
+    pub enum RpmSource {
+        Shaft = "S",
+        Engine = "E",
+    }
+}
+
+field_enum! {
+    /// Mode of RPM demand (PRC, TRC).
+    pub enum DemandMode {
+        Percent = "P",
+        Rpm = "R",
+        Invalid = "V",
+    }
+}
+
+field_enum! {
+    /// Mode of pitch demand (PRC, TRC).
+    pub enum PitchMode {
+        Percent = "P",
+        Degrees = "D",
+        Invalid = "V",
+    }
+}
+
+field_enum! {
+    /// Sentence status flag (TRC).
+    pub enum SentenceStatus {
+        /// Status report.
+        Reply = "R",
+        Command = "C",
+    }
+}
+
+field_enum! {
+    /// Engine telegraph message type (ETL).
+    pub enum TelegraphMessageType {
+        Order = "O",
+        AnswerBack = "A",
+    }
+}
+
+field_enum! {
+    /// Engine telegraph position (ETL).
+    pub enum TelegraphPosition {
+        Stop = "00",
+        AheadDeadSlow = "01",
+        AheadSlow = "02",
+        AheadHalf = "03",
+        AheadFull = "04",
+        NavigationFull = "05",
+        AsternDeadSlow = "11",
+        AsternSlow = "12",
+        AsternHalf = "13",
+        AsternFull = "14",
+        CrashAstern = "15",
+    }
+}
+
+impl TelegraphPosition {
+    /// Position as signed step: ahead is positive (1..5), astern is negative (-1..-5).
+    pub fn step(&self) -> i32 {
+        let code: i32 = self.code().parse().unwrap();
+        if code > 10 {
+            10 - code
+        } else {
+            code
+        }
+    }
+}
+
+field_enum! {
+    /// Sub telegraph position (ETL).
+    pub enum SubTelegraph {
+        StandBy = "20",
+        FullAway = "30",
+        FinishedWithEngine = "40",
+    }
+}
+
+//************************ u-blox proprietary fields ************************************
+
+field_enum! {
+    /// Navigation status (PUBX,00).
+    pub enum UbxNavStatus {
+        NoFix = "NF",
+        DeadReckoning = "DR",
+        Standalone2D = "G2",
+        Standalone3D = "G3",
+        Differential2D = "D2",
+        Differential3D = "D3",
+        /// Combined GNSS and dead reckoning.
+        Combined = "RK",
+        TimeOnly = "TT",
+    }
+}
+
+field_enum! {
+    /// Receiver port (PUBX,41).
+    pub enum UbxPort {
+        Ddc = "0",
+        Usart1 = "1",
+        Usart2 = "2",
+        Usb = "3",
+        Spi = "4",
+    }
+}
+
+/// Set of port protocols (PUBX,41). Transmitted as a 4 digit hex mask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct UbxProtocols(pub u16);
+
+impl UbxProtocols {
+    pub const UBX: UbxProtocols = UbxProtocols(0x01);
+    pub const NMEA: UbxProtocols = UbxProtocols(0x02);
+    pub const RTCM: UbxProtocols = UbxProtocols(0x04);
+    pub const RTCM3: UbxProtocols = UbxProtocols(0x20);
+
+    pub fn contains(&self, other: UbxProtocols) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for UbxProtocols {
+    type Output = UbxProtocols;
+
+    fn bitor(self, rhs: UbxProtocols) -> UbxProtocols {
+        UbxProtocols(self.0 | rhs.0)
+    }
+}
+
+impl FromSlice for Option<UbxProtocols> {
+    fn set_from_slice(&mut self, value: &[u8]) {
+        *self = std::str::from_utf8(value)
+            .ok()
+            .and_then(|v| u16::from_str_radix(v, 16).ok())
+            .map(UbxProtocols);
+    }
+
+    fn as_string(&self) -> Cow<'_, str> {
+        match self {
+            Some(v) => Cow::Owned(format!("{:04X}", v.0)),
+            None => Cow::Borrowed(""),
+        }
+    }
+}
+
+//************************ Garmin proprietary fields ************************************
+
+field_enum! {
+    /// Source of altitude (PGRMZ).
+    pub enum GarminFixDimension {
+        /// Altitude is entered by user (2D fix).
+        UserAltitude = "2",
+        GpsAltitude = "3",
+    }
+}
+
+field_enum! {
+    /// Self test result (PGRMT).
+    pub enum PassFail {
+        Pass = "P",
+        Fail = "F",
+    }
+}
+
+field_enum! {
+    /// Stored data state (PGRMT).
+    pub enum RetainedLost {
+        Retained = "R",
+        Lost = "L",
+    }
+}
+
+field_enum! {
+    /// Fix mode (PGRMC).
+    pub enum GarminFixMode {
+        Automatic = "A",
+        Fix2D = "2",
+        Fix3D = "3",
+    }
+}
+
+field_enum! {
+    /// Differential mode (PGRMC).
+    pub enum GarminDiffMode {
+        Automatic = "A",
+        /// Output only differential fixes.
+        DifferentialOnly = "D",
+    }
+}
+
+field_enum! {
+    /// NMEA port baud rate (PGRMC).
+    pub enum GarminBaudRate {
+        B1200 = "1",
+        B2400 = "2",
+        B4800 = "3",
+        B9600 = "4",
+        B19200 = "5",
+        B300 = "6",
+        B600 = "7",
+        B38400 = "8",
+    }
+}
+
+impl GarminBaudRate {
+    pub fn bps(&self) -> u32 {
+        match self {
+            GarminBaudRate::B1200 => 1200,
+            GarminBaudRate::B2400 => 2400,
+            GarminBaudRate::B4800 => 4800,
+            GarminBaudRate::B9600 => 9600,
+            GarminBaudRate::B19200 => 19200,
+            GarminBaudRate::B300 => 300,
+            GarminBaudRate::B600 => 600,
+            GarminBaudRate::B38400 => 38400,
+        }
+    }
+}
+
+field_enum! {
+    /// Output sentence selection mode (PGRMO).
+    pub enum GarminOutputMode {
+        Disable = "0",
+        Enable = "1",
+        DisableAll = "2",
+        /// Enable all sentences except GPALM.
+        EnableAll = "3",
+        FactoryDefault = "4",
+    }
+}
+
+//************************ MediaTek proprietary fields ************************************
+
+field_enum! {
+    /// Command acknowledge flag (PMTK001).
+    pub enum PmtkAckFlag {
+        InvalidCommand = "0",
+        UnsupportedCommand = "1",
+        /// Valid command, but action failed.
+        Failed = "2",
+        /// Valid command, action succeeded.
+        Succeeded = "3",
+    }
+}
+
+field_enum! {
+    /// AIS channel for transmission of ABM/BBM message.
+    pub enum AisTxChannel {
+        NoPreference = "0",
+        A = "1",
+        B = "2",
+        Both = "3",
+    }
+}
+
+field_enum! {
+    /// Acknowledgement type of ABK sentence.
+    pub enum AbkAckType {
+        /// Addressed message received by destination station.
+        Received = "0",
+        /// Addressed message broadcast, but no acknowledgement received.
+        NotAcknowledged = "1",
+        /// Message could not be broadcast.
+        NotBroadcast = "2",
+        /// Broadcast or interrogation message successfully transmitted.
+        Broadcast = "3",
+        /// Acknowledgement (message 7 or 13) received after timeout.
+        LateAcknowledgement = "4",
+    }
+}
+
+//************************ Radio communication fields ************************************
+
+field_enum! {
+    /// Format specifier of DSC call (ITU-R M.493).
+    pub enum DscFormat {
+        /// Call to ships in a geographic area.
+        GeographicArea = "02",
+        Distress = "12",
+        /// Call to a group of ships with common interest.
+        Group = "14",
+        AllShips = "16",
+        Individual = "20",
+        /// Individual station semi-automatic/automatic service.
+        IndividualAutomatic = "23",
+    }
+}
+
+field_enum! {
+    /// Category of DSC call.
+    pub enum DscCategory {
+        Routine = "00",
+        Safety = "08",
+        Urgency = "10",
+        Distress = "12",
+    }
+}
+
+field_enum! {
+    /// Nature of distress of DSC distress alert.
+    pub enum DistressNature {
+        FireExplosion = "00",
+        Flooding = "01",
+        Collision = "02",
+        Grounding = "03",
+        /// Listing, in danger of capsizing.
+        Listing = "04",
+        Sinking = "05",
+        DisabledAdrift = "06",
+        Undesignated = "07",
+        AbandoningShip = "08",
+        /// Piracy or armed robbery attack.
+        Piracy = "09",
+        ManOverboard = "10",
+        /// EPIRB emission.
+        Epirb = "12",
+    }
+}
+
+field_enum! {
+    /// Acknowledgement flag of DSC call.
+    pub enum DscAcknowledgement {
+        /// Acknowledgement request.
+        Request = "R",
+        /// Acknowledgement, able to comply.
+        Able = "B",
+        /// End of sequence (distress alerts and acknowledgements).
+        EndOfSequence = "S",
+    }
+}
+
+field_enum! {
+    /// Query/reply flag of DSE sentence.
+    pub enum DseFlag {
+        Query = "Q",
+        Reply = "R",
+        /// Automatic expansion of a DSC call.
+        Automatic = "A",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::messages::FromSlice;
+
+    use super::{nmea_to_degrees, Distance, DistanceUnit, FixQuality, SignalId, Speed, SpeedUnit};
+
+    #[test]
+    fn fix_quality_from_slice() {
+        let mut q: Option<FixQuality> = None;
+        q.set_from_slice(b"4");
+        assert_eq!(q, Some(FixQuality::RtkFixed));
+        assert!(q.unwrap().is_rtk());
+        q.set_from_slice(b"");
+        assert_eq!(q, None);
+        q.set_from_slice(b"9");
+        assert_eq!(q, None);
+    }
+
+    #[test]
+    fn enum_as_string() {
+        let q = Some(FixQuality::RtkFloat);
+        assert_eq!(q.as_string(), "5");
+        let q: Option<FixQuality> = None;
+        assert_eq!(q.as_string(), "");
+    }
+
+    #[test]
+    fn signal_id_hex() {
+        let mut id: Option<SignalId> = None;
+        id.set_from_slice(b"B");
+        assert_eq!(id, Some(SignalId(11)));
+        assert_eq!(id.as_string(), "B");
+        id.set_from_slice(b"");
+        assert_eq!(id, None);
+    }
+
+    #[test]
+    fn distance_conversion() {
+        let d = Distance::new(10.0, DistanceUnit::Fathoms);
+        assert!((d.meters() - 18.288).abs() < 1e-9);
+        assert!((d.to(DistanceUnit::Feet) - 60.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn speed_conversion() {
+        let s = Speed::new(36.0, SpeedUnit::KilometersPerHour);
+        assert!((s.meters_per_second() - 10.0).abs() < 1e-9);
+        assert!((s.knots() - 19.438445).abs() < 1e-6);
+    }
+
+    #[test]
+    fn nmea_coordinates_to_degrees() {
+        assert!((nmea_to_degrees(4717.11399, b'N') - 47.285233).abs() < 1e-6);
+        assert!((nmea_to_degrees(12158.3416, b'W') + 121.972360).abs() < 1e-6);
+    }
+
+    #[test]
+    fn quantity_from_fields() {
+        assert_eq!(
+            Distance::from_fields(Some(1.5), Some(DistanceUnit::Meters)),
+            Some(Distance::new(1.5, DistanceUnit::Meters))
+        );
+        assert_eq!(Distance::from_fields(Some(1.5), None), None);
+    }
+}
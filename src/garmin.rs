@@ -0,0 +1,195 @@
+//! Garmin proprietary PGRM* sentences: error estimates, altitude and sensor status, configuration builders.
+
+use crate::{
+    fields::{
+        Distance, GarminBaudRate, GarminDiffMode, GarminFixMode, GarminOutputMode, PassFail,
+        RetainedLost,
+    },
+    generated::nmea3::{
+        NmeaPgrmcMessage, NmeaPgrmeMessage, NmeaPgrmoMessage, NmeaPgrmtMessage, NmeaPgrmzMessage,
+    },
+};
+
+impl NmeaPgrmeMessage {
+    /// Estimated horizontal position error.
+    pub fn horizontal_error(&self) -> Option<Distance> {
+        Distance::from_fields(self.hpe, self.hpe_unit)
+    }
+
+    /// Estimated vertical position error.
+    pub fn vertical_error(&self) -> Option<Distance> {
+        Distance::from_fields(self.vpe, self.vpe_unit)
+    }
+
+    /// Overall spherical equivalent position error.
+    pub fn spherical_error(&self) -> Option<Distance> {
+        Distance::from_fields(self.epe, self.epe_unit)
+    }
+}
+
+impl NmeaPgrmzMessage {
+    /// Altitude above mean sea level.
+    pub fn altitude(&self) -> Option<Distance> {
+        Distance::from_fields(self.altitude, self.altitude_unit)
+    }
+}
+
+impl NmeaPgrmtMessage {
+    /// All self tests passed and no stored data is lost.
+    pub fn is_ok(&self) -> bool {
+        let pass = [self.rom_checksum, self.receiver_failure, self.oscillator]
+            .iter()
+            .all(|t| *t != Some(PassFail::Fail));
+        let retained = [self.stored_data, self.clock, self.configuration]
+            .iter()
+            .all(|d| *d != Some(RetainedLost::Lost));
+        pass && retained
+    }
+}
+
+impl NmeaPgrmcMessage {
+    /// Configuration command with fix and differential modes. Other parameters are left empty (not changed).
+    pub fn set_modes(fix_mode: GarminFixMode, diff_mode: GarminDiffMode) -> Self {
+        NmeaPgrmcMessage {
+            fix_mode: Some(fix_mode),
+            diff_mode: Some(diff_mode),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_baud_rate(mut self, baud_rate: GarminBaudRate) -> Self {
+        self.baud_rate = Some(baud_rate);
+        self
+    }
+
+    /// Sets altitude above mean sea level used for 2D fixes, meters.
+    pub fn with_altitude(mut self, altitude: f64) -> Self {
+        self.altitude = Some(altitude);
+        self
+    }
+}
+
+impl NmeaPgrmoMessage {
+    /// Command enabling output of a sentence (e.g. "GPGSV").
+    pub fn enable(sentence: &str) -> Self {
+        NmeaPgrmoMessage {
+            sentence: Some(sentence.to_string()),
+            mode: Some(GarminOutputMode::Enable),
+        }
+    }
+
+    /// Command disabling output of a sentence.
+    pub fn disable(sentence: &str) -> Self {
+        NmeaPgrmoMessage {
+            sentence: Some(sentence.to_string()),
+            mode: Some(GarminOutputMode::Disable),
+        }
+    }
+
+    /// Command for modes applied to all sentences (disable all, enable all or factory default).
+    pub fn all(mode: GarminOutputMode) -> Self {
+        NmeaPgrmoMessage {
+            sentence: None,
+            mode: Some(mode),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fields::{
+            DistanceUnit, GarminBaudRate, GarminDiffMode, GarminFixDimension, GarminFixMode,
+            GarminOutputMode, PassFail, RetainedLost,
+        },
+        generated::nmea3::*,
+        messages::{AddrField, MessageFields, MessagesMap},
+        test_util::parse,
+    };
+
+    #[test]
+    fn pgrme() {
+        let pgrme: NmeaPgrmeMessage = parse("$PGRME,15.0,M,45.0,M,25.0,M*1C");
+        assert_eq!(pgrme.horizontal_error().unwrap().meters(), 15.0);
+        assert_eq!(pgrme.vertical_error().unwrap().meters(), 45.0);
+        assert_eq!(pgrme.spherical_error().unwrap().meters(), 25.0);
+    }
+
+    #[test]
+    fn pgrmz() {
+        let pgrmz: NmeaPgrmzMessage = parse("$PGRMZ,246,f,3*1B");
+        let altitude = pgrmz.altitude().unwrap();
+        assert_eq!(altitude.unit, DistanceUnit::Feet);
+        assert!((altitude.meters() - 74.9808).abs() < 1e-9);
+        assert_eq!(pgrmz.fix_dimension, Some(GarminFixDimension::GpsAltitude));
+    }
+
+    #[test]
+    fn pgrmm() {
+        let pgrmm: NmeaPgrmmMessage = parse("$PGRMM,WGS 84*06");
+        assert_eq!(pgrmm.datum.as_deref(), Some("WGS 84"));
+    }
+
+    #[test]
+    fn pgrmt() {
+        let pgrmt: NmeaPgrmtMessage = parse("$PGRMT,GPS 15L/15H VER 2.05,P,P,R,R,P,,23,R*64");
+        assert_eq!(pgrmt.version.as_deref(), Some("GPS 15L/15H VER 2.05"));
+        assert_eq!(pgrmt.rom_checksum, Some(PassFail::Pass));
+        assert_eq!(pgrmt.clock, Some(RetainedLost::Retained));
+        assert_eq!(pgrmt.data_collection, None);
+        assert_eq!(pgrmt.temperature, Some(23.0));
+        assert!(pgrmt.is_ok());
+
+        let pgrmt: NmeaPgrmtMessage = parse("$PGRMT,GPS 15L/15H VER 2.05,P,P,R,L,F,C,41,R*2B");
+        assert_eq!(pgrmt.data_collection, Some(b'C'));
+        assert!(!pgrmt.is_ok());
+    }
+
+    #[test]
+    fn pgrmc() {
+        let pgrmc: NmeaPgrmcMessage =
+            parse("$PGRMC,A,218.8,100,6378137.000,298.257223563,0.0,0.0,0.0,A,3,1,1,4,30*72");
+        assert_eq!(pgrmc.fix_mode, Some(GarminFixMode::Automatic));
+        assert_eq!(pgrmc.datum_index, Some(100));
+        assert_eq!(pgrmc.inverse_flattening, Some(298.257223563));
+        assert_eq!(pgrmc.baud_rate.unwrap().bps(), 4800);
+        assert_eq!(pgrmc.dr_time, Some(30));
+
+        let cmd = NmeaPgrmcMessage::set_modes(GarminFixMode::Fix3D, GarminDiffMode::Automatic)
+            .with_baud_rate(GarminBaudRate::B9600);
+        assert_eq!(cmd.to_sentence(""), "$PGRMC,3,,,,,,,,A,4,,,,*0D\r\n");
+    }
+
+    #[test]
+    fn pgrmo() {
+        assert_eq!(
+            NmeaPgrmoMessage::disable("GPGSV").to_sentence(""),
+            "$PGRMO,GPGSV,0*22\r\n"
+        );
+        assert_eq!(
+            NmeaPgrmoMessage::enable("GPRMC").to_sentence(""),
+            "$PGRMO,GPRMC,1*3D\r\n"
+        );
+        assert_eq!(
+            NmeaPgrmoMessage::all(GarminOutputMode::DisableAll).to_sentence(""),
+            "$PGRMO,,2*75\r\n"
+        );
+        let pgrmo: NmeaPgrmoMessage = parse("$PGRMO,GPGSV,0*22");
+        assert_eq!(pgrmo.mode, Some(GarminOutputMode::Disable));
+    }
+
+    #[test]
+    fn messages_map_dispatch() {
+        let mut map = MessagesMap::new();
+        map.add_all_messages();
+        let addr = AddrField::new(b"PGRME");
+        assert_eq!(addr.talker_id, "");
+        for (idx, field) in ["15.0", "M", "45.0", "M", "25.0", "M"].iter().enumerate() {
+            assert!(map.set_field(&addr, idx as u8, field.as_bytes()));
+        }
+        let msg = map.get(&addr).unwrap();
+        assert_eq!(msg.message_type(), NmeaMessages::PGRME);
+        let pgrme = msg.as_any().downcast_ref::<NmeaPgrmeMessage>().unwrap();
+        assert_eq!(pgrme.vpe, Some(45.0));
+    }
+}
@@ -1,4 +1,8 @@
+// Messages with repeated fields only (e.g. XDR) get a match with single catch-all arm.
+#[allow(clippy::match_single_binding)]
 pub mod nmea3;
+#[cfg(test)]
+mod tests;
 
 pub enum TalkerIds {
     GP, // GPS
@@ -7,11 +11,17 @@ pub enum TalkerIds {
     GQ, // QZSS
     GB, // BeiDou
     BD, // BeiDou
+    GI, // NavIC
     GN, // GNSS - from multiple systems
 
     AI, // AIS
     AG, // Autopilot General
     AP, // Autopilot Magnetic
+    CD, // DSC
+    EC, // Electronic Chart Display & Information System
+    HC, // Heading, magnetic compass
+    HE, // Heading, north seeking gyro
+    HN, // Heading, non north seeking gyro
     IN, // Integrated Navigation
     II, // Integrated Instrumentation
     P,  // Proprietary
@@ -21,15 +31,20 @@ pub enum TalkerIds {
     TI, // Tourn Rate Indicator
     VD, // Velocity doppler
     VM, // Speed log, water, magnetic
+    VR, // Voyage Data Recorder
     VW, // Speed log, water, mechanical
     WI, // Weather Instruments
+    YX, // Transducer
 }
 
-pub const ALL_TALKER_IDS: [[u8; 2]; 2] = [[0x47, 0x50], [0x47, 0x4C]]; // GP, GL
+pub const ALL_TALKER_IDS: [[u8; 2]; 28] = [
+    *b"GP", *b"GL", *b"GA", *b"GQ", *b"GB", *b"BD", *b"GI", *b"GN", // GNSS
+    *b"AI", *b"AG", *b"AP", *b"CD", *b"EC", *b"HC", *b"HE", *b"HN", *b"IN", *b"II", *b"RA", *b"SD",
+    *b"SS", *b"TI", *b"VD", *b"VM", *b"VR", *b"VW", *b"WI", *b"YX",
+];
 
 pub fn is_talker_id(v: &[u8]) -> bool {
     assert!(v.len() == 2);
     let s: [u8; 2] = [v[0], v[1]];
     ALL_TALKER_IDS.contains(&s) //TODO:  O(n) complexity. Replace with hashset!!!
 }
-
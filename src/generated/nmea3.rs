@@ -2,6 +2,7 @@
 * Autogenerated file, all manual changes will be lost!
 ***********************************************************************/
 
+use crate::fields::*;
 use crate::messages::{MessageFields, FromSlice, AddrField, MessagesMap};
 
 /// Generated fn for initializing all message types.
@@ -10,21 +11,252 @@ impl MessagesMap {
         let msgs: Vec<Box<dyn MessageFields>> = vec![
             Box::new(NmeaGllMessage::new()),        //  GLL
             Box::new(NmeaRmcMessage::new()),        //  RMC
+            Box::new(NmeaGgaMessage::new()),        //  GGA
+            Box::new(NmeaGsaMessage::new()),        //  GSA
+            Box::new(NmeaGsvMessage::new()),        //  GSV
+            Box::new(NmeaVtgMessage::new()),        //  VTG
+            Box::new(NmeaZdaMessage::new()),        //  ZDA
+            Box::new(NmeaGnsMessage::new()),        //  GNS
+            Box::new(NmeaGstMessage::new()),        //  GST
+            Box::new(NmeaGbsMessage::new()),        //  GBS
+            Box::new(NmeaGrsMessage::new()),        //  GRS
+            Box::new(NmeaHdtMessage::new()),        //  HDT
+            Box::new(NmeaHdgMessage::new()),        //  HDG
+            Box::new(NmeaHdmMessage::new()),        //  HDM
+            Box::new(NmeaThsMessage::new()),        //  THS
+            Box::new(NmeaRotMessage::new()),        //  ROT
+            Box::new(NmeaMwvMessage::new()),        //  MWV
+            Box::new(NmeaMwdMessage::new()),        //  MWD
+            Box::new(NmeaMdaMessage::new()),        //  MDA
+            Box::new(NmeaMtwMessage::new()),        //  MTW
+            Box::new(NmeaXdrMessage::new()),        //  XDR
+            Box::new(NmeaDbtMessage::new()),        //  DBT
+            Box::new(NmeaDbsMessage::new()),        //  DBS
+            Box::new(NmeaDptMessage::new()),        //  DPT
+            Box::new(NmeaVhwMessage::new()),        //  VHW
+            Box::new(NmeaVlwMessage::new()),        //  VLW
+            Box::new(NmeaVbwMessage::new()),        //  VBW
+            Box::new(NmeaRmbMessage::new()),        //  RMB
+            Box::new(NmeaApbMessage::new()),        //  APB
+            Box::new(NmeaBodMessage::new()),        //  BOD
+            Box::new(NmeaBwcMessage::new()),        //  BWC
+            Box::new(NmeaXteMessage::new()),        //  XTE
+            Box::new(NmeaWplMessage::new()),        //  WPL
+            Box::new(NmeaRteMessage::new()),        //  RTE
+            Box::new(NmeaAamMessage::new()),        //  AAM
+            Box::new(NmeaTtmMessage::new()),        //  TTM
+            Box::new(NmeaTllMessage::new()),        //  TLL
+            Box::new(NmeaOsdMessage::new()),        //  OSD
+            Box::new(NmeaRsdMessage::new()),        //  RSD
+            Box::new(NmeaAlrMessage::new()),        //  ALR
+            Box::new(NmeaAckMessage::new()),        //  ACK
+            Box::new(NmeaAlfMessage::new()),        //  ALF
+            Box::new(NmeaAlcMessage::new()),        //  ALC
+            Box::new(NmeaAcnMessage::new()),        //  ACN
+            Box::new(NmeaArcMessage::new()),        //  ARC
+            Box::new(NmeaHbtMessage::new()),        //  HBT
+            Box::new(NmeaDtmMessage::new()),        //  DTM
+            Box::new(NmeaTxtMessage::new()),        //  TXT
+            Box::new(NmeaRsaMessage::new()),        //  RSA
+            Box::new(NmeaRorMessage::new()),        //  ROR
+            Box::new(NmeaRpmMessage::new()),        //  RPM
+            Box::new(NmeaPrcMessage::new()),        //  PRC
+            Box::new(NmeaTrcMessage::new()),        //  TRC
+            Box::new(NmeaEtlMessage::new()),        //  ETL
+            Box::new(NmeaPubx00Message::new()),        //  PUBX,00
+            Box::new(NmeaPubx03Message::new()),        //  PUBX,03
+            Box::new(NmeaPubx04Message::new()),        //  PUBX,04
+            Box::new(NmeaPubx40Message::new()),        //  PUBX,40
+            Box::new(NmeaPubx41Message::new()),        //  PUBX,41
+            Box::new(NmeaPgrmeMessage::new()),        //  PGRME
+            Box::new(NmeaPgrmzMessage::new()),        //  PGRMZ
+            Box::new(NmeaPgrmmMessage::new()),        //  PGRMM
+            Box::new(NmeaPgrmtMessage::new()),        //  PGRMT
+            Box::new(NmeaPgrmcMessage::new()),        //  PGRMC
+            Box::new(NmeaPgrmoMessage::new()),        //  PGRMO
+            Box::new(NmeaPmtk001Message::new()),        //  PMTK001
+            Box::new(NmeaPmtk101Message::new()),        //  PMTK101
+            Box::new(NmeaPmtk103Message::new()),        //  PMTK103
+            Box::new(NmeaPmtk220Message::new()),        //  PMTK220
+            Box::new(NmeaPmtk314Message::new()),        //  PMTK314
+            Box::new(NmeaVdmMessage::new()),        //  VDM
+            Box::new(NmeaVdoMessage::new()),        //  VDO
+            Box::new(NmeaAbmMessage::new()),        //  ABM
+            Box::new(NmeaBbmMessage::new()),        //  BBM
+            Box::new(NmeaAbkMessage::new()),        //  ABK
+            Box::new(NmeaAirMessage::new()),        //  AIR
+            Box::new(NmeaDscMessage::new()),        //  DSC
+            Box::new(NmeaDseMessage::new()),        //  DSE
+            Box::new(NmeaPcdinMessage::new()),        //  PCDIN
             ];
 
         for m in msgs {
-            let k = m.get_addr();
-            self.msgs.insert(k, m);
+            self.insert(m);
         }
     }
 }
 
 /// All implemented NMEA messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NmeaMessages { 
     /// Geographic Position - Latitude/Longitude
     GLL,
     /// Recommended Minimum Specific GNSS Data
     RMC,
+    /// Global Positioning System Fix Data
+    GGA,
+    /// GNSS DOP and Active Satellites
+    GSA,
+    /// GNSS Satellites in View
+    GSV,
+    /// Course Over Ground and Ground Speed
+    VTG,
+    /// Time and Date
+    ZDA,
+    /// GNSS Fix Data
+    GNS,
+    /// GNSS Pseudorange Error Statistics
+    GST,
+    /// GNSS Satellite Fault Detection
+    GBS,
+    /// GNSS Range Residuals
+    GRS,
+    /// Heading - True
+    HDT,
+    /// Heading - Deviation and Variation
+    HDG,
+    /// Heading - Magnetic
+    HDM,
+    /// True Heading and Status
+    THS,
+    /// Rate Of Turn
+    ROT,
+    /// Wind Speed and Angle
+    MWV,
+    /// Wind Direction and Speed
+    MWD,
+    /// Meteorological Composite
+    MDA,
+    /// Water Temperature
+    MTW,
+    /// Transducer Measurements
+    XDR,
+    /// Depth Below Transducer
+    DBT,
+    /// Depth Below Surface
+    DBS,
+    /// Depth
+    DPT,
+    /// Water Speed and Heading
+    VHW,
+    /// Dual Ground/Water Distance
+    VLW,
+    /// Dual Ground/Water Speed
+    VBW,
+    /// Recommended Minimum Navigation Information
+    RMB,
+    /// Heading/Track Controller (Autopilot) Sentence B
+    APB,
+    /// Bearing - Waypoint to Waypoint
+    BOD,
+    /// Bearing and Distance to Waypoint - Great Circle
+    BWC,
+    /// Cross-Track Error, Measured
+    XTE,
+    /// Waypoint Location
+    WPL,
+    /// Routes
+    RTE,
+    /// Waypoint Arrival Alarm
+    AAM,
+    /// Tracked Target Message
+    TTM,
+    /// Target Latitude and Longitude
+    TLL,
+    /// Own Ship Data
+    OSD,
+    /// Radar System Data
+    RSD,
+    /// Set Alarm State
+    ALR,
+    /// Acknowledge Alarm
+    ACK,
+    /// Alert Sentence
+    ALF,
+    /// Cyclic Alert List
+    ALC,
+    /// Alert Command
+    ACN,
+    /// Alert Command Refused
+    ARC,
+    /// Heartbeat Supervision
+    HBT,
+    /// Datum Reference
+    DTM,
+    /// Text Transmission
+    TXT,
+    /// Rudder Sensor Angle
+    RSA,
+    /// Rudder Order Status
+    ROR,
+    /// Revolutions
+    RPM,
+    /// Propulsion Remote Control Status
+    PRC,
+    /// Thruster Control Data
+    TRC,
+    /// Engine Telegraph Operation Status
+    ETL,
+    /// u-blox Lat/Long Position Data
+    PUBX00,
+    /// u-blox Satellite Status
+    PUBX03,
+    /// u-blox Time of Day and Clock Information
+    PUBX04,
+    /// u-blox Set NMEA Message Output Rate
+    PUBX40,
+    /// u-blox Set Protocols and Baudrate
+    PUBX41,
+    /// Garmin Estimated Error Information
+    PGRME,
+    /// Garmin Altitude Information
+    PGRMZ,
+    /// Garmin Map Datum
+    PGRMM,
+    /// Garmin Sensor Status Information
+    PGRMT,
+    /// Garmin Sensor Configuration
+    PGRMC,
+    /// Garmin Output Sentence Enable/Disable
+    PGRMO,
+    /// MediaTek Command Acknowledge
+    PMTK001,
+    /// MediaTek Hot Restart
+    PMTK101,
+    /// MediaTek Cold Restart
+    PMTK103,
+    /// MediaTek Set Position Fix Interval
+    PMTK220,
+    /// MediaTek Set NMEA Output
+    PMTK314,
+    /// AIS VHF Data-link Message
+    VDM,
+    /// AIS VHF Data-link Own-vessel Report
+    VDO,
+    /// AIS Addressed Binary and Safety Related Message
+    ABM,
+    /// AIS Broadcast Binary Message
+    BBM,
+    /// AIS Addressed and Binary Broadcast Acknowledgement
+    ABK,
+    /// AIS Interrogation Request
+    AIR,
+    /// Digital Selective Calling Information
+    DSC,
+    /// Expanded Digital Selective Calling
+    DSE,
+    /// SeaSmart NMEA 2000 Message Encapsulation
+    PCDIN,
 }
 
 /// Geographic Position - Latitude/Longitude
@@ -56,6 +288,12 @@ impl NmeaGllMessage {
     }
 }
 
+impl Default for NmeaGllMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl MessageFields for NmeaGllMessage {
     fn clear(&mut self) { 
         self.latitude= 0.0;
@@ -68,17 +306,38 @@ impl MessageFields for NmeaGllMessage {
         
     }
 
-    fn get_field_mut(&mut self, idx: u8) -> &mut dyn FromSlice {
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
         match idx {
-            0 => &mut self.latitude,
-            1 => &mut self.latitude_dir,
-            2 => &mut self.longitude,
-            3 => &mut self.longitude_dir,
-            4 => &mut self.utc,
-            5 => &mut self.status,
-            6 => &mut self.mode,
-            
-            _ => panic!("Invalid field index"),
+            0 => Some(&mut self.latitude),
+            1 => Some(&mut self.latitude_dir),
+            2 => Some(&mut self.longitude),
+            3 => Some(&mut self.longitude_dir),
+            4 => Some(&mut self.utc),
+            5 => Some(&mut self.status),
+            6 => Some(&mut self.mode),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.latitude),
+            1 => Some(&self.latitude_dir),
+            2 => Some(&self.longitude),
+            3 => Some(&self.longitude_dir),
+            4 => Some(&self.utc),
+            5 => Some(&self.status),
+            6 => Some(&self.mode),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((4, 4)),
+            2 => Some((5, 4)),
+            _ => None,
         }
     }
 
@@ -142,6 +401,12 @@ impl NmeaRmcMessage {
     }
 }
 
+impl Default for NmeaRmcMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl MessageFields for NmeaRmcMessage {
     fn clear(&mut self) { 
         self.utc= None;
@@ -159,22 +424,49 @@ impl MessageFields for NmeaRmcMessage {
         
     }
 
-    fn get_field_mut(&mut self, idx: u8) -> &mut dyn FromSlice {
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
         match idx {
-            0 => &mut self.utc,
-            1 => &mut self.status,
-            2 => &mut self.latitude,
-            3 => &mut self.latitude_dir,
-            4 => &mut self.longitude,
-            5 => &mut self.longitude_dir,
-            6 => &mut self.sog,
-            7 => &mut self.cog,
-            8 => &mut self.date,
-            9 => &mut self.magnetic_variation,
-            10 => &mut self.magnetic_variation_dir,
-            11 => &mut self.mode,
-            
-            _ => panic!("Invalid field index"),
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.status),
+            2 => Some(&mut self.latitude),
+            3 => Some(&mut self.latitude_dir),
+            4 => Some(&mut self.longitude),
+            5 => Some(&mut self.longitude_dir),
+            6 => Some(&mut self.sog),
+            7 => Some(&mut self.cog),
+            8 => Some(&mut self.date),
+            9 => Some(&mut self.magnetic_variation),
+            10 => Some(&mut self.magnetic_variation_dir),
+            11 => Some(&mut self.mode),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.status),
+            2 => Some(&self.latitude),
+            3 => Some(&self.latitude_dir),
+            4 => Some(&self.longitude),
+            5 => Some(&self.longitude_dir),
+            6 => Some(&self.sog),
+            7 => Some(&self.cog),
+            8 => Some(&self.date),
+            9 => Some(&self.magnetic_variation),
+            10 => Some(&self.magnetic_variation_dir),
+            11 => Some(&self.mode),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            2 => Some((4, 4)),
+            4 => Some((5, 4)),
+            9 => Some((0, 1)),
+            _ => None,
         }
     }
 
@@ -199,3 +491,7754 @@ impl MessageFields for NmeaRmcMessage {
     }
 }
 
+/// Global Positioning System Fix Data
+/// Ex: $GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B
+#[derive(Debug)]
+pub struct NmeaGgaMessage { 
+    pub utc: Option<String>,             // hhmmss.ss
+    pub latitude: Option<f64>,             // ddmm.mm
+    pub latitude_dir: Option<u8>,             // N/S
+    pub longitude: Option<f64>,             // dddmm.mm
+    pub longitude_dir: Option<u8>,             // E/W
+    pub quality: Option<FixQuality>,             // 0-invalid; 1-GPS; 2-DGPS; 3-PPS; 4-RTK fixed; 5-RTK float; 6-estimated; 7-manual; 8-simulation
+    pub satellites: Option<u32>,             // Number of satellites in use
+    pub hdop: Option<f64>,             // Horizontal dilution of precision
+    pub altitude: Option<f64>,             // Altitude above mean sea level
+    pub altitude_unit: Option<DistanceUnit>,             // M-meters
+    pub geoid_separation: Option<f64>,             // Geoidal separation (geoid minus ellipsoid)
+    pub geoid_separation_unit: Option<DistanceUnit>,             // M-meters
+    pub diff_age: Option<f64>,             // Age of differential corrections, seconds
+    pub diff_station_id: Option<String>,             // Differential reference station ID
+    
+}
+
+impl NmeaGgaMessage {
+    pub fn new() -> NmeaGgaMessage {
+        NmeaGgaMessage { 
+            utc: None,
+            latitude: None,
+            latitude_dir: None,
+            longitude: None,
+            longitude_dir: None,
+            quality: None,
+            satellites: None,
+            hdop: None,
+            altitude: None,
+            altitude_unit: None,
+            geoid_separation: None,
+            geoid_separation_unit: None,
+            diff_age: None,
+            diff_station_id: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaGgaMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaGgaMessage {
+    fn clear(&mut self) { 
+        self.utc= None;
+        self.latitude= None;
+        self.latitude_dir= None;
+        self.longitude= None;
+        self.longitude_dir= None;
+        self.quality= None;
+        self.satellites= None;
+        self.hdop= None;
+        self.altitude= None;
+        self.altitude_unit= None;
+        self.geoid_separation= None;
+        self.geoid_separation_unit= None;
+        self.diff_age= None;
+        self.diff_station_id= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.latitude),
+            2 => Some(&mut self.latitude_dir),
+            3 => Some(&mut self.longitude),
+            4 => Some(&mut self.longitude_dir),
+            5 => Some(&mut self.quality),
+            6 => Some(&mut self.satellites),
+            7 => Some(&mut self.hdop),
+            8 => Some(&mut self.altitude),
+            9 => Some(&mut self.altitude_unit),
+            10 => Some(&mut self.geoid_separation),
+            11 => Some(&mut self.geoid_separation_unit),
+            12 => Some(&mut self.diff_age),
+            13 => Some(&mut self.diff_station_id),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.latitude),
+            2 => Some(&self.latitude_dir),
+            3 => Some(&self.longitude),
+            4 => Some(&self.longitude_dir),
+            5 => Some(&self.quality),
+            6 => Some(&self.satellites),
+            7 => Some(&self.hdop),
+            8 => Some(&self.altitude),
+            9 => Some(&self.altitude_unit),
+            10 => Some(&self.geoid_separation),
+            11 => Some(&self.geoid_separation_unit),
+            12 => Some(&self.diff_age),
+            13 => Some(&self.diff_station_id),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            1 => Some((4, 5)),
+            3 => Some((5, 5)),
+            6 => Some((2, 0)),
+            8 => Some((0, 1)),
+            10 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        14
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("GGA".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::GGA
+    }
+}
+
+/// GNSS DOP and Active Satellites
+/// Ex: $GNGSA,A,3,23,29,07,08,09,18,26,,,,,,1.94,1.18,1.54,1*04
+#[derive(Debug)]
+pub struct NmeaGsaMessage { 
+    pub selection_mode: Option<SelectionMode>,             // M-manual; A-automatic 2D/3D
+    pub fix_type: Option<FixType>,             // 1-no fix; 2-2D; 3-3D
+    pub prn_1: Option<u32>,             // ID of satellite used in fix
+    pub prn_2: Option<u32>,             // ID of satellite used in fix
+    pub prn_3: Option<u32>,             // ID of satellite used in fix
+    pub prn_4: Option<u32>,             // ID of satellite used in fix
+    pub prn_5: Option<u32>,             // ID of satellite used in fix
+    pub prn_6: Option<u32>,             // ID of satellite used in fix
+    pub prn_7: Option<u32>,             // ID of satellite used in fix
+    pub prn_8: Option<u32>,             // ID of satellite used in fix
+    pub prn_9: Option<u32>,             // ID of satellite used in fix
+    pub prn_10: Option<u32>,             // ID of satellite used in fix
+    pub prn_11: Option<u32>,             // ID of satellite used in fix
+    pub prn_12: Option<u32>,             // ID of satellite used in fix
+    pub pdop: Option<f64>,             // Position dilution of precision
+    pub hdop: Option<f64>,             // Horizontal dilution of precision
+    pub vdop: Option<f64>,             // Vertical dilution of precision
+    pub system_id: Option<GnssSystem>,             // Valid in NMEA v4.10+ only. 1-GPS; 2-GLONASS; 3-Galileo; 4-BeiDou; 5-QZSS; 6-NavIC
+    
+}
+
+impl NmeaGsaMessage {
+    pub fn new() -> NmeaGsaMessage {
+        NmeaGsaMessage { 
+            selection_mode: None,
+            fix_type: None,
+            prn_1: None,
+            prn_2: None,
+            prn_3: None,
+            prn_4: None,
+            prn_5: None,
+            prn_6: None,
+            prn_7: None,
+            prn_8: None,
+            prn_9: None,
+            prn_10: None,
+            prn_11: None,
+            prn_12: None,
+            pdop: None,
+            hdop: None,
+            vdop: None,
+            system_id: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaGsaMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaGsaMessage {
+    fn clear(&mut self) { 
+        self.selection_mode= None;
+        self.fix_type= None;
+        self.prn_1= None;
+        self.prn_2= None;
+        self.prn_3= None;
+        self.prn_4= None;
+        self.prn_5= None;
+        self.prn_6= None;
+        self.prn_7= None;
+        self.prn_8= None;
+        self.prn_9= None;
+        self.prn_10= None;
+        self.prn_11= None;
+        self.prn_12= None;
+        self.pdop= None;
+        self.hdop= None;
+        self.vdop= None;
+        self.system_id= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.selection_mode),
+            1 => Some(&mut self.fix_type),
+            2 => Some(&mut self.prn_1),
+            3 => Some(&mut self.prn_2),
+            4 => Some(&mut self.prn_3),
+            5 => Some(&mut self.prn_4),
+            6 => Some(&mut self.prn_5),
+            7 => Some(&mut self.prn_6),
+            8 => Some(&mut self.prn_7),
+            9 => Some(&mut self.prn_8),
+            10 => Some(&mut self.prn_9),
+            11 => Some(&mut self.prn_10),
+            12 => Some(&mut self.prn_11),
+            13 => Some(&mut self.prn_12),
+            14 => Some(&mut self.pdop),
+            15 => Some(&mut self.hdop),
+            16 => Some(&mut self.vdop),
+            17 => Some(&mut self.system_id),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.selection_mode),
+            1 => Some(&self.fix_type),
+            2 => Some(&self.prn_1),
+            3 => Some(&self.prn_2),
+            4 => Some(&self.prn_3),
+            5 => Some(&self.prn_4),
+            6 => Some(&self.prn_5),
+            7 => Some(&self.prn_6),
+            8 => Some(&self.prn_7),
+            9 => Some(&self.prn_8),
+            10 => Some(&self.prn_9),
+            11 => Some(&self.prn_10),
+            12 => Some(&self.prn_11),
+            13 => Some(&self.prn_12),
+            14 => Some(&self.pdop),
+            15 => Some(&self.hdop),
+            16 => Some(&self.vdop),
+            17 => Some(&self.system_id),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            2 => Some((2, 0)),
+            3 => Some((2, 0)),
+            4 => Some((2, 0)),
+            5 => Some((2, 0)),
+            6 => Some((2, 0)),
+            7 => Some((2, 0)),
+            8 => Some((2, 0)),
+            9 => Some((2, 0)),
+            10 => Some((2, 0)),
+            11 => Some((2, 0)),
+            12 => Some((2, 0)),
+            13 => Some((2, 0)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        18
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("GSA".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::GSA
+    }
+}
+
+/// GNSS Satellites in View
+/// Ex: $GPGSV,3,1,10,23,38,230,44,29,71,156,47,07,29,116,41,08,09,081,36,1*62
+#[derive(Debug)]
+pub struct NmeaGsvMessage { 
+    pub total_messages: Option<u32>,             // Total number of messages in group
+    pub message_number: Option<u32>,             // Message number in group, starting from 1
+    pub satellites_in_view: Option<u32>,             // Total number of satellites in view
+    pub satellites: Vec<String>,             // Up to 4 groups of PRN, elevation, azimuth, SNR. Followed by signal ID in NMEA v4.10+
+    
+}
+
+impl NmeaGsvMessage {
+    pub fn new() -> NmeaGsvMessage {
+        NmeaGsvMessage { 
+            total_messages: None,
+            message_number: None,
+            satellites_in_view: None,
+            satellites: Vec::new(),
+            
+        }
+    }
+}
+
+impl Default for NmeaGsvMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaGsvMessage {
+    fn clear(&mut self) { 
+        self.total_messages= None;
+        self.message_number= None;
+        self.satellites_in_view= None;
+        self.satellites= Vec::new();
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.total_messages),
+            1 => Some(&mut self.message_number),
+            2 => Some(&mut self.satellites_in_view),
+            _ => Some(&mut self.satellites),
+            
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.total_messages),
+            1 => Some(&self.message_number),
+            2 => Some(&self.satellites_in_view),
+            _ => Some(&self.satellites),
+            
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        4
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("GSV".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::GSV
+    }
+}
+
+/// Course Over Ground and Ground Speed
+/// Ex: $GPVTG,140.88,T,,M,8.04,N,14.89,K,D*05
+#[derive(Debug)]
+pub struct NmeaVtgMessage { 
+    pub course_true: Option<f64>,             // Course over ground, degrees True
+    pub course_true_ref: Option<HeadingReference>,             // T-true
+    pub course_magnetic: Option<f64>,             // Course over ground, degrees Magnetic
+    pub course_magnetic_ref: Option<HeadingReference>,             // M-magnetic
+    pub speed_knots: Option<f64>,             // Speed over ground, knots
+    pub speed_knots_unit: Option<SpeedUnit>,             // N-knots
+    pub speed_kmh: Option<f64>,             // Speed over ground, km/h
+    pub speed_kmh_unit: Option<SpeedUnit>,             // K-km/h
+    pub mode: Option<PositionMode>,             // Valid in NMEA v2.3+ only. A-autonomous; D-Differential; E-Estimated; M-Manual; S-Simulator; N-Data not valid
+    
+}
+
+impl NmeaVtgMessage {
+    pub fn new() -> NmeaVtgMessage {
+        NmeaVtgMessage { 
+            course_true: None,
+            course_true_ref: None,
+            course_magnetic: None,
+            course_magnetic_ref: None,
+            speed_knots: None,
+            speed_knots_unit: None,
+            speed_kmh: None,
+            speed_kmh_unit: None,
+            mode: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaVtgMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaVtgMessage {
+    fn clear(&mut self) { 
+        self.course_true= None;
+        self.course_true_ref= None;
+        self.course_magnetic= None;
+        self.course_magnetic_ref= None;
+        self.speed_knots= None;
+        self.speed_knots_unit= None;
+        self.speed_kmh= None;
+        self.speed_kmh_unit= None;
+        self.mode= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.course_true),
+            1 => Some(&mut self.course_true_ref),
+            2 => Some(&mut self.course_magnetic),
+            3 => Some(&mut self.course_magnetic_ref),
+            4 => Some(&mut self.speed_knots),
+            5 => Some(&mut self.speed_knots_unit),
+            6 => Some(&mut self.speed_kmh),
+            7 => Some(&mut self.speed_kmh_unit),
+            8 => Some(&mut self.mode),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.course_true),
+            1 => Some(&self.course_true_ref),
+            2 => Some(&self.course_magnetic),
+            3 => Some(&self.course_magnetic_ref),
+            4 => Some(&self.speed_knots),
+            5 => Some(&self.speed_knots_unit),
+            6 => Some(&self.speed_kmh),
+            7 => Some(&self.speed_kmh_unit),
+            8 => Some(&self.mode),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        9
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("VTG".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::VTG
+    }
+}
+
+/// Time and Date
+/// Ex: $GPZDA,201530.00,04,07,2002,00,00*60
+#[derive(Debug)]
+pub struct NmeaZdaMessage { 
+    pub utc: Option<String>,             // hhmmss.ss
+    pub day: Option<u32>,             // 01-31
+    pub month: Option<u32>,             // 01-12
+    pub year: Option<u32>,             // yyyy
+    pub local_zone_hours: Option<f64>,             // Local zone hours, -13..13. Keeps sign of -00
+    pub local_zone_minutes: Option<i32>,             // Local zone minutes, 00..59
+    
+}
+
+impl NmeaZdaMessage {
+    pub fn new() -> NmeaZdaMessage {
+        NmeaZdaMessage { 
+            utc: None,
+            day: None,
+            month: None,
+            year: None,
+            local_zone_hours: None,
+            local_zone_minutes: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaZdaMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaZdaMessage {
+    fn clear(&mut self) { 
+        self.utc= None;
+        self.day= None;
+        self.month= None;
+        self.year= None;
+        self.local_zone_hours= None;
+        self.local_zone_minutes= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.day),
+            2 => Some(&mut self.month),
+            3 => Some(&mut self.year),
+            4 => Some(&mut self.local_zone_hours),
+            5 => Some(&mut self.local_zone_minutes),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.day),
+            2 => Some(&self.month),
+            3 => Some(&self.year),
+            4 => Some(&self.local_zone_hours),
+            5 => Some(&self.local_zone_minutes),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            1 => Some((2, 0)),
+            2 => Some((2, 0)),
+            3 => Some((4, 0)),
+            4 => Some((2, 0)),
+            5 => Some((2, 0)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        6
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("ZDA".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::ZDA
+    }
+}
+
+/// GNSS Fix Data
+/// Ex: $GNGNS,014035.00,4332.69262,S,17235.48549,E,RR,13,0.9,25.63,11.24,,*70
+#[derive(Debug)]
+pub struct NmeaGnsMessage { 
+    pub utc: Option<String>,             // hhmmss.ss
+    pub latitude: Option<f64>,             // ddmm.mm
+    pub latitude_dir: Option<u8>,             // N/S
+    pub longitude: Option<f64>,             // dddmm.mm
+    pub longitude_dir: Option<u8>,             // E/W
+    pub mode: Option<String>,             // Mode indicator per system: GPS, GLONASS, Galileo, BeiDou, QZSS, NavIC
+    pub satellites: Option<u32>,             // Number of satellites in use
+    pub hdop: Option<f64>,             // Horizontal dilution of precision
+    pub altitude: Option<f64>,             // Altitude above mean sea level, meters
+    pub geoid_separation: Option<f64>,             // Geoidal separation, meters
+    pub diff_age: Option<f64>,             // Age of differential corrections, seconds
+    pub diff_station_id: Option<String>,             // Differential reference station ID
+    pub nav_status: Option<NavStatus>,             // Valid in NMEA v4.10+ only. S-safe; C-caution; U-unsafe; V-not valid
+    
+}
+
+impl NmeaGnsMessage {
+    pub fn new() -> NmeaGnsMessage {
+        NmeaGnsMessage { 
+            utc: None,
+            latitude: None,
+            latitude_dir: None,
+            longitude: None,
+            longitude_dir: None,
+            mode: None,
+            satellites: None,
+            hdop: None,
+            altitude: None,
+            geoid_separation: None,
+            diff_age: None,
+            diff_station_id: None,
+            nav_status: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaGnsMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaGnsMessage {
+    fn clear(&mut self) { 
+        self.utc= None;
+        self.latitude= None;
+        self.latitude_dir= None;
+        self.longitude= None;
+        self.longitude_dir= None;
+        self.mode= None;
+        self.satellites= None;
+        self.hdop= None;
+        self.altitude= None;
+        self.geoid_separation= None;
+        self.diff_age= None;
+        self.diff_station_id= None;
+        self.nav_status= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.latitude),
+            2 => Some(&mut self.latitude_dir),
+            3 => Some(&mut self.longitude),
+            4 => Some(&mut self.longitude_dir),
+            5 => Some(&mut self.mode),
+            6 => Some(&mut self.satellites),
+            7 => Some(&mut self.hdop),
+            8 => Some(&mut self.altitude),
+            9 => Some(&mut self.geoid_separation),
+            10 => Some(&mut self.diff_age),
+            11 => Some(&mut self.diff_station_id),
+            12 => Some(&mut self.nav_status),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.latitude),
+            2 => Some(&self.latitude_dir),
+            3 => Some(&self.longitude),
+            4 => Some(&self.longitude_dir),
+            5 => Some(&self.mode),
+            6 => Some(&self.satellites),
+            7 => Some(&self.hdop),
+            8 => Some(&self.altitude),
+            9 => Some(&self.geoid_separation),
+            10 => Some(&self.diff_age),
+            11 => Some(&self.diff_station_id),
+            12 => Some(&self.nav_status),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            1 => Some((4, 4)),
+            3 => Some((5, 4)),
+            6 => Some((2, 0)),
+            8 => Some((0, 1)),
+            9 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        13
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("GNS".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::GNS
+    }
+}
+
+/// GNSS Pseudorange Error Statistics
+/// Ex: $GPGST,172814.0,0.006,0.023,0.020,273.6,0.023,0.020,0.031*6A
+#[derive(Debug)]
+pub struct NmeaGstMessage { 
+    pub utc: Option<String>,             // hhmmss.ss
+    pub rms: Option<f64>,             // RMS value of the standard deviation of the range inputs
+    pub semi_major_sd: Option<f64>,             // Standard deviation of semi-major axis of error ellipse, meters
+    pub semi_minor_sd: Option<f64>,             // Standard deviation of semi-minor axis of error ellipse, meters
+    pub orientation: Option<f64>,             // Orientation of semi-major axis of error ellipse, degrees from true north
+    pub latitude_sd: Option<f64>,             // Standard deviation of latitude error, meters
+    pub longitude_sd: Option<f64>,             // Standard deviation of longitude error, meters
+    pub altitude_sd: Option<f64>,             // Standard deviation of altitude error, meters
+    
+}
+
+impl NmeaGstMessage {
+    pub fn new() -> NmeaGstMessage {
+        NmeaGstMessage { 
+            utc: None,
+            rms: None,
+            semi_major_sd: None,
+            semi_minor_sd: None,
+            orientation: None,
+            latitude_sd: None,
+            longitude_sd: None,
+            altitude_sd: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaGstMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaGstMessage {
+    fn clear(&mut self) { 
+        self.utc= None;
+        self.rms= None;
+        self.semi_major_sd= None;
+        self.semi_minor_sd= None;
+        self.orientation= None;
+        self.latitude_sd= None;
+        self.longitude_sd= None;
+        self.altitude_sd= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.rms),
+            2 => Some(&mut self.semi_major_sd),
+            3 => Some(&mut self.semi_minor_sd),
+            4 => Some(&mut self.orientation),
+            5 => Some(&mut self.latitude_sd),
+            6 => Some(&mut self.longitude_sd),
+            7 => Some(&mut self.altitude_sd),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.rms),
+            2 => Some(&self.semi_major_sd),
+            3 => Some(&self.semi_minor_sd),
+            4 => Some(&self.orientation),
+            5 => Some(&self.latitude_sd),
+            6 => Some(&self.longitude_sd),
+            7 => Some(&self.altitude_sd),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            1 => Some((0, 3)),
+            2 => Some((0, 3)),
+            3 => Some((0, 3)),
+            4 => Some((0, 1)),
+            5 => Some((0, 3)),
+            6 => Some((0, 3)),
+            7 => Some((0, 3)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        8
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("GST".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::GST
+    }
+}
+
+/// GNSS Satellite Fault Detection
+/// Ex: $GPGBS,235458.00,1.4,1.3,3.1,03,,-21.4,3.8,1,0*5A
+#[derive(Debug)]
+pub struct NmeaGbsMessage { 
+    pub utc: Option<String>,             // hhmmss.ss
+    pub latitude_error: Option<f64>,             // Expected error in latitude, meters
+    pub longitude_error: Option<f64>,             // Expected error in longitude, meters
+    pub altitude_error: Option<f64>,             // Expected error in altitude, meters
+    pub failed_prn: Option<u32>,             // ID of most likely failed satellite
+    pub missed_detection_probability: Option<f64>,             // Probability of missed detection for most likely failed satellite
+    pub bias: Option<f64>,             // Estimate of bias on most likely failed satellite, meters
+    pub bias_sd: Option<f64>,             // Standard deviation of bias estimate, meters
+    pub system_id: Option<GnssSystem>,             // Valid in NMEA v4.10+ only
+    pub signal_id: Option<SignalId>,             // Valid in NMEA v4.10+ only
+    
+}
+
+impl NmeaGbsMessage {
+    pub fn new() -> NmeaGbsMessage {
+        NmeaGbsMessage { 
+            utc: None,
+            latitude_error: None,
+            longitude_error: None,
+            altitude_error: None,
+            failed_prn: None,
+            missed_detection_probability: None,
+            bias: None,
+            bias_sd: None,
+            system_id: None,
+            signal_id: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaGbsMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaGbsMessage {
+    fn clear(&mut self) { 
+        self.utc= None;
+        self.latitude_error= None;
+        self.longitude_error= None;
+        self.altitude_error= None;
+        self.failed_prn= None;
+        self.missed_detection_probability= None;
+        self.bias= None;
+        self.bias_sd= None;
+        self.system_id= None;
+        self.signal_id= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.latitude_error),
+            2 => Some(&mut self.longitude_error),
+            3 => Some(&mut self.altitude_error),
+            4 => Some(&mut self.failed_prn),
+            5 => Some(&mut self.missed_detection_probability),
+            6 => Some(&mut self.bias),
+            7 => Some(&mut self.bias_sd),
+            8 => Some(&mut self.system_id),
+            9 => Some(&mut self.signal_id),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.latitude_error),
+            2 => Some(&self.longitude_error),
+            3 => Some(&self.altitude_error),
+            4 => Some(&self.failed_prn),
+            5 => Some(&self.missed_detection_probability),
+            6 => Some(&self.bias),
+            7 => Some(&self.bias_sd),
+            8 => Some(&self.system_id),
+            9 => Some(&self.signal_id),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            1 => Some((0, 1)),
+            2 => Some((0, 1)),
+            3 => Some((0, 1)),
+            4 => Some((2, 0)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        10
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("GBS".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::GBS
+    }
+}
+
+/// GNSS Range Residuals
+/// Ex: $GNGRS,104148.00,1,2.6,2.2,-1.6,-1.1,-1.7,-1.5,5.8,1.7,,,,,1,1*52
+#[derive(Debug)]
+pub struct NmeaGrsMessage { 
+    pub utc: Option<String>,             // hhmmss.ss
+    pub mode: Option<GrsMode>,             // 0-residuals used in GGA; 1-residuals recomputed after GGA
+    pub residual_1: Option<f64>,             // Range residual of satellite 1 in GSA order, meters
+    pub residual_2: Option<f64>,             // Range residual of satellite 2 in GSA order, meters
+    pub residual_3: Option<f64>,             // Range residual of satellite 3 in GSA order, meters
+    pub residual_4: Option<f64>,             // Range residual of satellite 4 in GSA order, meters
+    pub residual_5: Option<f64>,             // Range residual of satellite 5 in GSA order, meters
+    pub residual_6: Option<f64>,             // Range residual of satellite 6 in GSA order, meters
+    pub residual_7: Option<f64>,             // Range residual of satellite 7 in GSA order, meters
+    pub residual_8: Option<f64>,             // Range residual of satellite 8 in GSA order, meters
+    pub residual_9: Option<f64>,             // Range residual of satellite 9 in GSA order, meters
+    pub residual_10: Option<f64>,             // Range residual of satellite 10 in GSA order, meters
+    pub residual_11: Option<f64>,             // Range residual of satellite 11 in GSA order, meters
+    pub residual_12: Option<f64>,             // Range residual of satellite 12 in GSA order, meters
+    pub system_id: Option<GnssSystem>,             // Valid in NMEA v4.10+ only
+    pub signal_id: Option<SignalId>,             // Valid in NMEA v4.10+ only
+    
+}
+
+impl NmeaGrsMessage {
+    pub fn new() -> NmeaGrsMessage {
+        NmeaGrsMessage { 
+            utc: None,
+            mode: None,
+            residual_1: None,
+            residual_2: None,
+            residual_3: None,
+            residual_4: None,
+            residual_5: None,
+            residual_6: None,
+            residual_7: None,
+            residual_8: None,
+            residual_9: None,
+            residual_10: None,
+            residual_11: None,
+            residual_12: None,
+            system_id: None,
+            signal_id: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaGrsMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaGrsMessage {
+    fn clear(&mut self) { 
+        self.utc= None;
+        self.mode= None;
+        self.residual_1= None;
+        self.residual_2= None;
+        self.residual_3= None;
+        self.residual_4= None;
+        self.residual_5= None;
+        self.residual_6= None;
+        self.residual_7= None;
+        self.residual_8= None;
+        self.residual_9= None;
+        self.residual_10= None;
+        self.residual_11= None;
+        self.residual_12= None;
+        self.system_id= None;
+        self.signal_id= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.mode),
+            2 => Some(&mut self.residual_1),
+            3 => Some(&mut self.residual_2),
+            4 => Some(&mut self.residual_3),
+            5 => Some(&mut self.residual_4),
+            6 => Some(&mut self.residual_5),
+            7 => Some(&mut self.residual_6),
+            8 => Some(&mut self.residual_7),
+            9 => Some(&mut self.residual_8),
+            10 => Some(&mut self.residual_9),
+            11 => Some(&mut self.residual_10),
+            12 => Some(&mut self.residual_11),
+            13 => Some(&mut self.residual_12),
+            14 => Some(&mut self.system_id),
+            15 => Some(&mut self.signal_id),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.mode),
+            2 => Some(&self.residual_1),
+            3 => Some(&self.residual_2),
+            4 => Some(&self.residual_3),
+            5 => Some(&self.residual_4),
+            6 => Some(&self.residual_5),
+            7 => Some(&self.residual_6),
+            8 => Some(&self.residual_7),
+            9 => Some(&self.residual_8),
+            10 => Some(&self.residual_9),
+            11 => Some(&self.residual_10),
+            12 => Some(&self.residual_11),
+            13 => Some(&self.residual_12),
+            14 => Some(&self.system_id),
+            15 => Some(&self.signal_id),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        16
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("GRS".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::GRS
+    }
+}
+
+/// Heading - True
+/// Ex: $HEHDT,274.07,T*19
+#[derive(Debug)]
+pub struct NmeaHdtMessage { 
+    pub heading: Option<f64>,             // Heading, degrees True
+    pub reference: Option<HeadingReference>,             // T-true
+    
+}
+
+impl NmeaHdtMessage {
+    pub fn new() -> NmeaHdtMessage {
+        NmeaHdtMessage { 
+            heading: None,
+            reference: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaHdtMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaHdtMessage {
+    fn clear(&mut self) { 
+        self.heading= None;
+        self.reference= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.heading),
+            1 => Some(&mut self.reference),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.heading),
+            1 => Some(&self.reference),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        2
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("HDT".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::HDT
+    }
+}
+
+/// Heading - Deviation and Variation
+/// Ex: $HCHDG,98.3,0.0,E,12.6,W*57
+#[derive(Debug)]
+pub struct NmeaHdgMessage { 
+    pub heading: Option<f64>,             // Magnetic sensor heading, degrees
+    pub deviation: Option<f64>,             // Magnetic deviation, degrees
+    pub deviation_dir: Option<EastWest>,             // E/W
+    pub variation: Option<f64>,             // Magnetic variation, degrees
+    pub variation_dir: Option<EastWest>,             // E/W
+    
+}
+
+impl NmeaHdgMessage {
+    pub fn new() -> NmeaHdgMessage {
+        NmeaHdgMessage { 
+            heading: None,
+            deviation: None,
+            deviation_dir: None,
+            variation: None,
+            variation_dir: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaHdgMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaHdgMessage {
+    fn clear(&mut self) { 
+        self.heading= None;
+        self.deviation= None;
+        self.deviation_dir= None;
+        self.variation= None;
+        self.variation_dir= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.heading),
+            1 => Some(&mut self.deviation),
+            2 => Some(&mut self.deviation_dir),
+            3 => Some(&mut self.variation),
+            4 => Some(&mut self.variation_dir),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.heading),
+            1 => Some(&self.deviation),
+            2 => Some(&self.deviation_dir),
+            3 => Some(&self.variation),
+            4 => Some(&self.variation_dir),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((0, 1)),
+            1 => Some((0, 1)),
+            3 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        5
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("HDG".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::HDG
+    }
+}
+
+/// Heading - Magnetic
+/// Ex: $HCHDM,238.5,M*25
+#[derive(Debug)]
+pub struct NmeaHdmMessage { 
+    pub heading: Option<f64>,             // Heading, degrees Magnetic
+    pub reference: Option<HeadingReference>,             // M-magnetic
+    
+}
+
+impl NmeaHdmMessage {
+    pub fn new() -> NmeaHdmMessage {
+        NmeaHdmMessage { 
+            heading: None,
+            reference: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaHdmMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaHdmMessage {
+    fn clear(&mut self) { 
+        self.heading= None;
+        self.reference= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.heading),
+            1 => Some(&mut self.reference),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.heading),
+            1 => Some(&self.reference),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        2
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("HDM".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::HDM
+    }
+}
+
+/// True Heading and Status
+/// Ex: $GPTHS,77.52,E*34
+#[derive(Debug)]
+pub struct NmeaThsMessage { 
+    pub heading: Option<f64>,             // Heading, degrees True
+    pub mode: Option<HeadingMode>,             // A-autonomous; E-estimated (dead reckoning); M-manual; S-simulator; V-not valid
+    
+}
+
+impl NmeaThsMessage {
+    pub fn new() -> NmeaThsMessage {
+        NmeaThsMessage { 
+            heading: None,
+            mode: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaThsMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaThsMessage {
+    fn clear(&mut self) { 
+        self.heading= None;
+        self.mode= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.heading),
+            1 => Some(&mut self.mode),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.heading),
+            1 => Some(&self.mode),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        2
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("THS".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::THS
+    }
+}
+
+/// Rate Of Turn
+/// Ex: $TIROT,-3.5,A*10
+#[derive(Debug)]
+pub struct NmeaRotMessage { 
+    pub rate: Option<f64>,             // Rate of turn, degrees per minute. Negative - bow turns to port
+    pub status: Option<DataStatus>,             // A-valid; V-invalid
+    
+}
+
+impl NmeaRotMessage {
+    pub fn new() -> NmeaRotMessage {
+        NmeaRotMessage { 
+            rate: None,
+            status: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaRotMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaRotMessage {
+    fn clear(&mut self) { 
+        self.rate= None;
+        self.status= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.rate),
+            1 => Some(&mut self.status),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.rate),
+            1 => Some(&self.status),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        2
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("ROT".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::ROT
+    }
+}
+
+/// Wind Speed and Angle
+/// Ex: $WIMWV,214.8,R,0.1,K,A*28
+#[derive(Debug)]
+pub struct NmeaMwvMessage { 
+    pub angle: Option<f64>,             // Wind angle, 0 to 359 degrees
+    pub reference: Option<WindReference>,             // R-relative; T-theoretical (true)
+    pub speed: Option<f64>,             // Wind speed
+    pub speed_unit: Option<SpeedUnit>,             // K-km/h; M-m/s; N-knots
+    pub status: Option<DataStatus>,             // A-valid; V-invalid
+    
+}
+
+impl NmeaMwvMessage {
+    pub fn new() -> NmeaMwvMessage {
+        NmeaMwvMessage { 
+            angle: None,
+            reference: None,
+            speed: None,
+            speed_unit: None,
+            status: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaMwvMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaMwvMessage {
+    fn clear(&mut self) { 
+        self.angle= None;
+        self.reference= None;
+        self.speed= None;
+        self.speed_unit= None;
+        self.status= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.angle),
+            1 => Some(&mut self.reference),
+            2 => Some(&mut self.speed),
+            3 => Some(&mut self.speed_unit),
+            4 => Some(&mut self.status),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.angle),
+            1 => Some(&self.reference),
+            2 => Some(&self.speed),
+            3 => Some(&self.speed_unit),
+            4 => Some(&self.status),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        5
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("MWV".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::MWV
+    }
+}
+
+/// Wind Direction and Speed
+/// Ex: $WIMWD,10.1,T,10.1,M,12,N,40,M*5D
+#[derive(Debug)]
+pub struct NmeaMwdMessage { 
+    pub direction_true: Option<f64>,             // Wind direction, degrees True
+    pub direction_true_ref: Option<HeadingReference>,             // T-true
+    pub direction_magnetic: Option<f64>,             // Wind direction, degrees Magnetic
+    pub direction_magnetic_ref: Option<HeadingReference>,             // M-magnetic
+    pub speed_knots: Option<f64>,             // Wind speed, knots
+    pub speed_knots_unit: Option<SpeedUnit>,             // N-knots
+    pub speed_ms: Option<f64>,             // Wind speed, m/s
+    pub speed_ms_unit: Option<SpeedUnit>,             // M-m/s
+    
+}
+
+impl NmeaMwdMessage {
+    pub fn new() -> NmeaMwdMessage {
+        NmeaMwdMessage { 
+            direction_true: None,
+            direction_true_ref: None,
+            direction_magnetic: None,
+            direction_magnetic_ref: None,
+            speed_knots: None,
+            speed_knots_unit: None,
+            speed_ms: None,
+            speed_ms_unit: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaMwdMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaMwdMessage {
+    fn clear(&mut self) { 
+        self.direction_true= None;
+        self.direction_true_ref= None;
+        self.direction_magnetic= None;
+        self.direction_magnetic_ref= None;
+        self.speed_knots= None;
+        self.speed_knots_unit= None;
+        self.speed_ms= None;
+        self.speed_ms_unit= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.direction_true),
+            1 => Some(&mut self.direction_true_ref),
+            2 => Some(&mut self.direction_magnetic),
+            3 => Some(&mut self.direction_magnetic_ref),
+            4 => Some(&mut self.speed_knots),
+            5 => Some(&mut self.speed_knots_unit),
+            6 => Some(&mut self.speed_ms),
+            7 => Some(&mut self.speed_ms_unit),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.direction_true),
+            1 => Some(&self.direction_true_ref),
+            2 => Some(&self.direction_magnetic),
+            3 => Some(&self.direction_magnetic_ref),
+            4 => Some(&self.speed_knots),
+            5 => Some(&self.speed_knots_unit),
+            6 => Some(&self.speed_ms),
+            7 => Some(&self.speed_ms_unit),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        8
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("MWD".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::MWD
+    }
+}
+
+/// Meteorological Composite
+/// Ex: $WIMDA,29.7544,I,1.0076,B,35.5,C,,,42.1,,20.6,C,116.4,T,107.7,M,1.2,N,0.6,M*66
+#[derive(Debug)]
+pub struct NmeaMdaMessage { 
+    pub pressure_inhg: Option<f64>,             // Barometric pressure, inches of mercury
+    pub pressure_inhg_unit: Option<PressureUnit>,             // I-inches of mercury
+    pub pressure_bar: Option<f64>,             // Barometric pressure, bars
+    pub pressure_bar_unit: Option<PressureUnit>,             // B-bars
+    pub air_temperature: Option<f64>,             // Air temperature, degrees C
+    pub air_temperature_unit: Option<TemperatureUnit>,             // C-Celsius
+    pub water_temperature: Option<f64>,             // Water temperature, degrees C
+    pub water_temperature_unit: Option<TemperatureUnit>,             // C-Celsius
+    pub relative_humidity: Option<f64>,             // Relative humidity, percent
+    pub absolute_humidity: Option<f64>,             // Absolute humidity, percent
+    pub dew_point: Option<f64>,             // Dew point, degrees C
+    pub dew_point_unit: Option<TemperatureUnit>,             // C-Celsius
+    pub wind_direction_true: Option<f64>,             // Wind direction, degrees True
+    pub wind_direction_true_ref: Option<HeadingReference>,             // T-true
+    pub wind_direction_magnetic: Option<f64>,             // Wind direction, degrees Magnetic
+    pub wind_direction_magnetic_ref: Option<HeadingReference>,             // M-magnetic
+    pub wind_speed_knots: Option<f64>,             // Wind speed, knots
+    pub wind_speed_knots_unit: Option<SpeedUnit>,             // N-knots
+    pub wind_speed_ms: Option<f64>,             // Wind speed, m/s
+    pub wind_speed_ms_unit: Option<SpeedUnit>,             // M-m/s
+    
+}
+
+impl NmeaMdaMessage {
+    pub fn new() -> NmeaMdaMessage {
+        NmeaMdaMessage { 
+            pressure_inhg: None,
+            pressure_inhg_unit: None,
+            pressure_bar: None,
+            pressure_bar_unit: None,
+            air_temperature: None,
+            air_temperature_unit: None,
+            water_temperature: None,
+            water_temperature_unit: None,
+            relative_humidity: None,
+            absolute_humidity: None,
+            dew_point: None,
+            dew_point_unit: None,
+            wind_direction_true: None,
+            wind_direction_true_ref: None,
+            wind_direction_magnetic: None,
+            wind_direction_magnetic_ref: None,
+            wind_speed_knots: None,
+            wind_speed_knots_unit: None,
+            wind_speed_ms: None,
+            wind_speed_ms_unit: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaMdaMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaMdaMessage {
+    fn clear(&mut self) { 
+        self.pressure_inhg= None;
+        self.pressure_inhg_unit= None;
+        self.pressure_bar= None;
+        self.pressure_bar_unit= None;
+        self.air_temperature= None;
+        self.air_temperature_unit= None;
+        self.water_temperature= None;
+        self.water_temperature_unit= None;
+        self.relative_humidity= None;
+        self.absolute_humidity= None;
+        self.dew_point= None;
+        self.dew_point_unit= None;
+        self.wind_direction_true= None;
+        self.wind_direction_true_ref= None;
+        self.wind_direction_magnetic= None;
+        self.wind_direction_magnetic_ref= None;
+        self.wind_speed_knots= None;
+        self.wind_speed_knots_unit= None;
+        self.wind_speed_ms= None;
+        self.wind_speed_ms_unit= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.pressure_inhg),
+            1 => Some(&mut self.pressure_inhg_unit),
+            2 => Some(&mut self.pressure_bar),
+            3 => Some(&mut self.pressure_bar_unit),
+            4 => Some(&mut self.air_temperature),
+            5 => Some(&mut self.air_temperature_unit),
+            6 => Some(&mut self.water_temperature),
+            7 => Some(&mut self.water_temperature_unit),
+            8 => Some(&mut self.relative_humidity),
+            9 => Some(&mut self.absolute_humidity),
+            10 => Some(&mut self.dew_point),
+            11 => Some(&mut self.dew_point_unit),
+            12 => Some(&mut self.wind_direction_true),
+            13 => Some(&mut self.wind_direction_true_ref),
+            14 => Some(&mut self.wind_direction_magnetic),
+            15 => Some(&mut self.wind_direction_magnetic_ref),
+            16 => Some(&mut self.wind_speed_knots),
+            17 => Some(&mut self.wind_speed_knots_unit),
+            18 => Some(&mut self.wind_speed_ms),
+            19 => Some(&mut self.wind_speed_ms_unit),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.pressure_inhg),
+            1 => Some(&self.pressure_inhg_unit),
+            2 => Some(&self.pressure_bar),
+            3 => Some(&self.pressure_bar_unit),
+            4 => Some(&self.air_temperature),
+            5 => Some(&self.air_temperature_unit),
+            6 => Some(&self.water_temperature),
+            7 => Some(&self.water_temperature_unit),
+            8 => Some(&self.relative_humidity),
+            9 => Some(&self.absolute_humidity),
+            10 => Some(&self.dew_point),
+            11 => Some(&self.dew_point_unit),
+            12 => Some(&self.wind_direction_true),
+            13 => Some(&self.wind_direction_true_ref),
+            14 => Some(&self.wind_direction_magnetic),
+            15 => Some(&self.wind_direction_magnetic_ref),
+            16 => Some(&self.wind_speed_knots),
+            17 => Some(&self.wind_speed_knots_unit),
+            18 => Some(&self.wind_speed_ms),
+            19 => Some(&self.wind_speed_ms_unit),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        20
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("MDA".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::MDA
+    }
+}
+
+/// Water Temperature
+/// Ex: $YXMTW,15.2,C*14
+#[derive(Debug)]
+pub struct NmeaMtwMessage { 
+    pub temperature: Option<f64>,             // Water temperature
+    pub temperature_unit: Option<TemperatureUnit>,             // C-Celsius
+    
+}
+
+impl NmeaMtwMessage {
+    pub fn new() -> NmeaMtwMessage {
+        NmeaMtwMessage { 
+            temperature: None,
+            temperature_unit: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaMtwMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaMtwMessage {
+    fn clear(&mut self) { 
+        self.temperature= None;
+        self.temperature_unit= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.temperature),
+            1 => Some(&mut self.temperature_unit),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.temperature),
+            1 => Some(&self.temperature_unit),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        2
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("MTW".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::MTW
+    }
+}
+
+/// Transducer Measurements
+/// Ex: $IIXDR,C,19.52,C,TempAir,P,1.02481,B,Barometer*7E
+#[derive(Debug)]
+pub struct NmeaXdrMessage { 
+    pub measurements: Vec<String>,             // Groups of transducer type, value, unit and name
+    
+}
+
+impl NmeaXdrMessage {
+    pub fn new() -> NmeaXdrMessage {
+        NmeaXdrMessage { 
+            measurements: Vec::new(),
+            
+        }
+    }
+}
+
+impl Default for NmeaXdrMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaXdrMessage {
+    fn clear(&mut self) { 
+        self.measurements= Vec::new();
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            _ => Some(&mut self.measurements),
+            
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            _ => Some(&self.measurements),
+            
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        1
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("XDR".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::XDR
+    }
+}
+
+/// Depth Below Transducer
+/// Ex: $SDDBT,7.8,f,2.4,M,1.3,F*0D
+#[derive(Debug)]
+pub struct NmeaDbtMessage { 
+    pub depth_feet: Option<f64>,             // Water depth below transducer, feet
+    pub depth_feet_unit: Option<DistanceUnit>,             // f-feet
+    pub depth_meters: Option<f64>,             // Water depth below transducer, meters
+    pub depth_meters_unit: Option<DistanceUnit>,             // M-meters
+    pub depth_fathoms: Option<f64>,             // Water depth below transducer, fathoms
+    pub depth_fathoms_unit: Option<DistanceUnit>,             // F-fathoms
+    
+}
+
+impl NmeaDbtMessage {
+    pub fn new() -> NmeaDbtMessage {
+        NmeaDbtMessage { 
+            depth_feet: None,
+            depth_feet_unit: None,
+            depth_meters: None,
+            depth_meters_unit: None,
+            depth_fathoms: None,
+            depth_fathoms_unit: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaDbtMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaDbtMessage {
+    fn clear(&mut self) { 
+        self.depth_feet= None;
+        self.depth_feet_unit= None;
+        self.depth_meters= None;
+        self.depth_meters_unit= None;
+        self.depth_fathoms= None;
+        self.depth_fathoms_unit= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.depth_feet),
+            1 => Some(&mut self.depth_feet_unit),
+            2 => Some(&mut self.depth_meters),
+            3 => Some(&mut self.depth_meters_unit),
+            4 => Some(&mut self.depth_fathoms),
+            5 => Some(&mut self.depth_fathoms_unit),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.depth_feet),
+            1 => Some(&self.depth_feet_unit),
+            2 => Some(&self.depth_meters),
+            3 => Some(&self.depth_meters_unit),
+            4 => Some(&self.depth_fathoms),
+            5 => Some(&self.depth_fathoms_unit),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        6
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("DBT".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::DBT
+    }
+}
+
+/// Depth Below Surface
+/// Ex: $SDDBS,9.4,f,2.9,M,1.6,F*00
+#[derive(Debug)]
+pub struct NmeaDbsMessage { 
+    pub depth_feet: Option<f64>,             // Water depth below surface, feet
+    pub depth_feet_unit: Option<DistanceUnit>,             // f-feet
+    pub depth_meters: Option<f64>,             // Water depth below surface, meters
+    pub depth_meters_unit: Option<DistanceUnit>,             // M-meters
+    pub depth_fathoms: Option<f64>,             // Water depth below surface, fathoms
+    pub depth_fathoms_unit: Option<DistanceUnit>,             // F-fathoms
+    
+}
+
+impl NmeaDbsMessage {
+    pub fn new() -> NmeaDbsMessage {
+        NmeaDbsMessage { 
+            depth_feet: None,
+            depth_feet_unit: None,
+            depth_meters: None,
+            depth_meters_unit: None,
+            depth_fathoms: None,
+            depth_fathoms_unit: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaDbsMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaDbsMessage {
+    fn clear(&mut self) { 
+        self.depth_feet= None;
+        self.depth_feet_unit= None;
+        self.depth_meters= None;
+        self.depth_meters_unit= None;
+        self.depth_fathoms= None;
+        self.depth_fathoms_unit= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.depth_feet),
+            1 => Some(&mut self.depth_feet_unit),
+            2 => Some(&mut self.depth_meters),
+            3 => Some(&mut self.depth_meters_unit),
+            4 => Some(&mut self.depth_fathoms),
+            5 => Some(&mut self.depth_fathoms_unit),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.depth_feet),
+            1 => Some(&self.depth_feet_unit),
+            2 => Some(&self.depth_meters),
+            3 => Some(&self.depth_meters_unit),
+            4 => Some(&self.depth_fathoms),
+            5 => Some(&self.depth_fathoms_unit),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        6
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("DBS".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::DBS
+    }
+}
+
+/// Depth
+/// Ex: $SDDPT,2.4,0.5,100.0*57
+#[derive(Debug)]
+pub struct NmeaDptMessage { 
+    pub depth: Option<f64>,             // Water depth relative to transducer, meters
+    pub offset: Option<f64>,             // Offset from transducer, meters. Positive - distance to waterline; negative - distance to keel
+    pub max_range: Option<f64>,             // Valid in NMEA v3.0+ only. Maximum range scale in use, meters
+    
+}
+
+impl NmeaDptMessage {
+    pub fn new() -> NmeaDptMessage {
+        NmeaDptMessage { 
+            depth: None,
+            offset: None,
+            max_range: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaDptMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaDptMessage {
+    fn clear(&mut self) { 
+        self.depth= None;
+        self.offset= None;
+        self.max_range= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.depth),
+            1 => Some(&mut self.offset),
+            2 => Some(&mut self.max_range),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.depth),
+            1 => Some(&self.offset),
+            2 => Some(&self.max_range),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((0, 1)),
+            1 => Some((0, 1)),
+            2 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        3
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("DPT".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::DPT
+    }
+}
+
+/// Water Speed and Heading
+/// Ex: $VWVHW,45.0,T,43.0,M,3.5,N,6.4,K*56
+#[derive(Debug)]
+pub struct NmeaVhwMessage { 
+    pub heading_true: Option<f64>,             // Heading, degrees True
+    pub heading_true_ref: Option<HeadingReference>,             // T-true
+    pub heading_magnetic: Option<f64>,             // Heading, degrees Magnetic
+    pub heading_magnetic_ref: Option<HeadingReference>,             // M-magnetic
+    pub speed_knots: Option<f64>,             // Speed through water, knots
+    pub speed_knots_unit: Option<SpeedUnit>,             // N-knots
+    pub speed_kmh: Option<f64>,             // Speed through water, km/h
+    pub speed_kmh_unit: Option<SpeedUnit>,             // K-km/h
+    
+}
+
+impl NmeaVhwMessage {
+    pub fn new() -> NmeaVhwMessage {
+        NmeaVhwMessage { 
+            heading_true: None,
+            heading_true_ref: None,
+            heading_magnetic: None,
+            heading_magnetic_ref: None,
+            speed_knots: None,
+            speed_knots_unit: None,
+            speed_kmh: None,
+            speed_kmh_unit: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaVhwMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaVhwMessage {
+    fn clear(&mut self) { 
+        self.heading_true= None;
+        self.heading_true_ref= None;
+        self.heading_magnetic= None;
+        self.heading_magnetic_ref= None;
+        self.speed_knots= None;
+        self.speed_knots_unit= None;
+        self.speed_kmh= None;
+        self.speed_kmh_unit= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.heading_true),
+            1 => Some(&mut self.heading_true_ref),
+            2 => Some(&mut self.heading_magnetic),
+            3 => Some(&mut self.heading_magnetic_ref),
+            4 => Some(&mut self.speed_knots),
+            5 => Some(&mut self.speed_knots_unit),
+            6 => Some(&mut self.speed_kmh),
+            7 => Some(&mut self.speed_kmh_unit),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.heading_true),
+            1 => Some(&self.heading_true_ref),
+            2 => Some(&self.heading_magnetic),
+            3 => Some(&self.heading_magnetic_ref),
+            4 => Some(&self.speed_knots),
+            5 => Some(&self.speed_knots_unit),
+            6 => Some(&self.speed_kmh),
+            7 => Some(&self.speed_kmh_unit),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((0, 1)),
+            2 => Some((0, 1)),
+            4 => Some((0, 1)),
+            6 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        8
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("VHW".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::VHW
+    }
+}
+
+/// Dual Ground/Water Distance
+/// Ex: $VWVLW,7803.2,N,0.00,N*42
+#[derive(Debug)]
+pub struct NmeaVlwMessage { 
+    pub total_water_distance: Option<f64>,             // Total cumulative water distance, nautical miles
+    pub total_water_distance_unit: Option<DistanceUnit>,             // N-nautical miles
+    pub trip_water_distance: Option<f64>,             // Water distance since reset, nautical miles
+    pub trip_water_distance_unit: Option<DistanceUnit>,             // N-nautical miles
+    pub total_ground_distance: Option<f64>,             // Valid in NMEA v4.0+ only. Total cumulative ground distance, nautical miles
+    pub total_ground_distance_unit: Option<DistanceUnit>,             // N-nautical miles
+    pub trip_ground_distance: Option<f64>,             // Valid in NMEA v4.0+ only. Ground distance since reset, nautical miles
+    pub trip_ground_distance_unit: Option<DistanceUnit>,             // N-nautical miles
+    
+}
+
+impl NmeaVlwMessage {
+    pub fn new() -> NmeaVlwMessage {
+        NmeaVlwMessage { 
+            total_water_distance: None,
+            total_water_distance_unit: None,
+            trip_water_distance: None,
+            trip_water_distance_unit: None,
+            total_ground_distance: None,
+            total_ground_distance_unit: None,
+            trip_ground_distance: None,
+            trip_ground_distance_unit: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaVlwMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaVlwMessage {
+    fn clear(&mut self) { 
+        self.total_water_distance= None;
+        self.total_water_distance_unit= None;
+        self.trip_water_distance= None;
+        self.trip_water_distance_unit= None;
+        self.total_ground_distance= None;
+        self.total_ground_distance_unit= None;
+        self.trip_ground_distance= None;
+        self.trip_ground_distance_unit= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.total_water_distance),
+            1 => Some(&mut self.total_water_distance_unit),
+            2 => Some(&mut self.trip_water_distance),
+            3 => Some(&mut self.trip_water_distance_unit),
+            4 => Some(&mut self.total_ground_distance),
+            5 => Some(&mut self.total_ground_distance_unit),
+            6 => Some(&mut self.trip_ground_distance),
+            7 => Some(&mut self.trip_ground_distance_unit),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.total_water_distance),
+            1 => Some(&self.total_water_distance_unit),
+            2 => Some(&self.trip_water_distance),
+            3 => Some(&self.trip_water_distance_unit),
+            4 => Some(&self.total_ground_distance),
+            5 => Some(&self.total_ground_distance_unit),
+            6 => Some(&self.trip_ground_distance),
+            7 => Some(&self.trip_ground_distance_unit),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((0, 1)),
+            2 => Some((0, 2)),
+            4 => Some((0, 1)),
+            6 => Some((0, 2)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        8
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("VLW".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::VLW
+    }
+}
+
+/// Dual Ground/Water Speed
+/// Ex: $VMVBW,12.3,0.07,A,11.78,0.12,A*63
+#[derive(Debug)]
+pub struct NmeaVbwMessage { 
+    pub water_speed_longitudinal: Option<f64>,             // Longitudinal water speed, knots. Negative - astern
+    pub water_speed_transverse: Option<f64>,             // Transverse water speed, knots. Negative - port
+    pub water_speed_status: Option<DataStatus>,             // A-valid; V-invalid
+    pub ground_speed_longitudinal: Option<f64>,             // Longitudinal ground speed, knots. Negative - astern
+    pub ground_speed_transverse: Option<f64>,             // Transverse ground speed, knots. Negative - port
+    pub ground_speed_status: Option<DataStatus>,             // A-valid; V-invalid
+    pub stern_water_speed_transverse: Option<f64>,             // Valid in NMEA v3.0+ only. Stern transverse water speed, knots
+    pub stern_water_speed_status: Option<DataStatus>,             // Valid in NMEA v3.0+ only. A-valid; V-invalid
+    pub stern_ground_speed_transverse: Option<f64>,             // Valid in NMEA v3.0+ only. Stern transverse ground speed, knots
+    pub stern_ground_speed_status: Option<DataStatus>,             // Valid in NMEA v3.0+ only. A-valid; V-invalid
+    
+}
+
+impl NmeaVbwMessage {
+    pub fn new() -> NmeaVbwMessage {
+        NmeaVbwMessage { 
+            water_speed_longitudinal: None,
+            water_speed_transverse: None,
+            water_speed_status: None,
+            ground_speed_longitudinal: None,
+            ground_speed_transverse: None,
+            ground_speed_status: None,
+            stern_water_speed_transverse: None,
+            stern_water_speed_status: None,
+            stern_ground_speed_transverse: None,
+            stern_ground_speed_status: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaVbwMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaVbwMessage {
+    fn clear(&mut self) { 
+        self.water_speed_longitudinal= None;
+        self.water_speed_transverse= None;
+        self.water_speed_status= None;
+        self.ground_speed_longitudinal= None;
+        self.ground_speed_transverse= None;
+        self.ground_speed_status= None;
+        self.stern_water_speed_transverse= None;
+        self.stern_water_speed_status= None;
+        self.stern_ground_speed_transverse= None;
+        self.stern_ground_speed_status= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.water_speed_longitudinal),
+            1 => Some(&mut self.water_speed_transverse),
+            2 => Some(&mut self.water_speed_status),
+            3 => Some(&mut self.ground_speed_longitudinal),
+            4 => Some(&mut self.ground_speed_transverse),
+            5 => Some(&mut self.ground_speed_status),
+            6 => Some(&mut self.stern_water_speed_transverse),
+            7 => Some(&mut self.stern_water_speed_status),
+            8 => Some(&mut self.stern_ground_speed_transverse),
+            9 => Some(&mut self.stern_ground_speed_status),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.water_speed_longitudinal),
+            1 => Some(&self.water_speed_transverse),
+            2 => Some(&self.water_speed_status),
+            3 => Some(&self.ground_speed_longitudinal),
+            4 => Some(&self.ground_speed_transverse),
+            5 => Some(&self.ground_speed_status),
+            6 => Some(&self.stern_water_speed_transverse),
+            7 => Some(&self.stern_water_speed_status),
+            8 => Some(&self.stern_ground_speed_transverse),
+            9 => Some(&self.stern_ground_speed_status),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        10
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("VBW".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::VBW
+    }
+}
+
+/// Recommended Minimum Navigation Information
+/// Ex: $GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20
+#[derive(Debug)]
+pub struct NmeaRmbMessage { 
+    pub status: Option<DataStatus>,             // A-valid; V-warning
+    pub xte: Option<f64>,             // Cross-track error, nautical miles
+    pub steer: Option<SteerDirection>,             // Direction to steer: L-left; R-right
+    pub origin_id: Option<String>,             // Origin waypoint ID
+    pub destination_id: Option<String>,             // Destination waypoint ID
+    pub destination_latitude: Option<f64>,             // Destination waypoint ddmm.mm
+    pub destination_latitude_dir: Option<u8>,             // Destination waypoint N/S
+    pub destination_longitude: Option<f64>,             // Destination waypoint dddmm.mm
+    pub destination_longitude_dir: Option<u8>,             // Destination waypoint E/W
+    pub range: Option<f64>,             // Range to destination, nautical miles
+    pub bearing: Option<f64>,             // Bearing to destination, degrees True
+    pub closing_velocity: Option<f64>,             // Destination closing velocity, knots
+    pub arrival_status: Option<DataStatus>,             // A-arrival circle entered or perpendicular passed; V-not entered/passed
+    pub mode: Option<PositionMode>,             // Valid in NMEA v2.3+ only. A-autonomous; D-Differential; E-Estimated; M-Manual; S-Simulator; N-Data not valid
+    
+}
+
+impl NmeaRmbMessage {
+    pub fn new() -> NmeaRmbMessage {
+        NmeaRmbMessage { 
+            status: None,
+            xte: None,
+            steer: None,
+            origin_id: None,
+            destination_id: None,
+            destination_latitude: None,
+            destination_latitude_dir: None,
+            destination_longitude: None,
+            destination_longitude_dir: None,
+            range: None,
+            bearing: None,
+            closing_velocity: None,
+            arrival_status: None,
+            mode: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaRmbMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaRmbMessage {
+    fn clear(&mut self) { 
+        self.status= None;
+        self.xte= None;
+        self.steer= None;
+        self.origin_id= None;
+        self.destination_id= None;
+        self.destination_latitude= None;
+        self.destination_latitude_dir= None;
+        self.destination_longitude= None;
+        self.destination_longitude_dir= None;
+        self.range= None;
+        self.bearing= None;
+        self.closing_velocity= None;
+        self.arrival_status= None;
+        self.mode= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.status),
+            1 => Some(&mut self.xte),
+            2 => Some(&mut self.steer),
+            3 => Some(&mut self.origin_id),
+            4 => Some(&mut self.destination_id),
+            5 => Some(&mut self.destination_latitude),
+            6 => Some(&mut self.destination_latitude_dir),
+            7 => Some(&mut self.destination_longitude),
+            8 => Some(&mut self.destination_longitude_dir),
+            9 => Some(&mut self.range),
+            10 => Some(&mut self.bearing),
+            11 => Some(&mut self.closing_velocity),
+            12 => Some(&mut self.arrival_status),
+            13 => Some(&mut self.mode),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.status),
+            1 => Some(&self.xte),
+            2 => Some(&self.steer),
+            3 => Some(&self.origin_id),
+            4 => Some(&self.destination_id),
+            5 => Some(&self.destination_latitude),
+            6 => Some(&self.destination_latitude_dir),
+            7 => Some(&self.destination_longitude),
+            8 => Some(&self.destination_longitude_dir),
+            9 => Some(&self.range),
+            10 => Some(&self.bearing),
+            11 => Some(&self.closing_velocity),
+            12 => Some(&self.arrival_status),
+            13 => Some(&self.mode),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            1 => Some((0, 2)),
+            5 => Some((4, 2)),
+            7 => Some((5, 2)),
+            9 => Some((3, 1)),
+            10 => Some((3, 1)),
+            11 => Some((3, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        14
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("RMB".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::RMB
+    }
+}
+
+/// Heading/Track Controller (Autopilot) Sentence B
+/// Ex: $GPAPB,A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M*3C
+#[derive(Debug)]
+pub struct NmeaApbMessage { 
+    pub status_warning: Option<DataStatus>,             // A-valid; V-general warning flag
+    pub status_lock: Option<DataStatus>,             // A-valid; V-cycle lock warning flag
+    pub xte: Option<f64>,             // Magnitude of cross-track error
+    pub steer: Option<SteerDirection>,             // Direction to steer: L-left; R-right
+    pub xte_unit: Option<DistanceUnit>,             // N-nautical miles; K-kilometers
+    pub arrival_circle: Option<DataStatus>,             // A-arrival circle entered
+    pub perpendicular_passed: Option<DataStatus>,             // A-perpendicular passed at waypoint
+    pub bearing_origin_to_destination: Option<f64>,             // Bearing origin to destination, degrees
+    pub bearing_origin_to_destination_ref: Option<HeadingReference>,             // M-magnetic; T-true
+    pub destination_id: Option<String>,             // Destination waypoint ID
+    pub bearing_to_destination: Option<f64>,             // Bearing present position to destination, degrees
+    pub bearing_to_destination_ref: Option<HeadingReference>,             // M-magnetic; T-true
+    pub heading_to_steer: Option<f64>,             // Heading to steer to destination waypoint, degrees
+    pub heading_to_steer_ref: Option<HeadingReference>,             // M-magnetic; T-true
+    pub mode: Option<PositionMode>,             // Valid in NMEA v2.3+ only. A-autonomous; D-Differential; E-Estimated; M-Manual; S-Simulator; N-Data not valid
+    
+}
+
+impl NmeaApbMessage {
+    pub fn new() -> NmeaApbMessage {
+        NmeaApbMessage { 
+            status_warning: None,
+            status_lock: None,
+            xte: None,
+            steer: None,
+            xte_unit: None,
+            arrival_circle: None,
+            perpendicular_passed: None,
+            bearing_origin_to_destination: None,
+            bearing_origin_to_destination_ref: None,
+            destination_id: None,
+            bearing_to_destination: None,
+            bearing_to_destination_ref: None,
+            heading_to_steer: None,
+            heading_to_steer_ref: None,
+            mode: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaApbMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaApbMessage {
+    fn clear(&mut self) { 
+        self.status_warning= None;
+        self.status_lock= None;
+        self.xte= None;
+        self.steer= None;
+        self.xte_unit= None;
+        self.arrival_circle= None;
+        self.perpendicular_passed= None;
+        self.bearing_origin_to_destination= None;
+        self.bearing_origin_to_destination_ref= None;
+        self.destination_id= None;
+        self.bearing_to_destination= None;
+        self.bearing_to_destination_ref= None;
+        self.heading_to_steer= None;
+        self.heading_to_steer_ref= None;
+        self.mode= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.status_warning),
+            1 => Some(&mut self.status_lock),
+            2 => Some(&mut self.xte),
+            3 => Some(&mut self.steer),
+            4 => Some(&mut self.xte_unit),
+            5 => Some(&mut self.arrival_circle),
+            6 => Some(&mut self.perpendicular_passed),
+            7 => Some(&mut self.bearing_origin_to_destination),
+            8 => Some(&mut self.bearing_origin_to_destination_ref),
+            9 => Some(&mut self.destination_id),
+            10 => Some(&mut self.bearing_to_destination),
+            11 => Some(&mut self.bearing_to_destination_ref),
+            12 => Some(&mut self.heading_to_steer),
+            13 => Some(&mut self.heading_to_steer_ref),
+            14 => Some(&mut self.mode),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.status_warning),
+            1 => Some(&self.status_lock),
+            2 => Some(&self.xte),
+            3 => Some(&self.steer),
+            4 => Some(&self.xte_unit),
+            5 => Some(&self.arrival_circle),
+            6 => Some(&self.perpendicular_passed),
+            7 => Some(&self.bearing_origin_to_destination),
+            8 => Some(&self.bearing_origin_to_destination_ref),
+            9 => Some(&self.destination_id),
+            10 => Some(&self.bearing_to_destination),
+            11 => Some(&self.bearing_to_destination_ref),
+            12 => Some(&self.heading_to_steer),
+            13 => Some(&self.heading_to_steer_ref),
+            14 => Some(&self.mode),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            2 => Some((0, 2)),
+            7 => Some((3, 0)),
+            10 => Some((3, 0)),
+            12 => Some((3, 0)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        15
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("APB".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::APB
+    }
+}
+
+/// Bearing - Waypoint to Waypoint
+/// Ex: $GPBOD,099.3,T,105.6,M,POINTB,POINTA*45
+#[derive(Debug)]
+pub struct NmeaBodMessage { 
+    pub bearing_true: Option<f64>,             // Bearing origin to destination, degrees True
+    pub bearing_true_ref: Option<HeadingReference>,             // T-true
+    pub bearing_magnetic: Option<f64>,             // Bearing origin to destination, degrees Magnetic
+    pub bearing_magnetic_ref: Option<HeadingReference>,             // M-magnetic
+    pub destination_id: Option<String>,             // Destination waypoint ID
+    pub origin_id: Option<String>,             // Origin waypoint ID
+    
+}
+
+impl NmeaBodMessage {
+    pub fn new() -> NmeaBodMessage {
+        NmeaBodMessage { 
+            bearing_true: None,
+            bearing_true_ref: None,
+            bearing_magnetic: None,
+            bearing_magnetic_ref: None,
+            destination_id: None,
+            origin_id: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaBodMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaBodMessage {
+    fn clear(&mut self) { 
+        self.bearing_true= None;
+        self.bearing_true_ref= None;
+        self.bearing_magnetic= None;
+        self.bearing_magnetic_ref= None;
+        self.destination_id= None;
+        self.origin_id= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.bearing_true),
+            1 => Some(&mut self.bearing_true_ref),
+            2 => Some(&mut self.bearing_magnetic),
+            3 => Some(&mut self.bearing_magnetic_ref),
+            4 => Some(&mut self.destination_id),
+            5 => Some(&mut self.origin_id),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.bearing_true),
+            1 => Some(&self.bearing_true_ref),
+            2 => Some(&self.bearing_magnetic),
+            3 => Some(&self.bearing_magnetic_ref),
+            4 => Some(&self.destination_id),
+            5 => Some(&self.origin_id),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((3, 1)),
+            2 => Some((3, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        6
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("BOD".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::BOD
+    }
+}
+
+/// Bearing and Distance to Waypoint - Great Circle
+/// Ex: $GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*21
+#[derive(Debug)]
+pub struct NmeaBwcMessage { 
+    pub utc: Option<String>,             // hhmmss.ss
+    pub waypoint_latitude: Option<f64>,             // Waypoint ddmm.mm
+    pub waypoint_latitude_dir: Option<u8>,             // Waypoint N/S
+    pub waypoint_longitude: Option<f64>,             // Waypoint dddmm.mm
+    pub waypoint_longitude_dir: Option<u8>,             // Waypoint E/W
+    pub bearing_true: Option<f64>,             // Bearing to waypoint, degrees True
+    pub bearing_true_ref: Option<HeadingReference>,             // T-true
+    pub bearing_magnetic: Option<f64>,             // Bearing to waypoint, degrees Magnetic
+    pub bearing_magnetic_ref: Option<HeadingReference>,             // M-magnetic
+    pub distance: Option<f64>,             // Distance to waypoint, nautical miles
+    pub distance_unit: Option<DistanceUnit>,             // N-nautical miles
+    pub waypoint_id: Option<String>,             // Waypoint ID
+    pub mode: Option<PositionMode>,             // Valid in NMEA v2.3+ only. A-autonomous; D-Differential; E-Estimated; M-Manual; S-Simulator; N-Data not valid
+    
+}
+
+impl NmeaBwcMessage {
+    pub fn new() -> NmeaBwcMessage {
+        NmeaBwcMessage { 
+            utc: None,
+            waypoint_latitude: None,
+            waypoint_latitude_dir: None,
+            waypoint_longitude: None,
+            waypoint_longitude_dir: None,
+            bearing_true: None,
+            bearing_true_ref: None,
+            bearing_magnetic: None,
+            bearing_magnetic_ref: None,
+            distance: None,
+            distance_unit: None,
+            waypoint_id: None,
+            mode: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaBwcMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaBwcMessage {
+    fn clear(&mut self) { 
+        self.utc= None;
+        self.waypoint_latitude= None;
+        self.waypoint_latitude_dir= None;
+        self.waypoint_longitude= None;
+        self.waypoint_longitude_dir= None;
+        self.bearing_true= None;
+        self.bearing_true_ref= None;
+        self.bearing_magnetic= None;
+        self.bearing_magnetic_ref= None;
+        self.distance= None;
+        self.distance_unit= None;
+        self.waypoint_id= None;
+        self.mode= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.waypoint_latitude),
+            2 => Some(&mut self.waypoint_latitude_dir),
+            3 => Some(&mut self.waypoint_longitude),
+            4 => Some(&mut self.waypoint_longitude_dir),
+            5 => Some(&mut self.bearing_true),
+            6 => Some(&mut self.bearing_true_ref),
+            7 => Some(&mut self.bearing_magnetic),
+            8 => Some(&mut self.bearing_magnetic_ref),
+            9 => Some(&mut self.distance),
+            10 => Some(&mut self.distance_unit),
+            11 => Some(&mut self.waypoint_id),
+            12 => Some(&mut self.mode),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.waypoint_latitude),
+            2 => Some(&self.waypoint_latitude_dir),
+            3 => Some(&self.waypoint_longitude),
+            4 => Some(&self.waypoint_longitude_dir),
+            5 => Some(&self.bearing_true),
+            6 => Some(&self.bearing_true_ref),
+            7 => Some(&self.bearing_magnetic),
+            8 => Some(&self.bearing_magnetic_ref),
+            9 => Some(&self.distance),
+            10 => Some(&self.distance_unit),
+            11 => Some(&self.waypoint_id),
+            12 => Some(&self.mode),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            1 => Some((4, 2)),
+            3 => Some((5, 2)),
+            5 => Some((3, 1)),
+            7 => Some((3, 1)),
+            9 => Some((4, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        13
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("BWC".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::BWC
+    }
+}
+
+/// Cross-Track Error, Measured
+/// Ex: $GPXTE,A,A,0.67,L,N*6F
+#[derive(Debug)]
+pub struct NmeaXteMessage { 
+    pub status_warning: Option<DataStatus>,             // A-valid; V-general warning flag
+    pub status_lock: Option<DataStatus>,             // A-valid; V-cycle lock warning flag
+    pub xte: Option<f64>,             // Magnitude of cross-track error
+    pub steer: Option<SteerDirection>,             // Direction to steer: L-left; R-right
+    pub xte_unit: Option<DistanceUnit>,             // N-nautical miles
+    pub mode: Option<PositionMode>,             // Valid in NMEA v2.3+ only. A-autonomous; D-Differential; E-Estimated; M-Manual; S-Simulator; N-Data not valid
+    
+}
+
+impl NmeaXteMessage {
+    pub fn new() -> NmeaXteMessage {
+        NmeaXteMessage { 
+            status_warning: None,
+            status_lock: None,
+            xte: None,
+            steer: None,
+            xte_unit: None,
+            mode: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaXteMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaXteMessage {
+    fn clear(&mut self) { 
+        self.status_warning= None;
+        self.status_lock= None;
+        self.xte= None;
+        self.steer= None;
+        self.xte_unit= None;
+        self.mode= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.status_warning),
+            1 => Some(&mut self.status_lock),
+            2 => Some(&mut self.xte),
+            3 => Some(&mut self.steer),
+            4 => Some(&mut self.xte_unit),
+            5 => Some(&mut self.mode),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.status_warning),
+            1 => Some(&self.status_lock),
+            2 => Some(&self.xte),
+            3 => Some(&self.steer),
+            4 => Some(&self.xte_unit),
+            5 => Some(&self.mode),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            2 => Some((0, 2)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        6
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("XTE".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::XTE
+    }
+}
+
+/// Waypoint Location
+/// Ex: $GPWPL,4917.16,N,12310.64,W,003*65
+#[derive(Debug)]
+pub struct NmeaWplMessage { 
+    pub latitude: Option<f64>,             // ddmm.mm
+    pub latitude_dir: Option<u8>,             // N/S
+    pub longitude: Option<f64>,             // dddmm.mm
+    pub longitude_dir: Option<u8>,             // E/W
+    pub waypoint_id: Option<String>,             // Waypoint ID
+    
+}
+
+impl NmeaWplMessage {
+    pub fn new() -> NmeaWplMessage {
+        NmeaWplMessage { 
+            latitude: None,
+            latitude_dir: None,
+            longitude: None,
+            longitude_dir: None,
+            waypoint_id: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaWplMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaWplMessage {
+    fn clear(&mut self) { 
+        self.latitude= None;
+        self.latitude_dir= None;
+        self.longitude= None;
+        self.longitude_dir= None;
+        self.waypoint_id= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.latitude),
+            1 => Some(&mut self.latitude_dir),
+            2 => Some(&mut self.longitude),
+            3 => Some(&mut self.longitude_dir),
+            4 => Some(&mut self.waypoint_id),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.latitude),
+            1 => Some(&self.latitude_dir),
+            2 => Some(&self.longitude),
+            3 => Some(&self.longitude_dir),
+            4 => Some(&self.waypoint_id),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((4, 2)),
+            2 => Some((5, 2)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        5
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("WPL".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::WPL
+    }
+}
+
+/// Routes
+/// Ex: $GPRTE,2,1,c,0,PBRCPK,PBRTO,PTELGR,PPLAND,PYAMBU,PPFAIR,PWARRN,PMORTL,PLISMR*73
+#[derive(Debug)]
+pub struct NmeaRteMessage { 
+    pub total_messages: Option<u32>,             // Total number of messages being transmitted
+    pub message_number: Option<u32>,             // Message number, starting from 1
+    pub route_type: Option<RouteType>,             // c-complete route; w-working route
+    pub route_id: Option<String>,             // Route identifier
+    pub waypoints: Vec<String>,             // Waypoint IDs
+    
+}
+
+impl NmeaRteMessage {
+    pub fn new() -> NmeaRteMessage {
+        NmeaRteMessage { 
+            total_messages: None,
+            message_number: None,
+            route_type: None,
+            route_id: None,
+            waypoints: Vec::new(),
+            
+        }
+    }
+}
+
+impl Default for NmeaRteMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaRteMessage {
+    fn clear(&mut self) { 
+        self.total_messages= None;
+        self.message_number= None;
+        self.route_type= None;
+        self.route_id= None;
+        self.waypoints= Vec::new();
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.total_messages),
+            1 => Some(&mut self.message_number),
+            2 => Some(&mut self.route_type),
+            3 => Some(&mut self.route_id),
+            _ => Some(&mut self.waypoints),
+            
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.total_messages),
+            1 => Some(&self.message_number),
+            2 => Some(&self.route_type),
+            3 => Some(&self.route_id),
+            _ => Some(&self.waypoints),
+            
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        5
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("RTE".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::RTE
+    }
+}
+
+/// Waypoint Arrival Alarm
+/// Ex: $GPAAM,A,A,0.10,N,WPTNME*32
+#[derive(Debug)]
+pub struct NmeaAamMessage { 
+    pub arrival_circle: Option<DataStatus>,             // A-arrival circle entered; V-not entered
+    pub perpendicular_passed: Option<DataStatus>,             // A-perpendicular passed at waypoint; V-not passed
+    pub radius: Option<f64>,             // Arrival circle radius
+    pub radius_unit: Option<DistanceUnit>,             // N-nautical miles
+    pub waypoint_id: Option<String>,             // Waypoint ID
+    
+}
+
+impl NmeaAamMessage {
+    pub fn new() -> NmeaAamMessage {
+        NmeaAamMessage { 
+            arrival_circle: None,
+            perpendicular_passed: None,
+            radius: None,
+            radius_unit: None,
+            waypoint_id: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaAamMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaAamMessage {
+    fn clear(&mut self) { 
+        self.arrival_circle= None;
+        self.perpendicular_passed= None;
+        self.radius= None;
+        self.radius_unit= None;
+        self.waypoint_id= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.arrival_circle),
+            1 => Some(&mut self.perpendicular_passed),
+            2 => Some(&mut self.radius),
+            3 => Some(&mut self.radius_unit),
+            4 => Some(&mut self.waypoint_id),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.arrival_circle),
+            1 => Some(&self.perpendicular_passed),
+            2 => Some(&self.radius),
+            3 => Some(&self.radius_unit),
+            4 => Some(&self.waypoint_id),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            2 => Some((0, 2)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        5
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("AAM".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::AAM
+    }
+}
+
+/// Tracked Target Message
+/// Ex: $RATTM,11,0.78,355.4,T,2.7,190.7,T,0.49,10.9,N,TGT11,T,,160511.00,A*75
+#[derive(Debug)]
+pub struct NmeaTtmMessage { 
+    pub target_number: Option<u32>,             // Target number, 00-999
+    pub distance: Option<f64>,             // Target distance from own ship
+    pub bearing: Option<f64>,             // Bearing from own ship, degrees
+    pub bearing_ref: Option<BearingReference>,             // T-true; R-relative
+    pub speed: Option<f64>,             // Target speed
+    pub course: Option<f64>,             // Target course, degrees
+    pub course_ref: Option<BearingReference>,             // T-true; R-relative
+    pub cpa: Option<f64>,             // Distance of closest point of approach
+    pub tcpa: Option<f64>,             // Time to CPA, minutes. Negative - increasing
+    pub unit: Option<DistanceUnit>,             // Units of distances and speeds: K-km, km/h; N-nautical miles, knots; S-statute miles, mph
+    pub name: Option<String>,             // Target name
+    pub status: Option<TargetStatus>,             // L-lost; Q-query (acquiring); T-tracking
+    pub reference: Option<TargetReference>,             // R-reference target; empty otherwise
+    pub utc: Option<String>,             // Time of data, hhmmss.ss
+    pub acquisition: Option<AcquisitionType>,             // A-automatic; M-manual; R-reported
+    
+}
+
+impl NmeaTtmMessage {
+    pub fn new() -> NmeaTtmMessage {
+        NmeaTtmMessage { 
+            target_number: None,
+            distance: None,
+            bearing: None,
+            bearing_ref: None,
+            speed: None,
+            course: None,
+            course_ref: None,
+            cpa: None,
+            tcpa: None,
+            unit: None,
+            name: None,
+            status: None,
+            reference: None,
+            utc: None,
+            acquisition: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaTtmMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaTtmMessage {
+    fn clear(&mut self) { 
+        self.target_number= None;
+        self.distance= None;
+        self.bearing= None;
+        self.bearing_ref= None;
+        self.speed= None;
+        self.course= None;
+        self.course_ref= None;
+        self.cpa= None;
+        self.tcpa= None;
+        self.unit= None;
+        self.name= None;
+        self.status= None;
+        self.reference= None;
+        self.utc= None;
+        self.acquisition= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.target_number),
+            1 => Some(&mut self.distance),
+            2 => Some(&mut self.bearing),
+            3 => Some(&mut self.bearing_ref),
+            4 => Some(&mut self.speed),
+            5 => Some(&mut self.course),
+            6 => Some(&mut self.course_ref),
+            7 => Some(&mut self.cpa),
+            8 => Some(&mut self.tcpa),
+            9 => Some(&mut self.unit),
+            10 => Some(&mut self.name),
+            11 => Some(&mut self.status),
+            12 => Some(&mut self.reference),
+            13 => Some(&mut self.utc),
+            14 => Some(&mut self.acquisition),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.target_number),
+            1 => Some(&self.distance),
+            2 => Some(&self.bearing),
+            3 => Some(&self.bearing_ref),
+            4 => Some(&self.speed),
+            5 => Some(&self.course),
+            6 => Some(&self.course_ref),
+            7 => Some(&self.cpa),
+            8 => Some(&self.tcpa),
+            9 => Some(&self.unit),
+            10 => Some(&self.name),
+            11 => Some(&self.status),
+            12 => Some(&self.reference),
+            13 => Some(&self.utc),
+            14 => Some(&self.acquisition),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((2, 0)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        15
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("TTM".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::TTM
+    }
+}
+
+/// Target Latitude and Longitude
+/// Ex: $RATLL,01,5603.370,N,00948.680,E,TGT01,161229.00,T,*6D
+#[derive(Debug)]
+pub struct NmeaTllMessage { 
+    pub target_number: Option<u32>,             // Target number, 00-999
+    pub latitude: Option<f64>,             // ddmm.mm
+    pub latitude_dir: Option<u8>,             // N/S
+    pub longitude: Option<f64>,             // dddmm.mm
+    pub longitude_dir: Option<u8>,             // E/W
+    pub name: Option<String>,             // Target name
+    pub utc: Option<String>,             // Time of data, hhmmss.ss
+    pub status: Option<TargetStatus>,             // L-lost; Q-query (acquiring); T-tracking
+    pub reference: Option<TargetReference>,             // R-reference target; empty otherwise
+    
+}
+
+impl NmeaTllMessage {
+    pub fn new() -> NmeaTllMessage {
+        NmeaTllMessage { 
+            target_number: None,
+            latitude: None,
+            latitude_dir: None,
+            longitude: None,
+            longitude_dir: None,
+            name: None,
+            utc: None,
+            status: None,
+            reference: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaTllMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaTllMessage {
+    fn clear(&mut self) { 
+        self.target_number= None;
+        self.latitude= None;
+        self.latitude_dir= None;
+        self.longitude= None;
+        self.longitude_dir= None;
+        self.name= None;
+        self.utc= None;
+        self.status= None;
+        self.reference= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.target_number),
+            1 => Some(&mut self.latitude),
+            2 => Some(&mut self.latitude_dir),
+            3 => Some(&mut self.longitude),
+            4 => Some(&mut self.longitude_dir),
+            5 => Some(&mut self.name),
+            6 => Some(&mut self.utc),
+            7 => Some(&mut self.status),
+            8 => Some(&mut self.reference),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.target_number),
+            1 => Some(&self.latitude),
+            2 => Some(&self.latitude_dir),
+            3 => Some(&self.longitude),
+            4 => Some(&self.longitude_dir),
+            5 => Some(&self.name),
+            6 => Some(&self.utc),
+            7 => Some(&self.status),
+            8 => Some(&self.reference),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((2, 0)),
+            1 => Some((4, 3)),
+            3 => Some((5, 3)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        9
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("TLL".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::TLL
+    }
+}
+
+/// Own Ship Data
+/// Ex: $RAOSD,35.1,A,36.0,P,10.2,P,15.3,0.0,N*40
+#[derive(Debug)]
+pub struct NmeaOsdMessage { 
+    pub heading: Option<f64>,             // Heading, degrees True
+    pub heading_status: Option<DataStatus>,             // A-valid; V-invalid
+    pub course: Option<f64>,             // Vessel course, degrees True
+    pub course_ref: Option<SpeedReference>,             // B-bottom track; M-manual; W-water; R-radar tracking; P-positioning system
+    pub speed: Option<f64>,             // Vessel speed
+    pub speed_ref: Option<SpeedReference>,             // B-bottom track; M-manual; W-water; R-radar tracking; P-positioning system
+    pub set: Option<f64>,             // Vessel set, degrees True
+    pub drift: Option<f64>,             // Vessel drift (speed)
+    pub speed_unit: Option<SpeedUnit>,             // K-km/h; N-knots; S-mph
+    
+}
+
+impl NmeaOsdMessage {
+    pub fn new() -> NmeaOsdMessage {
+        NmeaOsdMessage { 
+            heading: None,
+            heading_status: None,
+            course: None,
+            course_ref: None,
+            speed: None,
+            speed_ref: None,
+            set: None,
+            drift: None,
+            speed_unit: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaOsdMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaOsdMessage {
+    fn clear(&mut self) { 
+        self.heading= None;
+        self.heading_status= None;
+        self.course= None;
+        self.course_ref= None;
+        self.speed= None;
+        self.speed_ref= None;
+        self.set= None;
+        self.drift= None;
+        self.speed_unit= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.heading),
+            1 => Some(&mut self.heading_status),
+            2 => Some(&mut self.course),
+            3 => Some(&mut self.course_ref),
+            4 => Some(&mut self.speed),
+            5 => Some(&mut self.speed_ref),
+            6 => Some(&mut self.set),
+            7 => Some(&mut self.drift),
+            8 => Some(&mut self.speed_unit),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.heading),
+            1 => Some(&self.heading_status),
+            2 => Some(&self.course),
+            3 => Some(&self.course_ref),
+            4 => Some(&self.speed),
+            5 => Some(&self.speed_ref),
+            6 => Some(&self.set),
+            7 => Some(&self.drift),
+            8 => Some(&self.speed_unit),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((0, 1)),
+            2 => Some((0, 1)),
+            4 => Some((0, 1)),
+            6 => Some((0, 1)),
+            7 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        9
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("OSD".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::OSD
+    }
+}
+
+/// Radar System Data
+/// Ex: $RARSD,0.00,0.00,2.50,005.0,0.00,0.00,4.50,355.0,1.1,82.3,6.0,N,N*6B
+#[derive(Debug)]
+pub struct NmeaRsdMessage { 
+    pub origin1_range: Option<f64>,             // Origin 1 range from own ship
+    pub origin1_bearing: Option<f64>,             // Origin 1 bearing, degrees from 0
+    pub vrm1: Option<f64>,             // Variable range marker 1, range
+    pub ebl1: Option<f64>,             // Bearing line 1, degrees from 0
+    pub origin2_range: Option<f64>,             // Origin 2 range from own ship
+    pub origin2_bearing: Option<f64>,             // Origin 2 bearing, degrees from 0
+    pub vrm2: Option<f64>,             // Variable range marker 2, range
+    pub ebl2: Option<f64>,             // Bearing line 2, degrees from 0
+    pub cursor_range: Option<f64>,             // Cursor range from own ship
+    pub cursor_bearing: Option<f64>,             // Cursor bearing, degrees clockwise from 0
+    pub range_scale: Option<f64>,             // Range scale in use
+    pub range_unit: Option<DistanceUnit>,             // K-km; N-nautical miles; S-statute miles
+    pub rotation: Option<DisplayRotation>,             // C-course up; H-head up; N-north up
+    
+}
+
+impl NmeaRsdMessage {
+    pub fn new() -> NmeaRsdMessage {
+        NmeaRsdMessage { 
+            origin1_range: None,
+            origin1_bearing: None,
+            vrm1: None,
+            ebl1: None,
+            origin2_range: None,
+            origin2_bearing: None,
+            vrm2: None,
+            ebl2: None,
+            cursor_range: None,
+            cursor_bearing: None,
+            range_scale: None,
+            range_unit: None,
+            rotation: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaRsdMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaRsdMessage {
+    fn clear(&mut self) { 
+        self.origin1_range= None;
+        self.origin1_bearing= None;
+        self.vrm1= None;
+        self.ebl1= None;
+        self.origin2_range= None;
+        self.origin2_bearing= None;
+        self.vrm2= None;
+        self.ebl2= None;
+        self.cursor_range= None;
+        self.cursor_bearing= None;
+        self.range_scale= None;
+        self.range_unit= None;
+        self.rotation= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.origin1_range),
+            1 => Some(&mut self.origin1_bearing),
+            2 => Some(&mut self.vrm1),
+            3 => Some(&mut self.ebl1),
+            4 => Some(&mut self.origin2_range),
+            5 => Some(&mut self.origin2_bearing),
+            6 => Some(&mut self.vrm2),
+            7 => Some(&mut self.ebl2),
+            8 => Some(&mut self.cursor_range),
+            9 => Some(&mut self.cursor_bearing),
+            10 => Some(&mut self.range_scale),
+            11 => Some(&mut self.range_unit),
+            12 => Some(&mut self.rotation),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.origin1_range),
+            1 => Some(&self.origin1_bearing),
+            2 => Some(&self.vrm1),
+            3 => Some(&self.ebl1),
+            4 => Some(&self.origin2_range),
+            5 => Some(&self.origin2_bearing),
+            6 => Some(&self.vrm2),
+            7 => Some(&self.ebl2),
+            8 => Some(&self.cursor_range),
+            9 => Some(&self.cursor_bearing),
+            10 => Some(&self.range_scale),
+            11 => Some(&self.range_unit),
+            12 => Some(&self.rotation),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((0, 2)),
+            1 => Some((0, 2)),
+            2 => Some((0, 2)),
+            3 => Some((3, 1)),
+            4 => Some((0, 2)),
+            5 => Some((0, 2)),
+            6 => Some((0, 2)),
+            7 => Some((3, 1)),
+            8 => Some((0, 1)),
+            9 => Some((0, 1)),
+            10 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        13
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("RSD".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::RSD
+    }
+}
+
+/// Set Alarm State
+/// Ex: $IIALR,020000,031,A,V,Bilge pump alarm1*4B
+#[derive(Debug)]
+pub struct NmeaAlrMessage { 
+    pub utc: Option<String>,             // Time of alarm condition change, hhmmss.ss
+    pub alarm_id: Option<u32>,             // Local alarm number (identifier), 000-999
+    pub condition: Option<AlarmCondition>,             // A-threshold exceeded; V-not exceeded
+    pub ack_state: Option<AckState>,             // A-acknowledged; V-unacknowledged
+    pub text: Option<String>,             // Alarm description text
+    
+}
+
+impl NmeaAlrMessage {
+    pub fn new() -> NmeaAlrMessage {
+        NmeaAlrMessage { 
+            utc: None,
+            alarm_id: None,
+            condition: None,
+            ack_state: None,
+            text: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaAlrMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaAlrMessage {
+    fn clear(&mut self) { 
+        self.utc= None;
+        self.alarm_id= None;
+        self.condition= None;
+        self.ack_state= None;
+        self.text= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.alarm_id),
+            2 => Some(&mut self.condition),
+            3 => Some(&mut self.ack_state),
+            4 => Some(&mut self.text),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.alarm_id),
+            2 => Some(&self.condition),
+            3 => Some(&self.ack_state),
+            4 => Some(&self.text),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            1 => Some((3, 0)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        5
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("ALR".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::ALR
+    }
+}
+
+/// Acknowledge Alarm
+/// Ex: $IIACK,031*57
+#[derive(Debug)]
+pub struct NmeaAckMessage { 
+    pub alarm_id: Option<u32>,             // Local alarm number (identifier) of ALR, 000-999
+    
+}
+
+impl NmeaAckMessage {
+    pub fn new() -> NmeaAckMessage {
+        NmeaAckMessage { 
+            alarm_id: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaAckMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaAckMessage {
+    fn clear(&mut self) { 
+        self.alarm_id= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.alarm_id),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.alarm_id),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((3, 0)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        1
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("ACK".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::ACK
+    }
+}
+
+/// Alert Sentence
+/// Ex: $HNALF,1,1,0,124304.50,A,W,A,,192,1,1,0,LOST TARGET*12
+#[derive(Debug)]
+pub struct NmeaAlfMessage { 
+    pub total_sentences: Option<u32>,             // Total number of sentences, 1-2
+    pub sentence_number: Option<u32>,             // Sentence number, 1-2
+    pub sequence_id: Option<u32>,             // Sequential message identifier, 0-9
+    pub utc: Option<String>,             // Time of last change, hhmmss.ss
+    pub category: Option<AlertCategory>,             // Alert category: A, B or C
+    pub priority: Option<AlertPriority>,             // E-emergency alarm; A-alarm; W-warning; C-caution
+    pub state: Option<AlertState>,             // V-active unacknowledged; S-active silenced; A-active acknowledged; O-active responsibility transferred; U-rectified unacknowledged; N-normal
+    pub manufacturer: Option<String>,             // Manufacturer mnemonic code, empty for standardized alerts
+    pub alert_id: Option<u32>,             // Alert identifier
+    pub instance: Option<u32>,             // Alert instance, 1-999999
+    pub revision: Option<u32>,             // Revision counter, 1-99
+    pub escalation: Option<u32>,             // Escalation counter, 0-9
+    pub text: Option<String>,             // Alert title (sentence 1) or description (sentence 2)
+    
+}
+
+impl NmeaAlfMessage {
+    pub fn new() -> NmeaAlfMessage {
+        NmeaAlfMessage { 
+            total_sentences: None,
+            sentence_number: None,
+            sequence_id: None,
+            utc: None,
+            category: None,
+            priority: None,
+            state: None,
+            manufacturer: None,
+            alert_id: None,
+            instance: None,
+            revision: None,
+            escalation: None,
+            text: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaAlfMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaAlfMessage {
+    fn clear(&mut self) { 
+        self.total_sentences= None;
+        self.sentence_number= None;
+        self.sequence_id= None;
+        self.utc= None;
+        self.category= None;
+        self.priority= None;
+        self.state= None;
+        self.manufacturer= None;
+        self.alert_id= None;
+        self.instance= None;
+        self.revision= None;
+        self.escalation= None;
+        self.text= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.total_sentences),
+            1 => Some(&mut self.sentence_number),
+            2 => Some(&mut self.sequence_id),
+            3 => Some(&mut self.utc),
+            4 => Some(&mut self.category),
+            5 => Some(&mut self.priority),
+            6 => Some(&mut self.state),
+            7 => Some(&mut self.manufacturer),
+            8 => Some(&mut self.alert_id),
+            9 => Some(&mut self.instance),
+            10 => Some(&mut self.revision),
+            11 => Some(&mut self.escalation),
+            12 => Some(&mut self.text),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.total_sentences),
+            1 => Some(&self.sentence_number),
+            2 => Some(&self.sequence_id),
+            3 => Some(&self.utc),
+            4 => Some(&self.category),
+            5 => Some(&self.priority),
+            6 => Some(&self.state),
+            7 => Some(&self.manufacturer),
+            8 => Some(&self.alert_id),
+            9 => Some(&self.instance),
+            10 => Some(&self.revision),
+            11 => Some(&self.escalation),
+            12 => Some(&self.text),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        13
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("ALF".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::ALF
+    }
+}
+
+/// Cyclic Alert List
+/// Ex: $HNALC,01,01,00,02,,3015,1,2,FEC,192,1,1*34
+#[derive(Debug)]
+pub struct NmeaAlcMessage { 
+    pub total_sentences: Option<u32>,             // Total number of sentences
+    pub sentence_number: Option<u32>,             // Sentence number
+    pub sequence_id: Option<u32>,             // Sequential message identifier, 0-9
+    pub entries_count: Option<u32>,             // Number of alert entries in this sentence
+    pub entries: Vec<String>,             // Alert entries: manufacturer, alert id, instance, revision
+    
+}
+
+impl NmeaAlcMessage {
+    pub fn new() -> NmeaAlcMessage {
+        NmeaAlcMessage { 
+            total_sentences: None,
+            sentence_number: None,
+            sequence_id: None,
+            entries_count: None,
+            entries: Vec::new(),
+            
+        }
+    }
+}
+
+impl Default for NmeaAlcMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaAlcMessage {
+    fn clear(&mut self) { 
+        self.total_sentences= None;
+        self.sentence_number= None;
+        self.sequence_id= None;
+        self.entries_count= None;
+        self.entries= Vec::new();
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.total_sentences),
+            1 => Some(&mut self.sentence_number),
+            2 => Some(&mut self.sequence_id),
+            3 => Some(&mut self.entries_count),
+            _ => Some(&mut self.entries),
+            
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.total_sentences),
+            1 => Some(&self.sentence_number),
+            2 => Some(&self.sequence_id),
+            3 => Some(&self.entries_count),
+            _ => Some(&self.entries),
+            
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((2, 0)),
+            1 => Some((2, 0)),
+            2 => Some((2, 0)),
+            3 => Some((2, 0)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        5
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("ALC".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::ALC
+    }
+}
+
+/// Alert Command
+/// Ex: $VRACN,124305.00,,192,1,A,C*6E
+#[derive(Debug)]
+pub struct NmeaAcnMessage { 
+    pub utc: Option<String>,             // Time, hhmmss.ss
+    pub manufacturer: Option<String>,             // Manufacturer mnemonic code
+    pub alert_id: Option<u32>,             // Alert identifier
+    pub instance: Option<u32>,             // Alert instance
+    pub command: Option<AlertCommand>,             // A-acknowledge; Q-request/repeat information; O-responsibility transfer; S-silence
+    pub status: Option<u8>,             // Sentence status flag, always C (command)
+    
+}
+
+impl NmeaAcnMessage {
+    pub fn new() -> NmeaAcnMessage {
+        NmeaAcnMessage { 
+            utc: None,
+            manufacturer: None,
+            alert_id: None,
+            instance: None,
+            command: None,
+            status: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaAcnMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaAcnMessage {
+    fn clear(&mut self) { 
+        self.utc= None;
+        self.manufacturer= None;
+        self.alert_id= None;
+        self.instance= None;
+        self.command= None;
+        self.status= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.manufacturer),
+            2 => Some(&mut self.alert_id),
+            3 => Some(&mut self.instance),
+            4 => Some(&mut self.command),
+            5 => Some(&mut self.status),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.manufacturer),
+            2 => Some(&self.alert_id),
+            3 => Some(&self.instance),
+            4 => Some(&self.command),
+            5 => Some(&self.status),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        6
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("ACN".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::ACN
+    }
+}
+
+/// Alert Command Refused
+/// Ex: $HNARC,124305.00,,192,1,S*0D
+#[derive(Debug)]
+pub struct NmeaArcMessage { 
+    pub utc: Option<String>,             // Time, hhmmss.ss
+    pub manufacturer: Option<String>,             // Manufacturer mnemonic code
+    pub alert_id: Option<u32>,             // Alert identifier
+    pub instance: Option<u32>,             // Alert instance
+    pub command: Option<AlertCommand>,             // Refused command
+    
+}
+
+impl NmeaArcMessage {
+    pub fn new() -> NmeaArcMessage {
+        NmeaArcMessage { 
+            utc: None,
+            manufacturer: None,
+            alert_id: None,
+            instance: None,
+            command: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaArcMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaArcMessage {
+    fn clear(&mut self) { 
+        self.utc= None;
+        self.manufacturer= None;
+        self.alert_id= None;
+        self.instance= None;
+        self.command= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.manufacturer),
+            2 => Some(&mut self.alert_id),
+            3 => Some(&mut self.instance),
+            4 => Some(&mut self.command),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.manufacturer),
+            2 => Some(&self.alert_id),
+            3 => Some(&self.instance),
+            4 => Some(&self.command),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        5
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("ARC".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::ARC
+    }
+}
+
+/// Heartbeat Supervision
+/// Ex: $HNHBT,60,A,3*00
+#[derive(Debug)]
+pub struct NmeaHbtMessage { 
+    pub interval: Option<f64>,             // Configured repeat interval, seconds
+    pub status: Option<DataStatus>,             // Equipment status: A-normal; V-system fail
+    pub sequence_id: Option<u32>,             // Sequential sentence identifier, 0-9
+    
+}
+
+impl NmeaHbtMessage {
+    pub fn new() -> NmeaHbtMessage {
+        NmeaHbtMessage { 
+            interval: None,
+            status: None,
+            sequence_id: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaHbtMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaHbtMessage {
+    fn clear(&mut self) { 
+        self.interval= None;
+        self.status= None;
+        self.sequence_id= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.interval),
+            1 => Some(&mut self.status),
+            2 => Some(&mut self.sequence_id),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.interval),
+            1 => Some(&self.status),
+            2 => Some(&self.sequence_id),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        3
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("HBT".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::HBT
+    }
+}
+
+/// Datum Reference
+/// Ex: $GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F
+#[derive(Debug)]
+pub struct NmeaDtmMessage { 
+    pub datum: Option<Datum>,             // Local datum: W84, W72, S85, P90, 999-user defined
+    pub subdivision: Option<String>,             // Local datum subdivision code
+    pub latitude_offset: Option<f64>,             // Latitude offset, minutes
+    pub latitude_offset_dir: Option<u8>,             // N/S
+    pub longitude_offset: Option<f64>,             // Longitude offset, minutes
+    pub longitude_offset_dir: Option<u8>,             // E/W
+    pub altitude_offset: Option<f64>,             // Altitude offset, meters
+    pub reference_datum: Option<Datum>,             // Reference datum: W84, W72, S85, P90
+    
+}
+
+impl NmeaDtmMessage {
+    pub fn new() -> NmeaDtmMessage {
+        NmeaDtmMessage { 
+            datum: None,
+            subdivision: None,
+            latitude_offset: None,
+            latitude_offset_dir: None,
+            longitude_offset: None,
+            longitude_offset_dir: None,
+            altitude_offset: None,
+            reference_datum: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaDtmMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaDtmMessage {
+    fn clear(&mut self) { 
+        self.datum= None;
+        self.subdivision= None;
+        self.latitude_offset= None;
+        self.latitude_offset_dir= None;
+        self.longitude_offset= None;
+        self.longitude_offset_dir= None;
+        self.altitude_offset= None;
+        self.reference_datum= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.datum),
+            1 => Some(&mut self.subdivision),
+            2 => Some(&mut self.latitude_offset),
+            3 => Some(&mut self.latitude_offset_dir),
+            4 => Some(&mut self.longitude_offset),
+            5 => Some(&mut self.longitude_offset_dir),
+            6 => Some(&mut self.altitude_offset),
+            7 => Some(&mut self.reference_datum),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.datum),
+            1 => Some(&self.subdivision),
+            2 => Some(&self.latitude_offset),
+            3 => Some(&self.latitude_offset_dir),
+            4 => Some(&self.longitude_offset),
+            5 => Some(&self.longitude_offset_dir),
+            6 => Some(&self.altitude_offset),
+            7 => Some(&self.reference_datum),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            2 => Some((0, 1)),
+            4 => Some((0, 1)),
+            6 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        8
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("DTM".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::DTM
+    }
+}
+
+/// Text Transmission
+/// Ex: $GPTXT,01,01,02,ANTSTATUS=OK*3B
+#[derive(Debug)]
+pub struct NmeaTxtMessage { 
+    pub total_messages: Option<u32>,             // Total number of sentences, 01-99
+    pub message_number: Option<u32>,             // Sentence number, 01-99
+    pub text_id: Option<u32>,             // Text identifier, 01-99
+    pub text: Option<String>,             // Text message, up to 61 chars. Reserved chars are escaped as ^hh
+    
+}
+
+impl NmeaTxtMessage {
+    pub fn new() -> NmeaTxtMessage {
+        NmeaTxtMessage { 
+            total_messages: None,
+            message_number: None,
+            text_id: None,
+            text: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaTxtMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaTxtMessage {
+    fn clear(&mut self) { 
+        self.total_messages= None;
+        self.message_number= None;
+        self.text_id= None;
+        self.text= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.total_messages),
+            1 => Some(&mut self.message_number),
+            2 => Some(&mut self.text_id),
+            3 => Some(&mut self.text),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.total_messages),
+            1 => Some(&self.message_number),
+            2 => Some(&self.text_id),
+            3 => Some(&self.text),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((2, 0)),
+            1 => Some((2, 0)),
+            2 => Some((2, 0)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        4
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("TXT".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::TXT
+    }
+}
+
+/// Rudder Sensor Angle
+/// Ex: $IIRSA,10.5,A,-3.2,A*58
+#[derive(Debug)]
+pub struct NmeaRsaMessage { 
+    pub starboard: Option<f64>,             // Starboard (or single) rudder sensor, degrees. Negative - bow turns to port
+    pub starboard_status: Option<DataStatus>,             // A-valid; V-invalid
+    pub port: Option<f64>,             // Port rudder sensor, degrees
+    pub port_status: Option<DataStatus>,             // A-valid; V-invalid
+    
+}
+
+impl NmeaRsaMessage {
+    pub fn new() -> NmeaRsaMessage {
+        NmeaRsaMessage { 
+            starboard: None,
+            starboard_status: None,
+            port: None,
+            port_status: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaRsaMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaRsaMessage {
+    fn clear(&mut self) { 
+        self.starboard= None;
+        self.starboard_status= None;
+        self.port= None;
+        self.port_status= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.starboard),
+            1 => Some(&mut self.starboard_status),
+            2 => Some(&mut self.port),
+            3 => Some(&mut self.port_status),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.starboard),
+            1 => Some(&self.starboard_status),
+            2 => Some(&self.port),
+            3 => Some(&self.port_status),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((0, 1)),
+            2 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        4
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("RSA".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::RSA
+    }
+}
+
+/// Rudder Order Status
+/// Ex: $AGROR,-12.0,A,,V,B*00
+#[derive(Debug)]
+pub struct NmeaRorMessage { 
+    pub starboard: Option<f64>,             // Starboard (or single) rudder order, degrees. Negative - bow turns to port
+    pub starboard_status: Option<DataStatus>,             // A-valid; V-invalid
+    pub port: Option<f64>,             // Port rudder order, degrees
+    pub port_status: Option<DataStatus>,             // A-valid; V-invalid
+    pub location: Option<OperatingLocation>,             // Command source location: B-bridge; P-port wing; S-starboard wing; C-bridge centre console; E-engine control room; W-wing
+    
+}
+
+impl NmeaRorMessage {
+    pub fn new() -> NmeaRorMessage {
+        NmeaRorMessage { 
+            starboard: None,
+            starboard_status: None,
+            port: None,
+            port_status: None,
+            location: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaRorMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaRorMessage {
+    fn clear(&mut self) { 
+        self.starboard= None;
+        self.starboard_status= None;
+        self.port= None;
+        self.port_status= None;
+        self.location= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.starboard),
+            1 => Some(&mut self.starboard_status),
+            2 => Some(&mut self.port),
+            3 => Some(&mut self.port_status),
+            4 => Some(&mut self.location),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.starboard),
+            1 => Some(&self.starboard_status),
+            2 => Some(&self.port),
+            3 => Some(&self.port_status),
+            4 => Some(&self.location),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((0, 1)),
+            2 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        5
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("ROR".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::ROR
+    }
+}
+
+/// Revolutions
+/// Ex: $IIRPM,E,1,2418.2,10.5,A*5F
+#[derive(Debug)]
+pub struct NmeaRpmMessage { 
+    pub source: Option<RpmSource>,             // S-shaft; E-engine
+    pub number: Option<u32>,             // Engine or shaft number: 0-single or centre; odd-starboard; even-port
+    pub speed: Option<f64>,             // Revolutions per minute. Negative - counter-clockwise
+    pub pitch: Option<f64>,             // Propeller pitch, % of max. Negative - astern
+    pub status: Option<DataStatus>,             // A-valid; V-invalid
+    
+}
+
+impl NmeaRpmMessage {
+    pub fn new() -> NmeaRpmMessage {
+        NmeaRpmMessage { 
+            source: None,
+            number: None,
+            speed: None,
+            pitch: None,
+            status: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaRpmMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaRpmMessage {
+    fn clear(&mut self) { 
+        self.source= None;
+        self.number= None;
+        self.speed= None;
+        self.pitch= None;
+        self.status= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.source),
+            1 => Some(&mut self.number),
+            2 => Some(&mut self.speed),
+            3 => Some(&mut self.pitch),
+            4 => Some(&mut self.status),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.source),
+            1 => Some(&self.number),
+            2 => Some(&self.speed),
+            3 => Some(&self.pitch),
+            4 => Some(&self.status),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        5
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("RPM".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::RPM
+    }
+}
+
+/// Propulsion Remote Control Status
+/// Ex: $IIPRC,45.0,A,80.0,P,12.5,D,B,2*75
+#[derive(Debug)]
+pub struct NmeaPrcMessage { 
+    pub lever: Option<f64>,             // Lever demand position, % (-100..100). Negative - astern
+    pub lever_status: Option<DataStatus>,             // A-valid; V-invalid
+    pub rpm_demand: Option<f64>,             // RPM demand value
+    pub rpm_mode: Option<DemandMode>,             // P-percent; R-revolutions per minute; V-invalid
+    pub pitch_demand: Option<f64>,             // Pitch demand value
+    pub pitch_mode: Option<PitchMode>,             // P-percent; D-degrees; V-invalid
+    pub location: Option<OperatingLocation>,             // Operating location: B-bridge; P-port wing; S-starboard wing; C-bridge centre console; E-engine control room; W-wing
+    pub number: Option<u32>,             // Engine or propeller shaft number: 0-single or centre; odd-starboard; even-port
+    
+}
+
+impl NmeaPrcMessage {
+    pub fn new() -> NmeaPrcMessage {
+        NmeaPrcMessage { 
+            lever: None,
+            lever_status: None,
+            rpm_demand: None,
+            rpm_mode: None,
+            pitch_demand: None,
+            pitch_mode: None,
+            location: None,
+            number: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPrcMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPrcMessage {
+    fn clear(&mut self) { 
+        self.lever= None;
+        self.lever_status= None;
+        self.rpm_demand= None;
+        self.rpm_mode= None;
+        self.pitch_demand= None;
+        self.pitch_mode= None;
+        self.location= None;
+        self.number= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.lever),
+            1 => Some(&mut self.lever_status),
+            2 => Some(&mut self.rpm_demand),
+            3 => Some(&mut self.rpm_mode),
+            4 => Some(&mut self.pitch_demand),
+            5 => Some(&mut self.pitch_mode),
+            6 => Some(&mut self.location),
+            7 => Some(&mut self.number),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.lever),
+            1 => Some(&self.lever_status),
+            2 => Some(&self.rpm_demand),
+            3 => Some(&self.rpm_mode),
+            4 => Some(&self.pitch_demand),
+            5 => Some(&self.pitch_mode),
+            6 => Some(&self.location),
+            7 => Some(&self.number),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((0, 1)),
+            2 => Some((0, 1)),
+            4 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        8
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PRC".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PRC
+    }
+}
+
+/// Thruster Control Data
+/// Ex: $IITRC,1,60.0,P,10.0,D,270.0,B,R*5C
+#[derive(Debug)]
+pub struct NmeaTrcMessage { 
+    pub number: Option<u32>,             // Thruster number: odd-bow; even-stern
+    pub rpm_demand: Option<f64>,             // RPM demand value
+    pub rpm_mode: Option<DemandMode>,             // P-percent; R-revolutions per minute; V-invalid
+    pub pitch_demand: Option<f64>,             // Pitch demand value
+    pub pitch_mode: Option<PitchMode>,             // P-percent; D-degrees; V-invalid
+    pub azimuth_demand: Option<f64>,             // Azimuth demand, degrees (0-360)
+    pub location: Option<OperatingLocation>,             // Operating location: B-bridge; P-port wing; S-starboard wing; C-bridge centre console; E-engine control room; W-wing
+    pub sentence_status: Option<SentenceStatus>,             // R-status report; C-command
+    
+}
+
+impl NmeaTrcMessage {
+    pub fn new() -> NmeaTrcMessage {
+        NmeaTrcMessage { 
+            number: None,
+            rpm_demand: None,
+            rpm_mode: None,
+            pitch_demand: None,
+            pitch_mode: None,
+            azimuth_demand: None,
+            location: None,
+            sentence_status: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaTrcMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaTrcMessage {
+    fn clear(&mut self) { 
+        self.number= None;
+        self.rpm_demand= None;
+        self.rpm_mode= None;
+        self.pitch_demand= None;
+        self.pitch_mode= None;
+        self.azimuth_demand= None;
+        self.location= None;
+        self.sentence_status= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.number),
+            1 => Some(&mut self.rpm_demand),
+            2 => Some(&mut self.rpm_mode),
+            3 => Some(&mut self.pitch_demand),
+            4 => Some(&mut self.pitch_mode),
+            5 => Some(&mut self.azimuth_demand),
+            6 => Some(&mut self.location),
+            7 => Some(&mut self.sentence_status),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.number),
+            1 => Some(&self.rpm_demand),
+            2 => Some(&self.rpm_mode),
+            3 => Some(&self.pitch_demand),
+            4 => Some(&self.pitch_mode),
+            5 => Some(&self.azimuth_demand),
+            6 => Some(&self.location),
+            7 => Some(&self.sentence_status),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            1 => Some((0, 1)),
+            3 => Some((0, 1)),
+            5 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        8
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("TRC".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::TRC
+    }
+}
+
+/// Engine Telegraph Operation Status
+/// Ex: $IIETL,083015.00,O,03,20,B,1*41
+#[derive(Debug)]
+pub struct NmeaEtlMessage { 
+    pub utc: Option<String>,             // Event time, hhmmss.ss
+    pub message_type: Option<TelegraphMessageType>,             // O-order; A-answer-back
+    pub position: Option<TelegraphPosition>,             // Engine telegraph position: 00-stop; 01..05-ahead dead slow..navigation full; 11..15-astern dead slow..crash astern
+    pub sub_position: Option<SubTelegraph>,             // Sub telegraph position: 20-stand-by; 30-full away; 40-finished with engine
+    pub location: Option<OperatingLocation>,             // Operating location: B-bridge; P-port wing; S-starboard wing; C-bridge centre console; E-engine control room; W-wing
+    pub number: Option<u32>,             // Engine or propeller shaft number: 0-single or centre; odd-starboard; even-port
+    
+}
+
+impl NmeaEtlMessage {
+    pub fn new() -> NmeaEtlMessage {
+        NmeaEtlMessage { 
+            utc: None,
+            message_type: None,
+            position: None,
+            sub_position: None,
+            location: None,
+            number: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaEtlMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaEtlMessage {
+    fn clear(&mut self) { 
+        self.utc= None;
+        self.message_type= None;
+        self.position= None;
+        self.sub_position= None;
+        self.location= None;
+        self.number= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.message_type),
+            2 => Some(&mut self.position),
+            3 => Some(&mut self.sub_position),
+            4 => Some(&mut self.location),
+            5 => Some(&mut self.number),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.message_type),
+            2 => Some(&self.position),
+            3 => Some(&self.sub_position),
+            4 => Some(&self.location),
+            5 => Some(&self.number),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        6
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("ETL".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::ETL
+    }
+}
+
+/// u-blox Lat/Long Position Data
+/// Ex: $PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F
+#[derive(Debug)]
+pub struct NmeaPubx00Message { 
+    pub utc: Option<String>,             // UTC time, hhmmss.ss
+    pub latitude: Option<f64>,             // ddmm.mmmmm
+    pub latitude_dir: Option<u8>,             // N/S
+    pub longitude: Option<f64>,             // dddmm.mmmmm
+    pub longitude_dir: Option<u8>,             // E/W
+    pub altitude: Option<f64>,             // Altitude above user datum ellipsoid, meters
+    pub nav_status: Option<UbxNavStatus>,             // NF-no fix; DR-dead reckoning; G2/G3-standalone 2D/3D; D2/D3-differential 2D/3D; RK-combined GNSS and DR; TT-time only
+    pub h_acc: Option<f64>,             // Horizontal accuracy estimate, meters
+    pub v_acc: Option<f64>,             // Vertical accuracy estimate, meters
+    pub speed: Option<f64>,             // Speed over ground, km/h
+    pub course: Option<f64>,             // Course over ground, degrees
+    pub vertical_velocity: Option<f64>,             // Vertical velocity, m/s. Positive - downwards
+    pub diff_age: Option<f64>,             // Age of differential corrections, seconds
+    pub hdop: Option<f64>,             // Horizontal dilution of precision
+    pub vdop: Option<f64>,             // Vertical dilution of precision
+    pub tdop: Option<f64>,             // Time dilution of precision
+    pub satellites: Option<u32>,             // Number of satellites used in navigation solution
+    pub reserved: Option<String>,             // Reserved, always 0
+    pub dr_used: Option<u32>,             // Dead reckoning used: 0-no; 1-yes
+    
+}
+
+impl NmeaPubx00Message {
+    pub fn new() -> NmeaPubx00Message {
+        NmeaPubx00Message { 
+            utc: None,
+            latitude: None,
+            latitude_dir: None,
+            longitude: None,
+            longitude_dir: None,
+            altitude: None,
+            nav_status: None,
+            h_acc: None,
+            v_acc: None,
+            speed: None,
+            course: None,
+            vertical_velocity: None,
+            diff_age: None,
+            hdop: None,
+            vdop: None,
+            tdop: None,
+            satellites: None,
+            reserved: None,
+            dr_used: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPubx00Message {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPubx00Message {
+    fn clear(&mut self) { 
+        self.utc= None;
+        self.latitude= None;
+        self.latitude_dir= None;
+        self.longitude= None;
+        self.longitude_dir= None;
+        self.altitude= None;
+        self.nav_status= None;
+        self.h_acc= None;
+        self.v_acc= None;
+        self.speed= None;
+        self.course= None;
+        self.vertical_velocity= None;
+        self.diff_age= None;
+        self.hdop= None;
+        self.vdop= None;
+        self.tdop= None;
+        self.satellites= None;
+        self.reserved= None;
+        self.dr_used= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.latitude),
+            2 => Some(&mut self.latitude_dir),
+            3 => Some(&mut self.longitude),
+            4 => Some(&mut self.longitude_dir),
+            5 => Some(&mut self.altitude),
+            6 => Some(&mut self.nav_status),
+            7 => Some(&mut self.h_acc),
+            8 => Some(&mut self.v_acc),
+            9 => Some(&mut self.speed),
+            10 => Some(&mut self.course),
+            11 => Some(&mut self.vertical_velocity),
+            12 => Some(&mut self.diff_age),
+            13 => Some(&mut self.hdop),
+            14 => Some(&mut self.vdop),
+            15 => Some(&mut self.tdop),
+            16 => Some(&mut self.satellites),
+            17 => Some(&mut self.reserved),
+            18 => Some(&mut self.dr_used),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.latitude),
+            2 => Some(&self.latitude_dir),
+            3 => Some(&self.longitude),
+            4 => Some(&self.longitude_dir),
+            5 => Some(&self.altitude),
+            6 => Some(&self.nav_status),
+            7 => Some(&self.h_acc),
+            8 => Some(&self.v_acc),
+            9 => Some(&self.speed),
+            10 => Some(&self.course),
+            11 => Some(&self.vertical_velocity),
+            12 => Some(&self.diff_age),
+            13 => Some(&self.hdop),
+            14 => Some(&self.vdop),
+            15 => Some(&self.tdop),
+            16 => Some(&self.satellites),
+            17 => Some(&self.reserved),
+            18 => Some(&self.dr_used),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            1 => Some((4, 6)),
+            3 => Some((5, 6)),
+            7 => Some((0, 1)),
+            8 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        19
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PUBX".as_bytes())
+    }
+
+    #[inline]
+    fn sub_id(&self) -> Option<&'static str> {
+        Some("00")
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PUBX00
+    }
+}
+
+/// u-blox Satellite Status
+/// Ex: $PUBX,03,04,23,-,,,45,010,29,-,,,46,013,08,U,067,31,42,025,10,e,195,33,46,026*22
+#[derive(Debug)]
+pub struct NmeaPubx03Message { 
+    pub satellites_count: Option<u32>,             // Number of satellites tracked
+    pub satellites: Vec<String>,             // Satellites: id, status (U-used; e-ephemeris available; --not used), azimuth, elevation, C/N0, lock time
+    
+}
+
+impl NmeaPubx03Message {
+    pub fn new() -> NmeaPubx03Message {
+        NmeaPubx03Message { 
+            satellites_count: None,
+            satellites: Vec::new(),
+            
+        }
+    }
+}
+
+impl Default for NmeaPubx03Message {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPubx03Message {
+    fn clear(&mut self) { 
+        self.satellites_count= None;
+        self.satellites= Vec::new();
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.satellites_count),
+            _ => Some(&mut self.satellites),
+            
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.satellites_count),
+            _ => Some(&self.satellites),
+            
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((2, 0)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        2
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PUBX".as_bytes())
+    }
+
+    #[inline]
+    fn sub_id(&self) -> Option<&'static str> {
+        Some("03")
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PUBX03
+    }
+}
+
+/// u-blox Time of Day and Clock Information
+/// Ex: $PUBX,04,073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43,*5D
+#[derive(Debug)]
+pub struct NmeaPubx04Message { 
+    pub utc: Option<String>,             // UTC time, hhmmss.ss
+    pub date: Option<String>,             // UTC date, ddmmyy
+    pub utc_tow: Option<f64>,             // UTC time of week, seconds
+    pub utc_week: Option<u32>,             // UTC week number, continues beyond 1023
+    pub leap_seconds: Option<String>,             // Leap seconds. Ends with D if firmware default is used
+    pub clock_bias: Option<f64>,             // Receiver clock bias, ns
+    pub clock_drift: Option<f64>,             // Receiver clock drift, ns/s
+    pub tp_granularity: Option<u32>,             // Time pulse granularity, ns
+    pub reserved: Option<String>,             // Reserved
+    
+}
+
+impl NmeaPubx04Message {
+    pub fn new() -> NmeaPubx04Message {
+        NmeaPubx04Message { 
+            utc: None,
+            date: None,
+            utc_tow: None,
+            utc_week: None,
+            leap_seconds: None,
+            clock_bias: None,
+            clock_drift: None,
+            tp_granularity: None,
+            reserved: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPubx04Message {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPubx04Message {
+    fn clear(&mut self) { 
+        self.utc= None;
+        self.date= None;
+        self.utc_tow= None;
+        self.utc_week= None;
+        self.leap_seconds= None;
+        self.clock_bias= None;
+        self.clock_drift= None;
+        self.tp_granularity= None;
+        self.reserved= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.utc),
+            1 => Some(&mut self.date),
+            2 => Some(&mut self.utc_tow),
+            3 => Some(&mut self.utc_week),
+            4 => Some(&mut self.leap_seconds),
+            5 => Some(&mut self.clock_bias),
+            6 => Some(&mut self.clock_drift),
+            7 => Some(&mut self.tp_granularity),
+            8 => Some(&mut self.reserved),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.utc),
+            1 => Some(&self.date),
+            2 => Some(&self.utc_tow),
+            3 => Some(&self.utc_week),
+            4 => Some(&self.leap_seconds),
+            5 => Some(&self.clock_bias),
+            6 => Some(&self.clock_drift),
+            7 => Some(&self.tp_granularity),
+            8 => Some(&self.reserved),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            2 => Some((0, 2)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        9
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PUBX".as_bytes())
+    }
+
+    #[inline]
+    fn sub_id(&self) -> Option<&'static str> {
+        Some("04")
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PUBX04
+    }
+}
+
+/// u-blox Set NMEA Message Output Rate
+/// Ex: $PUBX,40,GLL,1,0,0,0,0,0*5D
+#[derive(Debug)]
+pub struct NmeaPubx40Message { 
+    pub msg_id: Option<String>,             // NMEA message ID, e.g. GLL
+    pub rate_ddc: Option<u32>,             // Output rate on DDC (I2C), per navigation solution. 0 - disabled
+    pub rate_usart1: Option<u32>,             // Output rate on USART 1
+    pub rate_usart2: Option<u32>,             // Output rate on USART 2
+    pub rate_usb: Option<u32>,             // Output rate on USB
+    pub rate_spi: Option<u32>,             // Output rate on SPI
+    pub reserved: Option<u32>,             // Reserved, always 0
+    
+}
+
+impl NmeaPubx40Message {
+    pub fn new() -> NmeaPubx40Message {
+        NmeaPubx40Message { 
+            msg_id: None,
+            rate_ddc: None,
+            rate_usart1: None,
+            rate_usart2: None,
+            rate_usb: None,
+            rate_spi: None,
+            reserved: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPubx40Message {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPubx40Message {
+    fn clear(&mut self) { 
+        self.msg_id= None;
+        self.rate_ddc= None;
+        self.rate_usart1= None;
+        self.rate_usart2= None;
+        self.rate_usb= None;
+        self.rate_spi= None;
+        self.reserved= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.msg_id),
+            1 => Some(&mut self.rate_ddc),
+            2 => Some(&mut self.rate_usart1),
+            3 => Some(&mut self.rate_usart2),
+            4 => Some(&mut self.rate_usb),
+            5 => Some(&mut self.rate_spi),
+            6 => Some(&mut self.reserved),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.msg_id),
+            1 => Some(&self.rate_ddc),
+            2 => Some(&self.rate_usart1),
+            3 => Some(&self.rate_usart2),
+            4 => Some(&self.rate_usb),
+            5 => Some(&self.rate_spi),
+            6 => Some(&self.reserved),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        7
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PUBX".as_bytes())
+    }
+
+    #[inline]
+    fn sub_id(&self) -> Option<&'static str> {
+        Some("40")
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PUBX40
+    }
+}
+
+/// u-blox Set Protocols and Baudrate
+/// Ex: $PUBX,41,1,0007,0003,19200,0*25
+#[derive(Debug)]
+pub struct NmeaPubx41Message { 
+    pub port: Option<UbxPort>,             // Port: 0-DDC; 1-USART 1; 2-USART 2; 3-USB; 4-SPI
+    pub in_proto: Option<UbxProtocols>,             // Input protocols mask, hex: 1-UBX; 2-NMEA; 4-RTCM; 20-RTCM3
+    pub out_proto: Option<UbxProtocols>,             // Output protocols mask, hex
+    pub baudrate: Option<u32>,             // Baudrate, bits/s
+    pub autobauding: Option<u32>,             // Autobauding: 0-disabled; 1-enabled
+    
+}
+
+impl NmeaPubx41Message {
+    pub fn new() -> NmeaPubx41Message {
+        NmeaPubx41Message { 
+            port: None,
+            in_proto: None,
+            out_proto: None,
+            baudrate: None,
+            autobauding: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPubx41Message {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPubx41Message {
+    fn clear(&mut self) { 
+        self.port= None;
+        self.in_proto= None;
+        self.out_proto= None;
+        self.baudrate= None;
+        self.autobauding= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.port),
+            1 => Some(&mut self.in_proto),
+            2 => Some(&mut self.out_proto),
+            3 => Some(&mut self.baudrate),
+            4 => Some(&mut self.autobauding),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.port),
+            1 => Some(&self.in_proto),
+            2 => Some(&self.out_proto),
+            3 => Some(&self.baudrate),
+            4 => Some(&self.autobauding),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        5
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PUBX".as_bytes())
+    }
+
+    #[inline]
+    fn sub_id(&self) -> Option<&'static str> {
+        Some("41")
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PUBX41
+    }
+}
+
+/// Garmin Estimated Error Information
+/// Ex: $PGRME,15.0,M,45.0,M,25.0,M*1C
+#[derive(Debug)]
+pub struct NmeaPgrmeMessage { 
+    pub hpe: Option<f64>,             // Estimated horizontal position error
+    pub hpe_unit: Option<DistanceUnit>,             // M-meters
+    pub vpe: Option<f64>,             // Estimated vertical position error
+    pub vpe_unit: Option<DistanceUnit>,             // M-meters
+    pub epe: Option<f64>,             // Overall spherical equivalent position error
+    pub epe_unit: Option<DistanceUnit>,             // M-meters
+    
+}
+
+impl NmeaPgrmeMessage {
+    pub fn new() -> NmeaPgrmeMessage {
+        NmeaPgrmeMessage { 
+            hpe: None,
+            hpe_unit: None,
+            vpe: None,
+            vpe_unit: None,
+            epe: None,
+            epe_unit: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPgrmeMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPgrmeMessage {
+    fn clear(&mut self) { 
+        self.hpe= None;
+        self.hpe_unit= None;
+        self.vpe= None;
+        self.vpe_unit= None;
+        self.epe= None;
+        self.epe_unit= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.hpe),
+            1 => Some(&mut self.hpe_unit),
+            2 => Some(&mut self.vpe),
+            3 => Some(&mut self.vpe_unit),
+            4 => Some(&mut self.epe),
+            5 => Some(&mut self.epe_unit),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.hpe),
+            1 => Some(&self.hpe_unit),
+            2 => Some(&self.vpe),
+            3 => Some(&self.vpe_unit),
+            4 => Some(&self.epe),
+            5 => Some(&self.epe_unit),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            0 => Some((0, 1)),
+            2 => Some((0, 1)),
+            4 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        6
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PGRME".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PGRME
+    }
+}
+
+/// Garmin Altitude Information
+/// Ex: $PGRMZ,246,f,3*1B
+#[derive(Debug)]
+pub struct NmeaPgrmzMessage { 
+    pub altitude: Option<f64>,             // Altitude above mean sea level
+    pub altitude_unit: Option<DistanceUnit>,             // f-feet
+    pub fix_dimension: Option<GarminFixDimension>,             // 2-user altitude; 3-GPS altitude
+    
+}
+
+impl NmeaPgrmzMessage {
+    pub fn new() -> NmeaPgrmzMessage {
+        NmeaPgrmzMessage { 
+            altitude: None,
+            altitude_unit: None,
+            fix_dimension: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPgrmzMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPgrmzMessage {
+    fn clear(&mut self) { 
+        self.altitude= None;
+        self.altitude_unit= None;
+        self.fix_dimension= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.altitude),
+            1 => Some(&mut self.altitude_unit),
+            2 => Some(&mut self.fix_dimension),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.altitude),
+            1 => Some(&self.altitude_unit),
+            2 => Some(&self.fix_dimension),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        3
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PGRMZ".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PGRMZ
+    }
+}
+
+/// Garmin Map Datum
+/// Ex: $PGRMM,WGS 84*06
+#[derive(Debug)]
+pub struct NmeaPgrmmMessage { 
+    pub datum: Option<String>,             // Name of currently active datum
+    
+}
+
+impl NmeaPgrmmMessage {
+    pub fn new() -> NmeaPgrmmMessage {
+        NmeaPgrmmMessage { 
+            datum: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPgrmmMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPgrmmMessage {
+    fn clear(&mut self) { 
+        self.datum= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.datum),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.datum),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        1
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PGRMM".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PGRMM
+    }
+}
+
+/// Garmin Sensor Status Information
+/// Ex: $PGRMT,GPS 15L/15H VER 2.05,P,P,R,R,P,,23,R*64
+#[derive(Debug)]
+pub struct NmeaPgrmtMessage { 
+    pub version: Option<String>,             // Product, model and software version
+    pub rom_checksum: Option<PassFail>,             // ROM checksum test: P-pass; F-fail
+    pub receiver_failure: Option<PassFail>,             // Receiver failure discrete: P-pass; F-fail
+    pub stored_data: Option<RetainedLost>,             // Stored data: R-retained; L-lost
+    pub clock: Option<RetainedLost>,             // Real time clock: R-retained; L-lost
+    pub oscillator: Option<PassFail>,             // Oscillator drift discrete: P-pass; F-excessive drift detected
+    pub data_collection: Option<u8>,             // Data collection discrete: C-collecting; empty otherwise
+    pub temperature: Option<f64>,             // Board temperature, degrees C
+    pub configuration: Option<RetainedLost>,             // Board configuration data: R-retained; L-lost
+    
+}
+
+impl NmeaPgrmtMessage {
+    pub fn new() -> NmeaPgrmtMessage {
+        NmeaPgrmtMessage { 
+            version: None,
+            rom_checksum: None,
+            receiver_failure: None,
+            stored_data: None,
+            clock: None,
+            oscillator: None,
+            data_collection: None,
+            temperature: None,
+            configuration: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPgrmtMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPgrmtMessage {
+    fn clear(&mut self) { 
+        self.version= None;
+        self.rom_checksum= None;
+        self.receiver_failure= None;
+        self.stored_data= None;
+        self.clock= None;
+        self.oscillator= None;
+        self.data_collection= None;
+        self.temperature= None;
+        self.configuration= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.version),
+            1 => Some(&mut self.rom_checksum),
+            2 => Some(&mut self.receiver_failure),
+            3 => Some(&mut self.stored_data),
+            4 => Some(&mut self.clock),
+            5 => Some(&mut self.oscillator),
+            6 => Some(&mut self.data_collection),
+            7 => Some(&mut self.temperature),
+            8 => Some(&mut self.configuration),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.version),
+            1 => Some(&self.rom_checksum),
+            2 => Some(&self.receiver_failure),
+            3 => Some(&self.stored_data),
+            4 => Some(&self.clock),
+            5 => Some(&self.oscillator),
+            6 => Some(&self.data_collection),
+            7 => Some(&self.temperature),
+            8 => Some(&self.configuration),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        9
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PGRMT".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PGRMT
+    }
+}
+
+/// Garmin Sensor Configuration
+/// Ex: $PGRMC,A,218.8,100,6378137.000,298.257223563,0.0,0.0,0.0,A,3,1,1,4,30*72
+#[derive(Debug)]
+pub struct NmeaPgrmcMessage { 
+    pub fix_mode: Option<GarminFixMode>,             // A-automatic; 2-2D exclusively; 3-3D exclusively
+    pub altitude: Option<f64>,             // Altitude above mean sea level for 2D fixes, meters
+    pub datum_index: Option<u32>,             // Earth datum index. 100 - user defined
+    pub semi_major_axis: Option<f64>,             // User datum semi-major axis, meters
+    pub inverse_flattening: Option<f64>,             // User datum inverse flattening factor
+    pub delta_x: Option<f64>,             // User datum delta X, meters
+    pub delta_y: Option<f64>,             // User datum delta Y, meters
+    pub delta_z: Option<f64>,             // User datum delta Z, meters
+    pub diff_mode: Option<GarminDiffMode>,             // A-automatic; D-differential exclusively
+    pub baud_rate: Option<GarminBaudRate>,             // NMEA baud rate: 1-1200; 2-2400; 3-4800; 4-9600; 5-19200; 6-300; 7-600; 8-38400
+    pub velocity_filter: Option<u32>,             // Velocity filter: 0-no filter; 1-automatic; 2-255 - time constant, seconds
+    pub pps_mode: Option<u32>,             // PPS mode: 1-no PPS; 2-1 Hz
+    pub pps_length: Option<u32>,             // PPS pulse length: (n+1)*20 ms, 0-48
+    pub dr_time: Option<u32>,             // Dead reckoning valid time, seconds (1-30)
+    
+}
+
+impl NmeaPgrmcMessage {
+    pub fn new() -> NmeaPgrmcMessage {
+        NmeaPgrmcMessage { 
+            fix_mode: None,
+            altitude: None,
+            datum_index: None,
+            semi_major_axis: None,
+            inverse_flattening: None,
+            delta_x: None,
+            delta_y: None,
+            delta_z: None,
+            diff_mode: None,
+            baud_rate: None,
+            velocity_filter: None,
+            pps_mode: None,
+            pps_length: None,
+            dr_time: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPgrmcMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPgrmcMessage {
+    fn clear(&mut self) { 
+        self.fix_mode= None;
+        self.altitude= None;
+        self.datum_index= None;
+        self.semi_major_axis= None;
+        self.inverse_flattening= None;
+        self.delta_x= None;
+        self.delta_y= None;
+        self.delta_z= None;
+        self.diff_mode= None;
+        self.baud_rate= None;
+        self.velocity_filter= None;
+        self.pps_mode= None;
+        self.pps_length= None;
+        self.dr_time= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.fix_mode),
+            1 => Some(&mut self.altitude),
+            2 => Some(&mut self.datum_index),
+            3 => Some(&mut self.semi_major_axis),
+            4 => Some(&mut self.inverse_flattening),
+            5 => Some(&mut self.delta_x),
+            6 => Some(&mut self.delta_y),
+            7 => Some(&mut self.delta_z),
+            8 => Some(&mut self.diff_mode),
+            9 => Some(&mut self.baud_rate),
+            10 => Some(&mut self.velocity_filter),
+            11 => Some(&mut self.pps_mode),
+            12 => Some(&mut self.pps_length),
+            13 => Some(&mut self.dr_time),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.fix_mode),
+            1 => Some(&self.altitude),
+            2 => Some(&self.datum_index),
+            3 => Some(&self.semi_major_axis),
+            4 => Some(&self.inverse_flattening),
+            5 => Some(&self.delta_x),
+            6 => Some(&self.delta_y),
+            7 => Some(&self.delta_z),
+            8 => Some(&self.diff_mode),
+            9 => Some(&self.baud_rate),
+            10 => Some(&self.velocity_filter),
+            11 => Some(&self.pps_mode),
+            12 => Some(&self.pps_length),
+            13 => Some(&self.dr_time),
+            _ => None,
+        }
+    }
+
+
+    fn field_format(&self, idx: u8) -> Option<(usize, usize)> {
+        match idx {
+            3 => Some((0, 3)),
+            5 => Some((0, 1)),
+            6 => Some((0, 1)),
+            7 => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        14
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PGRMC".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PGRMC
+    }
+}
+
+/// Garmin Output Sentence Enable/Disable
+/// Ex: $PGRMO,GPGSV,0*22
+#[derive(Debug)]
+pub struct NmeaPgrmoMessage { 
+    pub sentence: Option<String>,             // Target sentence, e.g. GPGSV
+    pub mode: Option<GarminOutputMode>,             // 0-disable; 1-enable; 2-disable all; 3-enable all except GPALM; 4-factory default
+    
+}
+
+impl NmeaPgrmoMessage {
+    pub fn new() -> NmeaPgrmoMessage {
+        NmeaPgrmoMessage { 
+            sentence: None,
+            mode: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPgrmoMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPgrmoMessage {
+    fn clear(&mut self) { 
+        self.sentence= None;
+        self.mode= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.sentence),
+            1 => Some(&mut self.mode),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.sentence),
+            1 => Some(&self.mode),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        2
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PGRMO".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PGRMO
+    }
+}
+
+/// MediaTek Command Acknowledge
+/// Ex: $PMTK001,220,3*30
+#[derive(Debug)]
+pub struct NmeaPmtk001Message { 
+    pub command: Option<u32>,             // Acknowledged command number
+    pub flag: Option<PmtkAckFlag>,             // 0-invalid command; 1-unsupported command; 2-action failed; 3-action succeeded
+    
+}
+
+impl NmeaPmtk001Message {
+    pub fn new() -> NmeaPmtk001Message {
+        NmeaPmtk001Message { 
+            command: None,
+            flag: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPmtk001Message {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPmtk001Message {
+    fn clear(&mut self) { 
+        self.command= None;
+        self.flag= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.command),
+            1 => Some(&mut self.flag),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.command),
+            1 => Some(&self.flag),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        2
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PMTK001".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PMTK001
+    }
+}
+
+/// MediaTek Hot Restart
+/// Ex: $PMTK101*32
+#[derive(Debug)]
+pub struct NmeaPmtk101Message { 
+    
+}
+
+impl NmeaPmtk101Message {
+    pub fn new() -> NmeaPmtk101Message {
+        NmeaPmtk101Message { 
+            
+        }
+    }
+}
+
+impl Default for NmeaPmtk101Message {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPmtk101Message {
+    fn clear(&mut self) { 
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        0
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PMTK101".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PMTK101
+    }
+}
+
+/// MediaTek Cold Restart
+/// Ex: $PMTK103*30
+#[derive(Debug)]
+pub struct NmeaPmtk103Message { 
+    
+}
+
+impl NmeaPmtk103Message {
+    pub fn new() -> NmeaPmtk103Message {
+        NmeaPmtk103Message { 
+            
+        }
+    }
+}
+
+impl Default for NmeaPmtk103Message {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPmtk103Message {
+    fn clear(&mut self) { 
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        0
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PMTK103".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PMTK103
+    }
+}
+
+/// MediaTek Set Position Fix Interval
+/// Ex: $PMTK220,1000*1F
+#[derive(Debug)]
+pub struct NmeaPmtk220Message { 
+    pub interval: Option<u32>,             // Position fix interval, milliseconds (100-10000)
+    
+}
+
+impl NmeaPmtk220Message {
+    pub fn new() -> NmeaPmtk220Message {
+        NmeaPmtk220Message { 
+            interval: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPmtk220Message {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPmtk220Message {
+    fn clear(&mut self) { 
+        self.interval= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.interval),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.interval),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        1
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PMTK220".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PMTK220
+    }
+}
+
+/// MediaTek Set NMEA Output
+/// Ex: $PMTK314,0,1,0,1,1,5,0,0,0,0,0,0,0,0,0,0,0,0,0*2C
+#[derive(Debug)]
+pub struct NmeaPmtk314Message { 
+    pub rates: Vec<String>,             // Output rates of GLL, RMC, VTG, GGA, GSA, GSV, GRS, GST, reserved, ZDA, MCHN. Single -1 restores defaults
+    
+}
+
+impl NmeaPmtk314Message {
+    pub fn new() -> NmeaPmtk314Message {
+        NmeaPmtk314Message { 
+            rates: Vec::new(),
+            
+        }
+    }
+}
+
+impl Default for NmeaPmtk314Message {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPmtk314Message {
+    fn clear(&mut self) { 
+        self.rates= Vec::new();
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            _ => Some(&mut self.rates),
+            
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            _ => Some(&self.rates),
+            
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        1
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PMTK314".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PMTK314
+    }
+}
+
+/// AIS VHF Data-link Message
+/// Ex: !AIVDM,1,1,,B,15M67FC000G?ufbE`FepT@3n00Sa,0*5C
+#[derive(Debug)]
+pub struct NmeaVdmMessage { 
+    pub fragment_count: Option<u32>,             // Number of fragments of the accumulated message (1-9)
+    pub fragment_number: Option<u32>,             // Fragment number (1-9)
+    pub sequence_id: Option<u32>,             // Sequential message identifier of multi-fragment message (0-9)
+    pub channel: Option<String>,             // AIS channel: A or 1 - 161.975 MHz; B or 2 - 162.025 MHz
+    pub payload: Option<String>,             // Encapsulated 6-bit ASCII payload
+    pub fill_bits: Option<u32>,             // Number of fill bits added to the last 6-bit char (0-5)
+    
+}
+
+impl NmeaVdmMessage {
+    pub fn new() -> NmeaVdmMessage {
+        NmeaVdmMessage { 
+            fragment_count: None,
+            fragment_number: None,
+            sequence_id: None,
+            channel: None,
+            payload: None,
+            fill_bits: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaVdmMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaVdmMessage {
+    fn clear(&mut self) { 
+        self.fragment_count= None;
+        self.fragment_number= None;
+        self.sequence_id= None;
+        self.channel= None;
+        self.payload= None;
+        self.fill_bits= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.fragment_count),
+            1 => Some(&mut self.fragment_number),
+            2 => Some(&mut self.sequence_id),
+            3 => Some(&mut self.channel),
+            4 => Some(&mut self.payload),
+            5 => Some(&mut self.fill_bits),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.fragment_count),
+            1 => Some(&self.fragment_number),
+            2 => Some(&self.sequence_id),
+            3 => Some(&self.channel),
+            4 => Some(&self.payload),
+            5 => Some(&self.fill_bits),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        6
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("VDM".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::VDM
+    }
+}
+
+/// AIS VHF Data-link Own-vessel Report
+/// Ex: !AIVDO,1,1,,,B52K>;h00Fc>jpUlNV@ikwpUoP06,0*0F
+#[derive(Debug)]
+pub struct NmeaVdoMessage { 
+    pub fragment_count: Option<u32>,             // Number of fragments of the accumulated message (1-9)
+    pub fragment_number: Option<u32>,             // Fragment number (1-9)
+    pub sequence_id: Option<u32>,             // Sequential message identifier of multi-fragment message (0-9)
+    pub channel: Option<String>,             // AIS channel: A or 1 - 161.975 MHz; B or 2 - 162.025 MHz
+    pub payload: Option<String>,             // Encapsulated 6-bit ASCII payload
+    pub fill_bits: Option<u32>,             // Number of fill bits added to the last 6-bit char (0-5)
+    
+}
+
+impl NmeaVdoMessage {
+    pub fn new() -> NmeaVdoMessage {
+        NmeaVdoMessage { 
+            fragment_count: None,
+            fragment_number: None,
+            sequence_id: None,
+            channel: None,
+            payload: None,
+            fill_bits: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaVdoMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaVdoMessage {
+    fn clear(&mut self) { 
+        self.fragment_count= None;
+        self.fragment_number= None;
+        self.sequence_id= None;
+        self.channel= None;
+        self.payload= None;
+        self.fill_bits= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.fragment_count),
+            1 => Some(&mut self.fragment_number),
+            2 => Some(&mut self.sequence_id),
+            3 => Some(&mut self.channel),
+            4 => Some(&mut self.payload),
+            5 => Some(&mut self.fill_bits),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.fragment_count),
+            1 => Some(&self.fragment_number),
+            2 => Some(&self.sequence_id),
+            3 => Some(&self.channel),
+            4 => Some(&self.payload),
+            5 => Some(&self.fill_bits),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        6
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("VDO".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::VDO
+    }
+}
+
+/// AIS Addressed Binary and Safety Related Message
+/// Ex: !AIABM,1,1,0,316123456,0,12,85M:Ih1KUQU6jAs85`0,4*01
+#[derive(Debug)]
+pub struct NmeaAbmMessage { 
+    pub fragment_count: Option<u32>,             // Total number of sentences needed to transfer the message (1-9)
+    pub fragment_number: Option<u32>,             // Sentence number (1-9)
+    pub sequence_id: Option<u32>,             // Sequential message identifier (0-3)
+    pub destination_mmsi: Option<u32>,             // MMSI of the destination AIS unit
+    pub channel: Option<AisTxChannel>,             // AIS channel for broadcast: 0-no preference; 1-A; 2-B; 3-both
+    pub message_id: Option<u32>,             // ITU-R M.1371 message ID (6, 12, 25, 26)
+    pub payload: Option<String>,             // Encapsulated data of the binary or text part of the message
+    pub fill_bits: Option<u32>,             // Number of fill bits (0-5)
+    
+}
+
+impl NmeaAbmMessage {
+    pub fn new() -> NmeaAbmMessage {
+        NmeaAbmMessage { 
+            fragment_count: None,
+            fragment_number: None,
+            sequence_id: None,
+            destination_mmsi: None,
+            channel: None,
+            message_id: None,
+            payload: None,
+            fill_bits: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaAbmMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaAbmMessage {
+    fn clear(&mut self) { 
+        self.fragment_count= None;
+        self.fragment_number= None;
+        self.sequence_id= None;
+        self.destination_mmsi= None;
+        self.channel= None;
+        self.message_id= None;
+        self.payload= None;
+        self.fill_bits= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.fragment_count),
+            1 => Some(&mut self.fragment_number),
+            2 => Some(&mut self.sequence_id),
+            3 => Some(&mut self.destination_mmsi),
+            4 => Some(&mut self.channel),
+            5 => Some(&mut self.message_id),
+            6 => Some(&mut self.payload),
+            7 => Some(&mut self.fill_bits),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.fragment_count),
+            1 => Some(&self.fragment_number),
+            2 => Some(&self.sequence_id),
+            3 => Some(&self.destination_mmsi),
+            4 => Some(&self.channel),
+            5 => Some(&self.message_id),
+            6 => Some(&self.payload),
+            7 => Some(&self.fill_bits),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        8
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("ABM".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::ABM
+    }
+}
+
+/// AIS Broadcast Binary Message
+/// Ex: !AIBBM,1,1,0,0,14,85M:Ih1KUQU6jAs85`0,4*1B
+#[derive(Debug)]
+pub struct NmeaBbmMessage { 
+    pub fragment_count: Option<u32>,             // Total number of sentences needed to transfer the message (1-9)
+    pub fragment_number: Option<u32>,             // Sentence number (1-9)
+    pub sequence_id: Option<u32>,             // Sequential message identifier (0-9)
+    pub channel: Option<AisTxChannel>,             // AIS channel for broadcast: 0-no preference; 1-A; 2-B; 3-both
+    pub message_id: Option<u32>,             // ITU-R M.1371 message ID (8, 14, 25, 26)
+    pub payload: Option<String>,             // Encapsulated data of the binary or text part of the message
+    pub fill_bits: Option<u32>,             // Number of fill bits (0-5)
+    
+}
+
+impl NmeaBbmMessage {
+    pub fn new() -> NmeaBbmMessage {
+        NmeaBbmMessage { 
+            fragment_count: None,
+            fragment_number: None,
+            sequence_id: None,
+            channel: None,
+            message_id: None,
+            payload: None,
+            fill_bits: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaBbmMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaBbmMessage {
+    fn clear(&mut self) { 
+        self.fragment_count= None;
+        self.fragment_number= None;
+        self.sequence_id= None;
+        self.channel= None;
+        self.message_id= None;
+        self.payload= None;
+        self.fill_bits= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.fragment_count),
+            1 => Some(&mut self.fragment_number),
+            2 => Some(&mut self.sequence_id),
+            3 => Some(&mut self.channel),
+            4 => Some(&mut self.message_id),
+            5 => Some(&mut self.payload),
+            6 => Some(&mut self.fill_bits),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.fragment_count),
+            1 => Some(&self.fragment_number),
+            2 => Some(&self.sequence_id),
+            3 => Some(&self.channel),
+            4 => Some(&self.message_id),
+            5 => Some(&self.payload),
+            6 => Some(&self.fill_bits),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        7
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("BBM".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::BBM
+    }
+}
+
+/// AIS Addressed and Binary Broadcast Acknowledgement
+/// Ex: $AIABK,316123456,A,12,0,0*1D
+#[derive(Debug)]
+pub struct NmeaAbkMessage { 
+    pub mmsi: Option<u32>,             // MMSI of the addressed AIS unit, empty for broadcast
+    pub channel: Option<String>,             // AIS channel of reception: A or B
+    pub message_id: Option<u32>,             // ITU-R M.1371 message ID of the acknowledged message
+    pub sequence_id: Option<u32>,             // Sequential message identifier of ABM, BBM or AIR sentence
+    pub ack_type: Option<AbkAckType>,             // 0-received by addressee; 1-broadcast, no acknowledgement; 2-could not broadcast; 3-broadcast done; 4-late acknowledgement
+    
+}
+
+impl NmeaAbkMessage {
+    pub fn new() -> NmeaAbkMessage {
+        NmeaAbkMessage { 
+            mmsi: None,
+            channel: None,
+            message_id: None,
+            sequence_id: None,
+            ack_type: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaAbkMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaAbkMessage {
+    fn clear(&mut self) { 
+        self.mmsi= None;
+        self.channel= None;
+        self.message_id= None;
+        self.sequence_id= None;
+        self.ack_type= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.mmsi),
+            1 => Some(&mut self.channel),
+            2 => Some(&mut self.message_id),
+            3 => Some(&mut self.sequence_id),
+            4 => Some(&mut self.ack_type),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.mmsi),
+            1 => Some(&self.channel),
+            2 => Some(&self.message_id),
+            3 => Some(&self.sequence_id),
+            4 => Some(&self.ack_type),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        5
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("ABK".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::ABK
+    }
+}
+
+/// AIS Interrogation Request
+/// Ex: $AIAIR,316123456,5,,,,,,,A,,,*15
+#[derive(Debug)]
+pub struct NmeaAirMessage { 
+    pub mmsi: Option<u32>,             // MMSI of interrogated station 1
+    pub message_id: Option<u32>,             // First message requested from station 1
+    pub message_sub_section: Option<u32>,             // Message sub-section of the first message
+    pub second_message_id: Option<u32>,             // Second message requested from station 1
+    pub second_message_sub_section: Option<u32>,             // Message sub-section of the second message
+    pub second_mmsi: Option<u32>,             // MMSI of interrogated station 2
+    pub second_station_message_id: Option<u32>,             // Message requested from station 2
+    pub second_station_message_sub_section: Option<u32>,             // Message sub-section of message from station 2
+    pub channel: Option<String>,             // AIS channel of interrogation: A or B
+    pub slot_offset: Option<u32>,             // Reply slot offset of the first message from station 1
+    pub second_slot_offset: Option<u32>,             // Reply slot offset of the second message from station 1
+    pub second_station_slot_offset: Option<u32>,             // Reply slot offset of the message from station 2
+    
+}
+
+impl NmeaAirMessage {
+    pub fn new() -> NmeaAirMessage {
+        NmeaAirMessage { 
+            mmsi: None,
+            message_id: None,
+            message_sub_section: None,
+            second_message_id: None,
+            second_message_sub_section: None,
+            second_mmsi: None,
+            second_station_message_id: None,
+            second_station_message_sub_section: None,
+            channel: None,
+            slot_offset: None,
+            second_slot_offset: None,
+            second_station_slot_offset: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaAirMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaAirMessage {
+    fn clear(&mut self) { 
+        self.mmsi= None;
+        self.message_id= None;
+        self.message_sub_section= None;
+        self.second_message_id= None;
+        self.second_message_sub_section= None;
+        self.second_mmsi= None;
+        self.second_station_message_id= None;
+        self.second_station_message_sub_section= None;
+        self.channel= None;
+        self.slot_offset= None;
+        self.second_slot_offset= None;
+        self.second_station_slot_offset= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.mmsi),
+            1 => Some(&mut self.message_id),
+            2 => Some(&mut self.message_sub_section),
+            3 => Some(&mut self.second_message_id),
+            4 => Some(&mut self.second_message_sub_section),
+            5 => Some(&mut self.second_mmsi),
+            6 => Some(&mut self.second_station_message_id),
+            7 => Some(&mut self.second_station_message_sub_section),
+            8 => Some(&mut self.channel),
+            9 => Some(&mut self.slot_offset),
+            10 => Some(&mut self.second_slot_offset),
+            11 => Some(&mut self.second_station_slot_offset),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.mmsi),
+            1 => Some(&self.message_id),
+            2 => Some(&self.message_sub_section),
+            3 => Some(&self.second_message_id),
+            4 => Some(&self.second_message_sub_section),
+            5 => Some(&self.second_mmsi),
+            6 => Some(&self.second_station_message_id),
+            7 => Some(&self.second_station_message_sub_section),
+            8 => Some(&self.channel),
+            9 => Some(&self.slot_offset),
+            10 => Some(&self.second_slot_offset),
+            11 => Some(&self.second_station_slot_offset),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        12
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("AIR".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::AIR
+    }
+}
+
+/// Digital Selective Calling Information
+/// Ex: $CDDSC,20,3380400790,00,21,26,1423108312,2021,,,B,E*73
+#[derive(Debug)]
+pub struct NmeaDscMessage { 
+    pub format: Option<DscFormat>,             // Format specifier: 02-geographic area; 12-distress; 14-group; 16-all ships; 20-individual; 23-automatic
+    pub address: Option<String>,             // MMSI of called station followed by 0, or geographic area; MMSI of distressed vessel for distress alerts
+    pub category: Option<DscCategory>,             // Category: 00-routine; 08-safety; 10-urgency; 12-distress
+    pub first_telecommand: Option<String>,             // Nature of distress for distress alert, otherwise first telecommand
+    pub second_telecommand: Option<String>,             // Type of communication for distress alert, otherwise second telecommand
+    pub position: Option<String>,             // Quadrant and position qDDMMDDDMM, or channel/frequency
+    pub time: Option<String>,             // UTC time hhmm, 8888 if not available
+    pub distress_mmsi: Option<String>,             // MMSI of ship in distress for distress relay and acknowledgement
+    pub distress_nature: Option<DistressNature>,             // Nature of distress for distress relay and acknowledgement
+    pub acknowledgement: Option<DscAcknowledgement>,             // R-acknowledgement request; B-acknowledgement; S-end of sequence
+    pub expansion: Option<String>,             // E if DSE expansion sentence follows
+    
+}
+
+impl NmeaDscMessage {
+    pub fn new() -> NmeaDscMessage {
+        NmeaDscMessage { 
+            format: None,
+            address: None,
+            category: None,
+            first_telecommand: None,
+            second_telecommand: None,
+            position: None,
+            time: None,
+            distress_mmsi: None,
+            distress_nature: None,
+            acknowledgement: None,
+            expansion: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaDscMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaDscMessage {
+    fn clear(&mut self) { 
+        self.format= None;
+        self.address= None;
+        self.category= None;
+        self.first_telecommand= None;
+        self.second_telecommand= None;
+        self.position= None;
+        self.time= None;
+        self.distress_mmsi= None;
+        self.distress_nature= None;
+        self.acknowledgement= None;
+        self.expansion= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.format),
+            1 => Some(&mut self.address),
+            2 => Some(&mut self.category),
+            3 => Some(&mut self.first_telecommand),
+            4 => Some(&mut self.second_telecommand),
+            5 => Some(&mut self.position),
+            6 => Some(&mut self.time),
+            7 => Some(&mut self.distress_mmsi),
+            8 => Some(&mut self.distress_nature),
+            9 => Some(&mut self.acknowledgement),
+            10 => Some(&mut self.expansion),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.format),
+            1 => Some(&self.address),
+            2 => Some(&self.category),
+            3 => Some(&self.first_telecommand),
+            4 => Some(&self.second_telecommand),
+            5 => Some(&self.position),
+            6 => Some(&self.time),
+            7 => Some(&self.distress_mmsi),
+            8 => Some(&self.distress_nature),
+            9 => Some(&self.acknowledgement),
+            10 => Some(&self.expansion),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        11
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("DSC".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::DSC
+    }
+}
+
+/// Expanded Digital Selective Calling
+/// Ex: $CDDSE,1,1,A,3380400790,00,45894494*1B
+#[derive(Debug)]
+pub struct NmeaDseMessage { 
+    pub total_sentences: Option<u32>,             // Total number of sentences
+    pub sentence_number: Option<u32>,             // Sentence number
+    pub flag: Option<DseFlag>,             // Q-query; R-reply; A-automatic
+    pub address: Option<String>,             // MMSI of the station of DSC call followed by 0
+    pub data: Vec<String>,             // Pairs of expansion data specifier and data
+    
+}
+
+impl NmeaDseMessage {
+    pub fn new() -> NmeaDseMessage {
+        NmeaDseMessage { 
+            total_sentences: None,
+            sentence_number: None,
+            flag: None,
+            address: None,
+            data: Vec::new(),
+            
+        }
+    }
+}
+
+impl Default for NmeaDseMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaDseMessage {
+    fn clear(&mut self) { 
+        self.total_sentences= None;
+        self.sentence_number= None;
+        self.flag= None;
+        self.address= None;
+        self.data= Vec::new();
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.total_sentences),
+            1 => Some(&mut self.sentence_number),
+            2 => Some(&mut self.flag),
+            3 => Some(&mut self.address),
+            _ => Some(&mut self.data),
+            
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.total_sentences),
+            1 => Some(&self.sentence_number),
+            2 => Some(&self.flag),
+            3 => Some(&self.address),
+            _ => Some(&self.data),
+            
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        5
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("DSE".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::DSE
+    }
+}
+
+/// SeaSmart NMEA 2000 Message Encapsulation
+/// Ex: $PCDIN,01FD02,000C72EA,09,000802AE1EFAFFFF*57
+#[derive(Debug)]
+pub struct NmeaPcdinMessage { 
+    pub pgn: Option<String>,             // PGN, 6 hex digits
+    pub timestamp: Option<String>,             // Gateway timestamp, 8 hex digits
+    pub source: Option<String>,             // Source address, 2 hex digits
+    pub data: Option<String>,             // PGN data bytes as hex digits
+    
+}
+
+impl NmeaPcdinMessage {
+    pub fn new() -> NmeaPcdinMessage {
+        NmeaPcdinMessage { 
+            pgn: None,
+            timestamp: None,
+            source: None,
+            data: None,
+            
+        }
+    }
+}
+
+impl Default for NmeaPcdinMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageFields for NmeaPcdinMessage {
+    fn clear(&mut self) { 
+        self.pgn= None;
+        self.timestamp= None;
+        self.source= None;
+        self.data= None;
+        
+    }
+
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice> {
+        match idx {
+            0 => Some(&mut self.pgn),
+            1 => Some(&mut self.timestamp),
+            2 => Some(&mut self.source),
+            3 => Some(&mut self.data),
+            _ => None,
+        }
+    }
+
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice> {
+        match idx {
+            0 => Some(&self.pgn),
+            1 => Some(&self.timestamp),
+            2 => Some(&self.source),
+            3 => Some(&self.data),
+            _ => None,
+        }
+    }
+
+
+    #[inline]
+    fn field_count(&self) -> u8 {
+        4
+    }
+
+    #[inline]
+    fn get_addr(&self) -> AddrField<'static> {
+        AddrField::new("PCDIN".as_bytes())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn message_type(&self) -> NmeaMessages {
+        NmeaMessages::PCDIN
+    }
+}
+
+/// Parses example sentence of every message and encodes it back.
+#[cfg(test)]
+#[test]
+fn examples_round_trip() {
+    fn round_trip<M: MessageFields + Default>(talker_id: &str, sentence: &str) {
+        let mut msg = M::default();
+        let (_, m) = crate::parse_into(sentence.as_bytes(), &mut msg);
+        assert!(m.crc_ok, "CRC mismatch in {sentence}");
+        let encoded = if sentence.starts_with('!') {
+            msg.to_encapsulated_sentence(talker_id)
+        } else {
+            msg.to_sentence(talker_id)
+        };
+        // Fields of later NMEA versions missing in the example are written empty
+        let body = |s: &str| s.split('*').next().unwrap().trim_end_matches(',').to_string();
+        assert_eq!(body(&encoded), body(sentence), "{sentence}");
+    }
+
+    round_trip::<NmeaGllMessage>("GP", r"$GPGLL,3723.2475,N,12158.3416,W,161229.487,A,A*41");
+    round_trip::<NmeaRmcMessage>("GP", r"$GPRMC,203522.00,A,5109.0262308,N,11401.8407342,W,0.004,133.4,130522,0.0,E,D*2B");
+    round_trip::<NmeaGgaMessage>("GP", r"$GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B");
+    round_trip::<NmeaGsaMessage>("GN", r"$GNGSA,A,3,23,29,07,08,09,18,26,,,,,,1.94,1.18,1.54,1*04");
+    round_trip::<NmeaGsvMessage>("GP", r"$GPGSV,3,1,10,23,38,230,44,29,71,156,47,07,29,116,41,08,09,081,36,1*62");
+    round_trip::<NmeaVtgMessage>("GP", r"$GPVTG,140.88,T,,M,8.04,N,14.89,K,D*05");
+    round_trip::<NmeaZdaMessage>("GP", r"$GPZDA,201530.00,04,07,2002,00,00*60");
+    round_trip::<NmeaGnsMessage>("GN", r"$GNGNS,014035.00,4332.69262,S,17235.48549,E,RR,13,0.9,25.63,11.24,,*70");
+    round_trip::<NmeaGstMessage>("GP", r"$GPGST,172814.0,0.006,0.023,0.020,273.6,0.023,0.020,0.031*6A");
+    round_trip::<NmeaGbsMessage>("GP", r"$GPGBS,235458.00,1.4,1.3,3.1,03,,-21.4,3.8,1,0*5A");
+    round_trip::<NmeaGrsMessage>("GN", r"$GNGRS,104148.00,1,2.6,2.2,-1.6,-1.1,-1.7,-1.5,5.8,1.7,,,,,1,1*52");
+    round_trip::<NmeaHdtMessage>("HE", r"$HEHDT,274.07,T*19");
+    round_trip::<NmeaHdgMessage>("HC", r"$HCHDG,98.3,0.0,E,12.6,W*57");
+    round_trip::<NmeaHdmMessage>("HC", r"$HCHDM,238.5,M*25");
+    round_trip::<NmeaThsMessage>("GP", r"$GPTHS,77.52,E*34");
+    round_trip::<NmeaRotMessage>("TI", r"$TIROT,-3.5,A*10");
+    round_trip::<NmeaMwvMessage>("WI", r"$WIMWV,214.8,R,0.1,K,A*28");
+    round_trip::<NmeaMwdMessage>("WI", r"$WIMWD,10.1,T,10.1,M,12,N,40,M*5D");
+    round_trip::<NmeaMdaMessage>("WI", r"$WIMDA,29.7544,I,1.0076,B,35.5,C,,,42.1,,20.6,C,116.4,T,107.7,M,1.2,N,0.6,M*66");
+    round_trip::<NmeaMtwMessage>("YX", r"$YXMTW,15.2,C*14");
+    round_trip::<NmeaXdrMessage>("II", r"$IIXDR,C,19.52,C,TempAir,P,1.02481,B,Barometer*7E");
+    round_trip::<NmeaDbtMessage>("SD", r"$SDDBT,7.8,f,2.4,M,1.3,F*0D");
+    round_trip::<NmeaDbsMessage>("SD", r"$SDDBS,9.4,f,2.9,M,1.6,F*00");
+    round_trip::<NmeaDptMessage>("SD", r"$SDDPT,2.4,0.5,100.0*57");
+    round_trip::<NmeaVhwMessage>("VW", r"$VWVHW,45.0,T,43.0,M,3.5,N,6.4,K*56");
+    round_trip::<NmeaVlwMessage>("VW", r"$VWVLW,7803.2,N,0.00,N*42");
+    round_trip::<NmeaVbwMessage>("VM", r"$VMVBW,12.3,0.07,A,11.78,0.12,A*63");
+    round_trip::<NmeaRmbMessage>("GP", r"$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20");
+    round_trip::<NmeaApbMessage>("GP", r"$GPAPB,A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M*3C");
+    round_trip::<NmeaBodMessage>("GP", r"$GPBOD,099.3,T,105.6,M,POINTB,POINTA*45");
+    round_trip::<NmeaBwcMessage>("GP", r"$GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*21");
+    round_trip::<NmeaXteMessage>("GP", r"$GPXTE,A,A,0.67,L,N*6F");
+    round_trip::<NmeaWplMessage>("GP", r"$GPWPL,4917.16,N,12310.64,W,003*65");
+    round_trip::<NmeaRteMessage>("GP", r"$GPRTE,2,1,c,0,PBRCPK,PBRTO,PTELGR,PPLAND,PYAMBU,PPFAIR,PWARRN,PMORTL,PLISMR*73");
+    round_trip::<NmeaAamMessage>("GP", r"$GPAAM,A,A,0.10,N,WPTNME*32");
+    round_trip::<NmeaTtmMessage>("RA", r"$RATTM,11,0.78,355.4,T,2.7,190.7,T,0.49,10.9,N,TGT11,T,,160511.00,A*75");
+    round_trip::<NmeaTllMessage>("RA", r"$RATLL,01,5603.370,N,00948.680,E,TGT01,161229.00,T,*6D");
+    round_trip::<NmeaOsdMessage>("RA", r"$RAOSD,35.1,A,36.0,P,10.2,P,15.3,0.0,N*40");
+    round_trip::<NmeaRsdMessage>("RA", r"$RARSD,0.00,0.00,2.50,005.0,0.00,0.00,4.50,355.0,1.1,82.3,6.0,N,N*6B");
+    round_trip::<NmeaAlrMessage>("II", r"$IIALR,020000,031,A,V,Bilge pump alarm1*4B");
+    round_trip::<NmeaAckMessage>("II", r"$IIACK,031*57");
+    round_trip::<NmeaAlfMessage>("HN", r"$HNALF,1,1,0,124304.50,A,W,A,,192,1,1,0,LOST TARGET*12");
+    round_trip::<NmeaAlcMessage>("HN", r"$HNALC,01,01,00,02,,3015,1,2,FEC,192,1,1*34");
+    round_trip::<NmeaAcnMessage>("VR", r"$VRACN,124305.00,,192,1,A,C*6E");
+    round_trip::<NmeaArcMessage>("HN", r"$HNARC,124305.00,,192,1,S*0D");
+    round_trip::<NmeaHbtMessage>("HN", r"$HNHBT,60,A,3*00");
+    round_trip::<NmeaDtmMessage>("GP", r"$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F");
+    round_trip::<NmeaTxtMessage>("GP", r"$GPTXT,01,01,02,ANTSTATUS=OK*3B");
+    round_trip::<NmeaRsaMessage>("II", r"$IIRSA,10.5,A,-3.2,A*58");
+    round_trip::<NmeaRorMessage>("AG", r"$AGROR,-12.0,A,,V,B*00");
+    round_trip::<NmeaRpmMessage>("II", r"$IIRPM,E,1,2418.2,10.5,A*5F");
+    round_trip::<NmeaPrcMessage>("II", r"$IIPRC,45.0,A,80.0,P,12.5,D,B,2*75");
+    round_trip::<NmeaTrcMessage>("II", r"$IITRC,1,60.0,P,10.0,D,270.0,B,R*5C");
+    round_trip::<NmeaEtlMessage>("II", r"$IIETL,083015.00,O,03,20,B,1*41");
+    round_trip::<NmeaPubx00Message>("", r"$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F");
+    round_trip::<NmeaPubx03Message>("", r"$PUBX,03,04,23,-,,,45,010,29,-,,,46,013,08,U,067,31,42,025,10,e,195,33,46,026*22");
+    round_trip::<NmeaPubx04Message>("", r"$PUBX,04,073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43,*5D");
+    round_trip::<NmeaPubx40Message>("", r"$PUBX,40,GLL,1,0,0,0,0,0*5D");
+    round_trip::<NmeaPubx41Message>("", r"$PUBX,41,1,0007,0003,19200,0*25");
+    round_trip::<NmeaPgrmeMessage>("", r"$PGRME,15.0,M,45.0,M,25.0,M*1C");
+    round_trip::<NmeaPgrmzMessage>("", r"$PGRMZ,246,f,3*1B");
+    round_trip::<NmeaPgrmmMessage>("", r"$PGRMM,WGS 84*06");
+    round_trip::<NmeaPgrmtMessage>("", r"$PGRMT,GPS 15L/15H VER 2.05,P,P,R,R,P,,23,R*64");
+    round_trip::<NmeaPgrmcMessage>("", r"$PGRMC,A,218.8,100,6378137.000,298.257223563,0.0,0.0,0.0,A,3,1,1,4,30*72");
+    round_trip::<NmeaPgrmoMessage>("", r"$PGRMO,GPGSV,0*22");
+    round_trip::<NmeaPmtk001Message>("", r"$PMTK001,220,3*30");
+    round_trip::<NmeaPmtk101Message>("", r"$PMTK101*32");
+    round_trip::<NmeaPmtk103Message>("", r"$PMTK103*30");
+    round_trip::<NmeaPmtk220Message>("", r"$PMTK220,1000*1F");
+    round_trip::<NmeaPmtk314Message>("", r"$PMTK314,0,1,0,1,1,5,0,0,0,0,0,0,0,0,0,0,0,0,0*2C");
+    round_trip::<NmeaVdmMessage>("AI", r"!AIVDM,1,1,,B,15M67FC000G?ufbE`FepT@3n00Sa,0*5C");
+    round_trip::<NmeaVdoMessage>("AI", r"!AIVDO,1,1,,,B52K>;h00Fc>jpUlNV@ikwpUoP06,0*0F");
+    round_trip::<NmeaAbmMessage>("AI", r"!AIABM,1,1,0,316123456,0,12,85M:Ih1KUQU6jAs85`0,4*01");
+    round_trip::<NmeaBbmMessage>("AI", r"!AIBBM,1,1,0,0,14,85M:Ih1KUQU6jAs85`0,4*1B");
+    round_trip::<NmeaAbkMessage>("AI", r"$AIABK,316123456,A,12,0,0*1D");
+    round_trip::<NmeaAirMessage>("AI", r"$AIAIR,316123456,5,,,,,,,A,,,*15");
+    round_trip::<NmeaDscMessage>("CD", r"$CDDSC,20,3380400790,00,21,26,1423108312,2021,,,B,E*73");
+    round_trip::<NmeaDseMessage>("CD", r"$CDDSE,1,1,A,3380400790,00,45894494*1B");
+    round_trip::<NmeaPcdinMessage>("", r"$PCDIN,01FD02,000C72EA,09,000802AE1EFAFFFF*57");
+}
\ No newline at end of file
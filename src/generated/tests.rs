@@ -0,0 +1,397 @@
+//! Golden tests for generated messages. Sentences are taken from receiver manuals and logs.
+
+use crate::{
+    fields::*,
+    generated::nmea3::*,
+    messages::MessageFields,
+    parse_into,
+    test_util::{parse, parse_with_talker},
+};
+
+#[test]
+fn gga_ublox() {
+    let (gga, talker) = parse_with_talker::<NmeaGgaMessage>(
+        "$GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B",
+    );
+    assert_eq!(talker, "GP");
+    assert_eq!(gga.utc.as_deref(), Some("092725.00"));
+    assert_eq!(gga.latitude, Some(4717.11399));
+    assert_eq!(gga.latitude_dir, Some(b'N'));
+    assert_eq!(gga.longitude, Some(833.9159));
+    assert_eq!(gga.longitude_dir, Some(b'E'));
+    assert_eq!(gga.quality, Some(FixQuality::Gps));
+    assert_eq!(gga.satellites, Some(8));
+    assert_eq!(gga.hdop, Some(1.01));
+    assert_eq!(
+        gga.altitude(),
+        Some(Distance::new(499.6, DistanceUnit::Meters))
+    );
+    assert_eq!(
+        gga.geoid_separation(),
+        Some(Distance::new(48.0, DistanceUnit::Meters))
+    );
+    assert_eq!(gga.diff_age, None);
+    assert_eq!(gga.diff_station_id, None);
+    assert!(gga.has_fix());
+}
+
+#[test]
+fn gga_trimble() {
+    let gga: NmeaGgaMessage = parse(
+        "$GPGGA,172814.0,3723.46587704,N,12202.26957864,W,2,6,1.2,18.893,M,-25.669,M,2.0,0031*4F",
+    );
+    assert_eq!(gga.latitude, Some(3723.46587704));
+    assert_eq!(gga.longitude_dir, Some(b'W'));
+    assert_eq!(gga.quality, Some(FixQuality::Dgps));
+    assert_eq!(gga.satellites, Some(6));
+    assert_eq!(gga.geoid_separation, Some(-25.669));
+    assert_eq!(gga.diff_age, Some(2.0));
+    assert_eq!(gga.diff_station_id.as_deref(), Some("0031"));
+    assert!((gga.ellipsoid_height_m().unwrap() - (18.893 - 25.669)).abs() < 1e-9);
+}
+
+#[test]
+fn gga_novatel() {
+    let gga: NmeaGgaMessage =
+        parse("$GPGGA,134658.00,5106.9792,N,11402.3003,W,2,09,1.0,1048.47,M,-16.27,M,08,AAAA*60");
+    assert_eq!(gga.utc.as_deref(), Some("134658.00"));
+    assert_eq!(gga.satellites, Some(9));
+    assert_eq!(gga.altitude, Some(1048.47));
+    assert_eq!(gga.diff_age, Some(8.0));
+    assert_eq!(gga.diff_station_id.as_deref(), Some("AAAA"));
+}
+
+#[test]
+fn gga_rtk_fixed_and_float() {
+    let (gga, talker) = parse_with_talker::<NmeaGgaMessage>(
+        "$GNGGA,001043.00,4404.14036,N,12118.85961,W,4,12,0.98,1113.0,M,-21.3,M,1.0,0000*6D",
+    );
+    assert_eq!(talker, "GN");
+    assert_eq!(gga.quality, Some(FixQuality::RtkFixed));
+    assert!(gga.quality.unwrap().is_rtk());
+
+    let gga: NmeaGgaMessage =
+        parse("$GNGGA,001044.00,4404.14037,N,12118.85962,W,5,12,0.98,1113.1,M,-21.3,M,2.0,0000*6B");
+    assert_eq!(gga.quality, Some(FixQuality::RtkFloat));
+}
+
+#[test]
+fn gga_no_fix() {
+    let gga: NmeaGgaMessage = parse("$GPGGA,,,,,,0,00,99.99,,,,,,*48");
+    assert_eq!(gga.latitude, None);
+    assert_eq!(gga.quality, Some(FixQuality::Invalid));
+    assert_eq!(gga.satellites, Some(0));
+    assert_eq!(gga.altitude(), None);
+    assert!(!gga.has_fix());
+}
+
+#[test]
+fn gga_malformed_fields() {
+    // Garbled serial line: fields are set before CRC is checked, so parsing shall not panic
+    let mut gga = NmeaGgaMessage::default();
+    let (_, m) = parse_into(
+        b"$GPGGA,092725.00,47x7.11399,N,00833.91590,E,1,0.8,1.01,\xff\xfe,M,48.0,M,,*00",
+        &mut gga,
+    );
+    assert!(!m.crc_ok);
+    assert_eq!(gga.latitude, None);
+    assert_eq!(gga.longitude, Some(833.9159));
+    assert_eq!(gga.satellites, None);
+    assert_eq!(gga.altitude, None);
+}
+
+#[test]
+fn vtg() {
+    let vtg: NmeaVtgMessage = parse("$GPVTG,140.88,T,,M,8.04,N,14.89,K,D*05");
+    assert_eq!(vtg.course_true, Some(140.88));
+    assert_eq!(vtg.course_true_ref, Some(HeadingReference::True));
+    assert_eq!(vtg.course_magnetic, None);
+    assert_eq!(vtg.course_magnetic_ref, Some(HeadingReference::Magnetic));
+    assert_eq!(vtg.speed(), Some(Speed::new(8.04, SpeedUnit::Knots)));
+    assert_eq!(vtg.speed_kmh, Some(14.89));
+    assert_eq!(vtg.speed_kmh_unit, Some(SpeedUnit::KilometersPerHour));
+    assert_eq!(vtg.mode, Some(PositionMode::Differential));
+}
+
+#[test]
+fn vtg_nmea_2_0() {
+    let vtg: NmeaVtgMessage = parse("$GPVTG,054.7,T,034.4,M,005.5,N,010.2,K*48");
+    assert_eq!(vtg.course_magnetic, Some(34.4));
+    assert_eq!(vtg.mode, None);
+}
+
+#[test]
+fn zda() {
+    let zda: NmeaZdaMessage = parse("$GPZDA,201530.00,04,07,2002,00,00*60");
+    assert_eq!(zda.utc.as_deref(), Some("201530.00"));
+    assert_eq!(zda.day, Some(4));
+    assert_eq!(zda.month, Some(7));
+    assert_eq!(zda.year, Some(2002));
+    assert_eq!(zda.local_zone_offset_minutes(), Some(0));
+}
+
+#[test]
+fn zda_malformed_fields() {
+    let mut zda = NmeaZdaMessage::default();
+    parse_into(b"$GPZDA,201530.00,4.5,07,20O2,-0x,00*60", &mut zda);
+    assert_eq!(zda.day, None);
+    assert_eq!(zda.month, Some(7));
+    assert_eq!(zda.year, None);
+    assert_eq!(zda.local_zone_hours, None);
+}
+
+#[test]
+fn zda_local_zone() {
+    let zda: NmeaZdaMessage = parse("$GPZDA,160012.71,11,03,2004,-03,30*4C");
+    assert_eq!(zda.local_zone_hours, Some(-3.0));
+    assert_eq!(zda.local_zone_minutes, Some(30));
+    assert_eq!(zda.local_zone_offset_minutes(), Some(-210));
+
+    let zda: NmeaZdaMessage = parse("$GPZDA,160012.71,11,03,2004,05,45*65");
+    assert_eq!(zda.local_zone_offset_minutes(), Some(345));
+
+    let zda: NmeaZdaMessage = parse("$GPZDA,160012.71,11,03,2004,-00,30*4F");
+    assert_eq!(zda.local_zone_offset_minutes(), Some(-30));
+    assert_eq!(
+        zda.to_sentence("GP"),
+        "$GPZDA,160012.71,11,03,2004,-00,30*4F\r\n"
+    );
+
+    let zda: NmeaZdaMessage = parse("$GPZDA,160012.71,11,03,2004,00,-30*4F");
+    assert_eq!(zda.local_zone_offset_minutes(), Some(-30));
+}
+
+#[test]
+fn gns() {
+    let (gns, talker) = parse_with_talker::<NmeaGnsMessage>(
+        "$GNGNS,014035.00,4332.69262,S,17235.48549,E,RR,13,0.9,25.63,11.24,,*70",
+    );
+    assert_eq!(talker, "GN");
+    assert_eq!(gns.latitude, Some(4332.69262));
+    assert_eq!(gns.latitude_dir, Some(b'S'));
+    assert_eq!(gns.satellites, Some(13));
+    assert_eq!(
+        gns.altitude(),
+        Some(Distance::new(25.63, DistanceUnit::Meters))
+    );
+    assert_eq!(gns.geoid_separation, Some(11.24));
+    assert_eq!(
+        gns.modes(),
+        vec![
+            (GnssSystem::Gps, PositionMode::RtkFixed),
+            (GnssSystem::Glonass, PositionMode::RtkFixed)
+        ]
+    );
+    assert_eq!(gns.mode_of(GnssSystem::Galileo), None);
+    assert_eq!(gns.nav_status, None);
+}
+
+#[test]
+fn gns_nmea_4_10() {
+    let gns: NmeaGnsMessage =
+        parse("$GNGNS,014035.00,4332.69262,S,17235.48549,E,ADNA,13,0.9,25.63,11.24,,,S*05");
+    assert_eq!(gns.mode_of(GnssSystem::Gps), Some(PositionMode::Autonomous));
+    assert_eq!(
+        gns.mode_of(GnssSystem::Glonass),
+        Some(PositionMode::Differential)
+    );
+    assert_eq!(
+        gns.mode_of(GnssSystem::Galileo),
+        Some(PositionMode::NotValid)
+    );
+    assert_eq!(
+        gns.mode_of(GnssSystem::BeiDou),
+        Some(PositionMode::Autonomous)
+    );
+    assert_eq!(gns.nav_status, Some(NavStatus::Safe));
+}
+
+#[test]
+fn hdt_gyro() {
+    let (hdt, talker) = parse_with_talker::<NmeaHdtMessage>("$HEHDT,274.07,T*19");
+    assert_eq!(talker, "HE");
+    assert_eq!(hdt.heading, Some(274.07));
+    assert_eq!(hdt.reference, Some(HeadingReference::True));
+}
+
+#[test]
+fn hdg_deviation_variation() {
+    let (hdg, talker) = parse_with_talker::<NmeaHdgMessage>("$HCHDG,98.3,0.0,E,12.6,W*57");
+    assert_eq!(talker, "HC");
+    assert_eq!(hdg.heading, Some(98.3));
+    assert_eq!(hdg.deviation_dir, Some(EastWest::East));
+    assert_eq!(hdg.variation, Some(12.6));
+    assert_eq!(hdg.variation_dir, Some(EastWest::West));
+    assert!((hdg.magnetic_heading().unwrap() - 98.3).abs() < 1e-9);
+    assert!((hdg.true_heading().unwrap() - 85.7).abs() < 1e-9);
+
+    let hdg: NmeaHdgMessage = parse("$HCHDG,355.0,2.5,E,7.0,E*41");
+    assert!((hdg.true_heading().unwrap() - 4.5).abs() < 1e-9);
+
+    let hdg: NmeaHdgMessage = parse("$HCHDG,101.1,,,,*43");
+    assert_eq!(hdg.magnetic_heading(), Some(101.1));
+    assert_eq!(hdg.true_heading(), None);
+}
+
+#[test]
+fn hdm() {
+    let hdm: NmeaHdmMessage = parse("$HCHDM,238.5,M*25");
+    assert_eq!(hdm.heading, Some(238.5));
+    assert_eq!(hdm.reference, Some(HeadingReference::Magnetic));
+}
+
+#[test]
+fn ths_mode() {
+    let (ths, talker) = parse_with_talker::<NmeaThsMessage>("$GPTHS,77.52,E*34");
+    assert_eq!(talker, "GP");
+    assert_eq!(ths.mode, Some(HeadingMode::Estimated));
+    assert_eq!(ths.valid_heading(), Some(77.52));
+
+    let ths: NmeaThsMessage = parse("$INTHS,,V*1E");
+    assert_eq!(ths.heading, None);
+    assert_eq!(ths.mode, Some(HeadingMode::NotValid));
+    assert_eq!(ths.valid_heading(), None);
+}
+
+#[test]
+fn rot() {
+    let (rot, talker) = parse_with_talker::<NmeaRotMessage>("$TIROT,-3.5,A*10");
+    assert_eq!(talker, "TI");
+    assert_eq!(rot.rate, Some(-3.5));
+    assert_eq!(rot.status, Some(DataStatus::Valid));
+    assert!((rot.rate_per_second().unwrap() + 3.5 / 60.0).abs() < 1e-9);
+
+    let rot: NmeaRotMessage = parse("$HEROT,12.0,V*0F");
+    assert_eq!(rot.rate_per_second(), None);
+}
+
+#[test]
+fn dbt() {
+    let (dbt, talker) = parse_with_talker::<NmeaDbtMessage>("$SDDBT,7.8,f,2.4,M,1.3,F*0D");
+    assert_eq!(talker, "SD");
+    assert_eq!(dbt.depth_feet, Some(7.8));
+    assert_eq!(dbt.depth_feet_unit, Some(DistanceUnit::Feet));
+    assert_eq!(dbt.depth_fathoms_unit, Some(DistanceUnit::Fathoms));
+    assert_eq!(dbt.depth(), Some(Distance::new(2.4, DistanceUnit::Meters)));
+}
+
+#[test]
+fn dbt_feet_only() {
+    let dbt: NmeaDbtMessage = parse("$SDDBT,10.0,f,,M,,F*37");
+    let depth = dbt.depth().unwrap();
+    assert_eq!(depth.unit, DistanceUnit::Feet);
+    assert!((depth.meters() - 3.048).abs() < 1e-9);
+}
+
+#[test]
+fn dbs() {
+    let dbs: NmeaDbsMessage = parse("$SDDBS,9.4,f,2.9,M,1.6,F*00");
+    assert_eq!(dbs.depth(), Some(Distance::new(2.9, DistanceUnit::Meters)));
+}
+
+#[test]
+fn dpt_offset() {
+    let dpt: NmeaDptMessage = parse("$SDDPT,2.4,0.5,100.0*57");
+    assert_eq!(dpt.max_range, Some(100.0));
+    assert_eq!(dpt.depth(), Some(Distance::new(2.4, DistanceUnit::Meters)));
+    assert!((dpt.depth_below_surface().unwrap().meters() - 2.9).abs() < 1e-9);
+    assert_eq!(dpt.depth_below_keel(), None);
+
+    let dpt: NmeaDptMessage = parse("$SDDPT,12.5,-1.2*4F");
+    assert_eq!(dpt.max_range, None);
+    assert!((dpt.depth_below_keel().unwrap().meters() - 11.3).abs() < 1e-9);
+    assert_eq!(dpt.depth_below_surface(), None);
+
+    // Zero offset is depth below surface only
+    let dpt: NmeaDptMessage = parse("$SDDPT,3.1,0.0*55");
+    assert_eq!(
+        dpt.depth_below_surface(),
+        Some(Distance::new(3.1, DistanceUnit::Meters))
+    );
+    assert_eq!(dpt.depth_below_keel(), None);
+}
+
+#[test]
+fn vhw() {
+    let (vhw, talker) = parse_with_talker::<NmeaVhwMessage>("$VWVHW,45.0,T,43.0,M,3.5,N,6.4,K*56");
+    assert_eq!(talker, "VW");
+    assert_eq!(vhw.heading_true, Some(45.0));
+    assert_eq!(vhw.heading_magnetic, Some(43.0));
+    assert_eq!(vhw.speed(), Some(Speed::new(3.5, SpeedUnit::Knots)));
+
+    let vhw: NmeaVhwMessage = parse("$VWVHW,,T,,M,,N,7.2,K*7F");
+    assert!((vhw.speed().unwrap().meters_per_second() - 2.0).abs() < 1e-9);
+}
+
+#[test]
+fn vlw() {
+    let vlw: NmeaVlwMessage = parse("$VWVLW,7803.2,N,0.00,N*42");
+    let total = vlw.total_water_distance().unwrap();
+    assert_eq!(total.unit, DistanceUnit::NauticalMiles);
+    assert!((total.meters() - 7803.2 * 1852.0).abs() < 1e-6);
+    assert_eq!(vlw.trip_water_distance().unwrap().value, 0.0);
+    assert_eq!(vlw.total_ground_distance(), None);
+
+    let vlw: NmeaVlwMessage = parse("$IIVLW,2310.4,N,12.3,N,2405.9,N,13.1,N*40");
+    assert_eq!(vlw.trip_ground_distance().unwrap().value, 13.1);
+}
+
+#[test]
+fn vbw() {
+    let (vbw, talker) = parse_with_talker::<NmeaVbwMessage>("$VMVBW,12.3,0.07,A,11.78,0.12,A*63");
+    assert_eq!(talker, "VM");
+    assert_eq!(vbw.water_speed(), Some(Speed::new(12.3, SpeedUnit::Knots)));
+    assert_eq!(
+        vbw.water_speed_transverse(),
+        Some(Speed::new(0.07, SpeedUnit::Knots))
+    );
+    assert_eq!(
+        vbw.ground_speed(),
+        Some(Speed::new(11.78, SpeedUnit::Knots))
+    );
+    assert_eq!(vbw.stern_water_speed_status, None);
+
+    let vbw: NmeaVbwMessage = parse("$VDVBW,,,V,5.1,-0.2,A,,V,,V*6D");
+    assert_eq!(vbw.water_speed(), None);
+    assert_eq!(
+        vbw.ground_speed_transverse(),
+        Some(Speed::new(-0.2, SpeedUnit::Knots))
+    );
+    assert_eq!(vbw.stern_water_speed_status, Some(DataStatus::Invalid));
+}
+
+#[test]
+fn dtm_wgs84() {
+    let dtm: NmeaDtmMessage = parse("$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F");
+    assert!(dtm.is_wgs84());
+    assert_eq!(dtm.reference_datum, Some(Datum::Wgs84));
+    assert_eq!(dtm.latitude_offset_degrees(), Some(0.0));
+}
+
+#[test]
+fn dtm_local_datum() {
+    let dtm: NmeaDtmMessage = parse("$GPDTM,999,CH95,0.08,N,0.07,W,-47.7,W84*0E");
+    assert_eq!(dtm.datum, Some(Datum::UserDefined));
+    assert!(!dtm.is_wgs84());
+    assert_eq!(dtm.subdivision.as_deref(), Some("CH95"));
+    assert!((dtm.latitude_offset_degrees().unwrap() - 0.08 / 60.0).abs() < 1e-9);
+    assert!((dtm.longitude_offset_degrees().unwrap() + 0.07 / 60.0).abs() < 1e-9);
+    assert_eq!(dtm.altitude_offset().unwrap().meters(), -47.7);
+}
+
+#[test]
+fn dtm_glonass_datum() {
+    let (dtm, talker) = parse_with_talker::<NmeaDtmMessage>("$GLDTM,P90,,0.0,N,0.0,E,0.0,W84*71");
+    assert_eq!(talker, "GL");
+    assert_eq!(dtm.datum, Some(Datum::Pe90));
+}
+
+#[test]
+fn dtm_other_datum() {
+    let dtm: NmeaDtmMessage = parse("$GPDTM,216,,0.0,N,0.0,E,0.0,W84*01");
+    assert_eq!(dtm.datum, Some(Datum::Other("216".to_string())));
+    assert_eq!(
+        dtm.to_sentence("GP"),
+        "$GPDTM,216,,0.0,N,0.0,E,0.0,W84*01\r\n"
+    );
+}
@@ -0,0 +1,268 @@
+//! Hand-written helpers for generated messages.
+//! Generated code is overwritten by `generate_code.sh`, so accessors combining several fields live here.
+
+use crate::{
+    fields::{
+        DataStatus, Datum, Distance, DistanceUnit, GnssSystem, HeadingMode, PositionMode, Speed,
+        SpeedUnit,
+    },
+    generated::nmea3::{
+        NmeaDbsMessage, NmeaDbtMessage, NmeaDptMessage, NmeaDtmMessage, NmeaGgaMessage,
+        NmeaGnsMessage, NmeaHdgMessage, NmeaRotMessage, NmeaThsMessage, NmeaVbwMessage,
+        NmeaVhwMessage, NmeaVlwMessage, NmeaVtgMessage, NmeaZdaMessage,
+    },
+};
+
+impl NmeaGgaMessage {
+    /// Altitude above mean sea level.
+    pub fn altitude(&self) -> Option<Distance> {
+        Distance::from_fields(self.altitude, self.altitude_unit)
+    }
+
+    /// Geoidal separation (geoid minus WGS84 ellipsoid).
+    pub fn geoid_separation(&self) -> Option<Distance> {
+        Distance::from_fields(self.geoid_separation, self.geoid_separation_unit)
+    }
+
+    /// Height above WGS84 ellipsoid in meters.
+    pub fn ellipsoid_height_m(&self) -> Option<f64> {
+        Some(self.altitude()?.meters() + self.geoid_separation()?.meters())
+    }
+
+    /// True if receiver reports a usable fix.
+    pub fn has_fix(&self) -> bool {
+        self.quality.is_some_and(|q| q.is_valid())
+    }
+}
+
+impl NmeaVtgMessage {
+    /// Speed over ground. Knots are preferred if both speeds are present.
+    pub fn speed(&self) -> Option<Speed> {
+        Speed::from_fields(self.speed_knots, self.speed_knots_unit)
+            .or(Speed::from_fields(self.speed_kmh, self.speed_kmh_unit))
+    }
+}
+
+impl NmeaZdaMessage {
+    /// Local zone offset from UTC in minutes. Zone minutes have the same sign as zone hours
+    /// ('-00,30' is -30). Sign of minutes is used if zone hours are '00'.
+    pub fn local_zone_offset_minutes(&self) -> Option<i32> {
+        let hours = self.local_zone_hours?;
+        let minutes = self.local_zone_minutes.unwrap_or(0);
+        let offset = hours.abs() as i32 * 60 + minutes.abs();
+        let negative = if hours == 0.0 {
+            hours.is_sign_negative() || minutes < 0
+        } else {
+            hours < 0.0
+        };
+        Some(if negative { -offset } else { offset })
+    }
+}
+
+impl NmeaGnsMessage {
+    /// Order of systems in the mode indicator string.
+    const MODE_SYSTEMS: [GnssSystem; 6] = [
+        GnssSystem::Gps,
+        GnssSystem::Glonass,
+        GnssSystem::Galileo,
+        GnssSystem::BeiDou,
+        GnssSystem::Qzss,
+        GnssSystem::NavIc,
+    ];
+
+    /// Mode indicator split per system. Unknown indicators are skipped.
+    pub fn modes(&self) -> Vec<(GnssSystem, PositionMode)> {
+        let Some(mode) = &self.mode else {
+            return Vec::new();
+        };
+        Self::MODE_SYSTEMS
+            .iter()
+            .zip(mode.as_bytes())
+            .filter_map(|(system, c)| Some((*system, PositionMode::from_code(&[*c])?)))
+            .collect()
+    }
+
+    /// Mode indicator of a system.
+    pub fn mode_of(&self, system: GnssSystem) -> Option<PositionMode> {
+        self.modes()
+            .into_iter()
+            .find(|(s, _)| *s == system)
+            .map(|(_, m)| m)
+    }
+
+    /// Altitude above mean sea level.
+    pub fn altitude(&self) -> Option<Distance> {
+        Some(Distance::new(self.altitude?, DistanceUnit::Meters))
+    }
+}
+
+impl NmeaDtmMessage {
+    /// Positions are given in WGS84 without offsets.
+    pub fn is_wgs84(&self) -> bool {
+        self.datum == Some(Datum::Wgs84)
+    }
+
+    /// Latitude offset in degrees, north is positive.
+    pub fn latitude_offset_degrees(&self) -> Option<f64> {
+        let minutes = self.latitude_offset?;
+        match self.latitude_offset_dir? {
+            b'S' => Some(-minutes / 60.0),
+            _ => Some(minutes / 60.0),
+        }
+    }
+
+    /// Longitude offset in degrees, east is positive.
+    pub fn longitude_offset_degrees(&self) -> Option<f64> {
+        let minutes = self.longitude_offset?;
+        match self.longitude_offset_dir? {
+            b'W' => Some(-minutes / 60.0),
+            _ => Some(minutes / 60.0),
+        }
+    }
+
+    pub fn altitude_offset(&self) -> Option<Distance> {
+        Some(Distance::new(self.altitude_offset?, DistanceUnit::Meters))
+    }
+}
+
+/// Normalizes angle to 0..360 degrees.
+fn normalize_degrees(angle: f64) -> f64 {
+    angle.rem_euclid(360.0)
+}
+
+impl NmeaHdgMessage {
+    /// Magnetic heading: sensor heading corrected by deviation (easterly is added).
+    pub fn magnetic_heading(&self) -> Option<f64> {
+        let deviation = match (self.deviation, self.deviation_dir) {
+            (Some(d), Some(dir)) => dir.signed(d),
+            _ => 0.0,
+        };
+        Some(normalize_degrees(self.heading? + deviation))
+    }
+
+    /// True heading: magnetic heading corrected by variation. Requires variation.
+    pub fn true_heading(&self) -> Option<f64> {
+        let variation = self.variation_dir?.signed(self.variation?);
+        Some(normalize_degrees(self.magnetic_heading()? + variation))
+    }
+}
+
+impl NmeaThsMessage {
+    /// Heading if mode indicates that it can be used for navigation.
+    pub fn valid_heading(&self) -> Option<f64> {
+        match self.mode? {
+            HeadingMode::Autonomous | HeadingMode::Estimated => self.heading,
+            _ => None,
+        }
+    }
+}
+
+impl NmeaRotMessage {
+    /// Rate of turn in degrees per second if status is valid.
+    pub fn rate_per_second(&self) -> Option<f64> {
+        match self.status? {
+            DataStatus::Valid => Some(self.rate? / 60.0),
+            DataStatus::Invalid => None,
+        }
+    }
+}
+
+impl NmeaDbtMessage {
+    /// Depth below transducer. Meters are preferred, then feet and fathoms.
+    pub fn depth(&self) -> Option<Distance> {
+        Distance::from_fields(self.depth_meters, self.depth_meters_unit)
+            .or(Distance::from_fields(self.depth_feet, self.depth_feet_unit))
+            .or(Distance::from_fields(
+                self.depth_fathoms,
+                self.depth_fathoms_unit,
+            ))
+    }
+}
+
+impl NmeaDbsMessage {
+    /// Depth below surface. Meters are preferred, then feet and fathoms.
+    pub fn depth(&self) -> Option<Distance> {
+        Distance::from_fields(self.depth_meters, self.depth_meters_unit)
+            .or(Distance::from_fields(self.depth_feet, self.depth_feet_unit))
+            .or(Distance::from_fields(
+                self.depth_fathoms,
+                self.depth_fathoms_unit,
+            ))
+    }
+}
+
+impl NmeaDptMessage {
+    /// Depth below transducer.
+    pub fn depth(&self) -> Option<Distance> {
+        Some(Distance::new(self.depth?, DistanceUnit::Meters))
+    }
+
+    /// Depth below surface. Available if offset is positive (distance from transducer to waterline)
+    /// or zero (transducer at waterline).
+    pub fn depth_below_surface(&self) -> Option<Distance> {
+        let offset = self.offset.filter(|o| *o >= 0.0)?;
+        Some(Distance::new(self.depth? + offset, DistanceUnit::Meters))
+    }
+
+    /// Depth below keel. Available if offset is negative (distance from transducer to keel).
+    pub fn depth_below_keel(&self) -> Option<Distance> {
+        let offset = self.offset.filter(|o| *o < 0.0)?;
+        Some(Distance::new(self.depth? + offset, DistanceUnit::Meters))
+    }
+}
+
+impl NmeaVhwMessage {
+    /// Speed through water. Knots are preferred if both speeds are present.
+    pub fn speed(&self) -> Option<Speed> {
+        Speed::from_fields(self.speed_knots, self.speed_knots_unit)
+            .or(Speed::from_fields(self.speed_kmh, self.speed_kmh_unit))
+    }
+}
+
+impl NmeaVlwMessage {
+    pub fn total_water_distance(&self) -> Option<Distance> {
+        Distance::from_fields(self.total_water_distance, self.total_water_distance_unit)
+    }
+
+    pub fn trip_water_distance(&self) -> Option<Distance> {
+        Distance::from_fields(self.trip_water_distance, self.trip_water_distance_unit)
+    }
+
+    pub fn total_ground_distance(&self) -> Option<Distance> {
+        Distance::from_fields(self.total_ground_distance, self.total_ground_distance_unit)
+    }
+
+    pub fn trip_ground_distance(&self) -> Option<Distance> {
+        Distance::from_fields(self.trip_ground_distance, self.trip_ground_distance_unit)
+    }
+}
+
+/// Speed in knots if status is valid.
+fn valid_knots(speed: Option<f64>, status: Option<DataStatus>) -> Option<Speed> {
+    match status? {
+        DataStatus::Valid => Some(Speed::new(speed?, SpeedUnit::Knots)),
+        DataStatus::Invalid => None,
+    }
+}
+
+impl NmeaVbwMessage {
+    /// Longitudinal speed through water.
+    pub fn water_speed(&self) -> Option<Speed> {
+        valid_knots(self.water_speed_longitudinal, self.water_speed_status)
+    }
+
+    /// Transverse speed through water.
+    pub fn water_speed_transverse(&self) -> Option<Speed> {
+        valid_knots(self.water_speed_transverse, self.water_speed_status)
+    }
+
+    /// Longitudinal speed over ground.
+    pub fn ground_speed(&self) -> Option<Speed> {
+        valid_knots(self.ground_speed_longitudinal, self.ground_speed_status)
+    }
+
+    /// Transverse speed over ground.
+    pub fn ground_speed_transverse(&self) -> Option<Speed> {
+        valid_knots(self.ground_speed_transverse, self.ground_speed_status)
+    }
+}
@@ -0,0 +1,171 @@
+//! Receiver autonomous integrity helpers for GST, GBS and GRS messages.
+
+use crate::{
+    fields::{Distance, DistanceUnit, GnssSystem, SignalId},
+    generated::nmea3::{NmeaGbsMessage, NmeaGrsMessage, NmeaGstMessage},
+};
+
+/// Scale factor of 1-sigma error ellipse to 95% confidence (sqrt of chi-squared with 2 DOF).
+const ELLIPSE_95_SCALE: f64 = 2.4477;
+
+impl NmeaGstMessage {
+    /// Horizontal error with 95% confidence.
+    /// Computed as 2DRMS from latitude and longitude errors, or from error ellipse axes if those are absent.
+    pub fn horizontal_error_95(&self) -> Option<Distance> {
+        let (a, b) = match (self.latitude_sd, self.longitude_sd) {
+            (Some(lat), Some(lon)) => (lat, lon),
+            _ => (self.semi_major_sd?, self.semi_minor_sd?),
+        };
+        Some(Distance::new(
+            2.0 * (a * a + b * b).sqrt(),
+            DistanceUnit::Meters,
+        ))
+    }
+
+    /// Semi-major and semi-minor axes of 95% error ellipse, meters.
+    pub fn error_ellipse_95(&self) -> Option<(f64, f64)> {
+        Some((
+            self.semi_major_sd? * ELLIPSE_95_SCALE,
+            self.semi_minor_sd? * ELLIPSE_95_SCALE,
+        ))
+    }
+
+    /// Vertical error with 95% confidence.
+    pub fn vertical_error_95(&self) -> Option<Distance> {
+        Some(Distance::new(
+            self.altitude_sd? * 1.96,
+            DistanceUnit::Meters,
+        ))
+    }
+}
+
+/// Most likely failed satellite reported by RAIM.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FailedSatellite {
+    pub prn: u32,
+    /// System id (NMEA v4.10+) or system detected from PRN range.
+    pub system: Option<GnssSystem>,
+    pub signal_id: Option<SignalId>,
+    /// Probability of missed detection.
+    pub missed_detection_probability: Option<f64>,
+    /// Estimate of bias, meters.
+    pub bias: Option<f64>,
+    /// Standard deviation of bias estimate, meters.
+    pub bias_sd: Option<f64>,
+}
+
+impl NmeaGbsMessage {
+    /// Most likely failed satellite. `None` if RAIM detected no fault.
+    pub fn failed_satellite(&self) -> Option<FailedSatellite> {
+        let prn = self.failed_prn?;
+        Some(FailedSatellite {
+            prn,
+            system: self.system_id.or(GnssSystem::from_nmea_prn(prn)),
+            signal_id: self.signal_id,
+            missed_detection_probability: self.missed_detection_probability,
+            bias: self.bias,
+            bias_sd: self.bias_sd,
+        })
+    }
+
+    /// Largest expected horizontal error, meters.
+    pub fn horizontal_error(&self) -> Option<f64> {
+        Some(self.latitude_error?.max(self.longitude_error?))
+    }
+}
+
+impl NmeaGrsMessage {
+    /// Range residuals in the order of satellites in GSA. Empty slots are skipped.
+    pub fn residuals(&self) -> Vec<f64> {
+        [
+            self.residual_1,
+            self.residual_2,
+            self.residual_3,
+            self.residual_4,
+            self.residual_5,
+            self.residual_6,
+            self.residual_7,
+            self.residual_8,
+            self.residual_9,
+            self.residual_10,
+            self.residual_11,
+            self.residual_12,
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Residual with largest magnitude, meters.
+    pub fn max_residual(&self) -> Option<f64> {
+        self.residuals()
+            .into_iter()
+            .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fields::{GnssSystem, GrsMode, SignalId},
+        generated::nmea3::{NmeaGbsMessage, NmeaGrsMessage, NmeaGstMessage},
+        test_util::parse,
+    };
+
+    #[test]
+    fn gst_trimble() {
+        let gst: NmeaGstMessage =
+            parse("$GPGST,172814.0,0.006,0.023,0.020,273.6,0.023,0.020,0.031*6A");
+        assert_eq!(gst.rms, Some(0.006));
+        assert_eq!(gst.orientation, Some(273.6));
+        assert_eq!(gst.altitude_sd, Some(0.031));
+
+        let h = gst.horizontal_error_95().unwrap().meters();
+        assert!((h - 2.0 * (0.023f64.powi(2) + 0.020f64.powi(2)).sqrt()).abs() < 1e-9);
+        let (a, b) = gst.error_ellipse_95().unwrap();
+        assert!((a - 0.0563).abs() < 1e-4);
+        assert!((b - 0.0490).abs() < 1e-4);
+    }
+
+    #[test]
+    fn gst_without_lat_lon_errors() {
+        let gst: NmeaGstMessage = parse("$GPGST,024603.00,3.2,6.6,4.7,47.3,,,*48");
+        let h = gst.horizontal_error_95().unwrap().meters();
+        assert!((h - 2.0 * (6.6f64.powi(2) + 4.7f64.powi(2)).sqrt()).abs() < 1e-9);
+        assert_eq!(gst.vertical_error_95(), None);
+    }
+
+    #[test]
+    fn gbs_failed_satellite() {
+        let gbs: NmeaGbsMessage = parse("$GPGBS,235458.00,1.4,1.3,3.1,03,,-21.4,3.8,1,0*5A");
+        assert_eq!(gbs.horizontal_error(), Some(1.4));
+        let failed = gbs.failed_satellite().unwrap();
+        assert_eq!(failed.prn, 3);
+        assert_eq!(failed.system, Some(GnssSystem::Gps));
+        assert_eq!(failed.signal_id, Some(SignalId(0)));
+        assert_eq!(failed.missed_detection_probability, None);
+        assert_eq!(failed.bias, Some(-21.4));
+        assert_eq!(failed.bias_sd, Some(3.8));
+    }
+
+    #[test]
+    fn gbs_no_fault() {
+        let gbs: NmeaGbsMessage = parse("$GPGBS,015509.00,-0.031,-0.186,0.219,,,,*4E");
+        assert_eq!(gbs.altitude_error, Some(0.219));
+        assert_eq!(gbs.failed_satellite(), None);
+    }
+
+    #[test]
+    fn grs_residuals() {
+        let grs: NmeaGrsMessage =
+            parse("$GNGRS,104148.00,1,2.6,2.2,-1.6,-1.1,-1.7,-1.5,5.8,1.7,,,,,1,1*52");
+        assert_eq!(grs.mode, Some(GrsMode::Recomputed));
+        assert_eq!(
+            grs.residuals(),
+            vec![2.6, 2.2, -1.6, -1.1, -1.7, -1.5, 5.8, 1.7]
+        );
+        assert_eq!(grs.max_residual(), Some(5.8));
+        assert_eq!(grs.system_id, Some(GnssSystem::Gps));
+        assert_eq!(grs.signal_id, Some(SignalId(1)));
+    }
+}
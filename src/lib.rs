@@ -1,10 +1,30 @@
-use messages::AddrField;
+#![allow(non_snake_case)]
 
+use messages::{AddrField, MessageFields};
+
+pub mod ais;
+pub mod alert;
+pub mod dsc;
+pub mod fields;
+pub mod garmin;
 pub mod generated;
+pub mod integrity;
+mod helpers;
 pub mod messages;
+pub mod mtk;
+pub mod n2k;
+pub mod navigation;
+pub mod propulsion;
+pub mod radar;
+pub mod sky_view;
+#[cfg(test)]
+mod test_util;
+pub mod text;
+pub mod ubx;
+pub mod weather;
 
-const DOLLAR: u8 = b'$';
-const EXCLAMATION: u8 = b'!';
+pub(crate) const DOLLAR: u8 = b'$';
+pub(crate) const EXCLAMATION: u8 = b'!';
 const ASTERISK: u8 = b'*';
 const COMMA: u8 = b',';
 const LF: u8 = 0x0A;
@@ -22,6 +42,9 @@ pub struct NmeaMessage<'a> {
 /// Is responsible to handle field's value, convert into required format and store.
 pub trait HandleField {
     fn handle(&mut self, addr_field: &AddrField<'_>, field_idx: u8, field: &[u8]);
+
+    /// Is called when all fields of a message are parsed and CRC is checked.
+    fn message_end(&mut self, _addr_field: &AddrField<'_>, _crc_ok: bool) {}
 }
 
 /// Parses single message from buffer until LF.
@@ -29,11 +52,11 @@ pub trait HandleField {
 /// See returned `[consume_amt]` to know how many bytes were read from the `[buf]`.
 pub fn get_message_body<'buf>(
     buf: &'buf [u8], // Source bufer
-    field_handler: &mut (dyn HandleField),
+    field_handler: &mut dyn HandleField,
 ) -> (usize /* consume_amt */, NmeaMessage<'buf>) {
     assert!(buf.len() > 10, "Too short NMEA message");
     assert!(
-        buf[0] == DOLLAR,
+        buf[0] == DOLLAR || buf[0] == EXCLAMATION,
         "Unexpected 1st char '{}'",
         char::from(buf[0])
     );
@@ -43,36 +66,39 @@ pub fn get_message_body<'buf>(
     let mut crc_ok = false;
 
     // Detect address field position [1..addr_end]
-    while addr_end < buf.len() && ![COMMA, CR, LF].contains(&buf[addr_end]) {
+    while addr_end < buf.len() && ![COMMA, ASTERISK, CR, LF].contains(&buf[addr_end]) {
         crc ^= buf[addr_end];
         addr_end += 1;
     }
-    crc ^= COMMA;
 
     let addr_field = AddrField::new(&buf[1..addr_end]);
-    let mut asterisk_pos = addr_end + 1; // next char after 1st ','
+    let mut asterisk_pos = addr_end;
     let mut field_start = addr_end + 1;
     let mut field_idx: u8 = 0;
-    // Parse message content until CRC
-    loop {
-        if ![ASTERISK, CR, LF].contains(&buf[asterisk_pos]) {
-            crc ^= buf[asterisk_pos];
-        }
-
-        // Detect fields and provide to concrete message parsers
-        if [COMMA, ASTERISK, CR, LF].contains(&buf[asterisk_pos]) {
-            let field = &buf[field_start..asterisk_pos];
-            field_start = asterisk_pos + 1;
-
-            field_handler.handle(&addr_field, field_idx, field);
-
-            field_idx += 1;
-        }
-
-        if asterisk_pos >= buf.len() || [ASTERISK, CR, LF].contains(&buf[asterisk_pos]) {
-            break;
+    // Parse message content until CRC. Sentences without fields (e.g. '$PMTK101*32') have none.
+    if buf.get(addr_end) == Some(&COMMA) {
+        crc ^= COMMA;
+        asterisk_pos += 1; // next char after 1st ','
+        loop {
+            if ![ASTERISK, CR, LF].contains(&buf[asterisk_pos]) {
+                crc ^= buf[asterisk_pos];
+            }
+
+            // Detect fields and provide to concrete message parsers
+            if [COMMA, ASTERISK, CR, LF].contains(&buf[asterisk_pos]) {
+                let field = &buf[field_start..asterisk_pos];
+                field_start = asterisk_pos + 1;
+
+                field_handler.handle(&addr_field, field_idx, field);
+
+                field_idx += 1;
+            }
+
+            if asterisk_pos >= buf.len() || [ASTERISK, CR, LF].contains(&buf[asterisk_pos]) {
+                break;
+            }
+            asterisk_pos += 1;
         }
-        asterisk_pos += 1;
     }
 
     // At this point message CRC is calculated. Compare with a CRC value in message after * if it is not empty.
@@ -80,6 +106,7 @@ pub fn get_message_body<'buf>(
         let expected_crc = hex_chars_to_u8(&buf[asterisk_pos + 1..asterisk_pos + 3]);
         crc_ok = expected_crc == crc;
     }
+    field_handler.message_end(&addr_field, crc_ok);
 
     let mut consume_amt = asterisk_pos;
     while asterisk_pos < buf.len() && buf[asterisk_pos] != LF {
@@ -90,14 +117,78 @@ pub fn get_message_body<'buf>(
         consume_amt += 1; // LF
     }
 
-    return (
+    (
         consume_amt,
         NmeaMessage {
             addr_field: &buf[1..addr_end],
             fields: &buf[addr_end..asterisk_pos - 1],
             crc_ok,
         },
-    );
+    )
+}
+
+/// Field handler which sets fields of a single message with matching address.
+struct SingleMessageHandler<'a> {
+    msg: &'a mut dyn MessageFields,
+    /// Sub ID of proprietary sentence matches message.
+    sub_id_ok: bool,
+}
+
+impl<'a> HandleField for SingleMessageHandler<'a> {
+    fn handle(&mut self, addr_field: &AddrField<'_>, field_idx: u8, field: &[u8]) {
+        if addr_field.data != self.msg.get_addr().data {
+            return;
+        }
+        let Some(sub_id) = self.msg.sub_id() else {
+            if field_idx == 0 {
+                self.msg.clear();
+            }
+            self.msg.set_field(field_idx, field);
+            return;
+        };
+        if field_idx == 0 {
+            self.sub_id_ok = field == sub_id.as_bytes();
+            if self.sub_id_ok {
+                self.msg.clear();
+            }
+        } else if self.sub_id_ok {
+            self.msg.set_field(field_idx - 1, field);
+        }
+    }
+}
+
+/// Parses single message from buffer into `[msg]`.
+/// Fields are set only if message address (without talker id) and sub ID of proprietary message match `[msg]`.
+pub fn parse_into<'buf>(
+    buf: &'buf [u8],
+    msg: &mut dyn MessageFields,
+) -> (usize /* consume_amt */, NmeaMessage<'buf>) {
+    get_message_body(
+        buf,
+        &mut SingleMessageHandler {
+            msg,
+            sub_id_ok: false,
+        },
+    )
+}
+
+/// Calculates CRC of sentence content between start char and '*'.
+pub fn checksum(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |crc, c| crc ^ c)
+}
+
+/// Builds sentence from address and fields: adds start char ('$' or '!'), CRC and CRLF.
+pub fn encode_sentence<S: AsRef<str>>(start: u8, addr: &str, fields: &[S]) -> String {
+    let mut body = String::from(addr);
+    for f in fields {
+        body.push(char::from(COMMA));
+        body.push_str(f.as_ref());
+    }
+    format!(
+        "{}{body}*{:02X}\r\n",
+        char::from(start),
+        checksum(body.as_bytes())
+    )
 }
 
 /// Converts 2 char ASCII hex value to a byte value.
@@ -119,7 +210,10 @@ fn hex_chars_to_u8(h: &[u8]) -> u8 {
 
 #[cfg(test)]
 mod tests {
-    use crate::{get_message_body, hex_chars_to_u8, messages::AddrField, HandleField, NmeaMessage};
+    use crate::{
+        encode_sentence, get_message_body, hex_chars_to_u8, messages::AddrField, HandleField,
+        NmeaMessage, DOLLAR,
+    };
 
     struct FieldHandlerStub {}
 
@@ -140,7 +234,7 @@ mod tests {
         }
     }
 
-    pub fn get_message_body_stub<'a>(buf: &'a [u8]) -> NmeaMessage {
+    pub fn get_message_body_stub(buf: &[u8]) -> NmeaMessage<'_> {
         get_message_body(buf, &mut FieldHandlerStub::new()).1
     }
 
@@ -167,7 +261,7 @@ mod tests {
 
     #[test]
     fn consume_2_lines_amt_test() {
-        let s = format!("$GPGLL,3751.65,S,14507.36,E*77\n$GPRMC,87,E*4B");
+        let s = "$GPGLL,3751.65,S,14507.36,E*77\n$GPRMC,87,E*4B".to_string();
         let buf = s.as_bytes();
         let r1 = get_message_body(buf, &mut FieldHandlerStub::new());
         let r2 = get_message_body(&buf[r1.0..], &mut FieldHandlerStub::new());
@@ -199,21 +293,28 @@ mod tests {
         let _ = get_message_body_stub("$GPGLL,3751.65,S,14507.36,E*".as_bytes());
     }
 
+    #[test]
+    fn encode_valid_nmea() {
+        let s = encode_sentence(DOLLAR, "GPGLL", &["3751.65", "S", "14507.36", "E"]);
+        assert_eq!(s, "$GPGLL,3751.65,S,14507.36,E*77\r\n");
+        assert!(get_message_body_stub(s.as_bytes()).crc_ok);
+    }
+
     #[test]
     fn hex_to_char_72() {
-        let v = hex_chars_to_u8(&"72".as_bytes());
+        let v = hex_chars_to_u8("72".as_bytes());
         assert_eq!(v, 0x72)
     }
 
     #[test]
     fn hex_to_char_fa() {
-        let v = hex_chars_to_u8(&"FA".as_bytes());
+        let v = hex_chars_to_u8("FA".as_bytes());
         assert_eq!(v, 0xFA)
     }
 
     #[test]
     fn hex_to_char_6c() {
-        let v = hex_chars_to_u8(&"6C".as_bytes());
+        let v = hex_chars_to_u8("6C".as_bytes());
         assert_eq!(v, 0x6C)
     }
 }
@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use std::{any::Any, env, fs::File, io::Read};
 
 use nmeaParseTest::generated::nmea3::*;
@@ -19,7 +21,7 @@ fn main() -> std::io::Result<()> {
     Ok(())
 }
 
-fn callback(msg_type: NmeaMessages, msg: &dyn Any) -> () {
+fn callback(msg_type: NmeaMessages, _talker_id: &str, msg: &dyn Any) {
     match msg_type {
         NmeaMessages::GLL => {
             let gll = msg.downcast_ref::<NmeaGllMessage>().unwrap();
@@ -29,6 +31,10 @@ fn callback(msg_type: NmeaMessages, msg: &dyn Any) -> () {
             let gll = msg.downcast_ref::<NmeaRmcMessage>().unwrap();
             println!("{:?}", gll);
         }
-        _ => panic!(),
+        NmeaMessages::GGA => {
+            let gga = msg.downcast_ref::<NmeaGgaMessage>().unwrap();
+            println!("{:?}", gga);
+        }
+        other => println!("{:?} parsed", other),
     }
 }
@@ -5,22 +5,76 @@ use std::{
     hash::Hash,
 };
 
-use crate::generated::{is_talker_id, nmea3::NmeaMessages};
+use crate::{
+    generated::{is_talker_id, nmea3::NmeaMessages},
+    DOLLAR, EXCLAMATION,
+};
 
 /// Represents a NMEA message with list of values.
 pub trait MessageFields {
+    /// Sets field value. Fields not defined in specification (e.g. added in later NMEA versions) are ignored.
     fn set_field(&mut self, idx: u8, value: &[u8]) {
-        self.get_field_mut(idx).set_from_slice(value);
+        if let Some(field) = self.get_field_mut(idx) {
+            field.set_from_slice(value);
+        }
+    }
+
+    /// Returns field by index or `None` if message has no such field.
+    fn get_field_mut(&mut self, idx: u8) -> Option<&mut dyn FromSlice>;
+    fn get_field(&self, idx: u8) -> Option<&dyn FromSlice>;
+
+    /// Encodes message into a sentence with CRC and CRLF. All fields defined in specification are written.
+    /// Sub ID of proprietary message is written as the first field. Proprietary sentences have no
+    /// talker id, `talker_id` shall be empty for them.
+    fn to_sentence(&self, talker_id: &str) -> String {
+        encode_message(self, DOLLAR, talker_id)
+    }
+
+    /// Encodes message into an encapsulation sentence starting with '!' (e.g. VDM or ABM).
+    fn to_encapsulated_sentence(&self, talker_id: &str) -> String {
+        encode_message(self, EXCLAMATION, talker_id)
+    }
+
+    /// Minimum integer digits and decimals of numeric field, as a sentence shall have them
+    /// (e.g. (4, 2) for latitude '4916.45'). `None` if field is written as is.
+    fn field_format(&self, _idx: u8) -> Option<(usize, usize)> {
+        None
     }
 
-    fn get_field_mut(&mut self, idx: u8) -> &mut dyn FromSlice;
     fn field_count(&self) -> u8;
     fn clear(&mut self);
     fn get_addr(&self) -> AddrField<'static>;
+
+    /// Sub ID of proprietary messages which share address (e.g. '00' for '$PUBX,00').
+    /// Sub ID is the first field of a sentence and is not counted in message fields.
+    fn sub_id(&self) -> Option<&'static str> {
+        None
+    }
+
     fn as_any(&self) -> &dyn Any;
     fn message_type(&self) -> NmeaMessages;
 }
 
+fn encode_message<M: MessageFields + ?Sized>(msg: &M, start: u8, talker_id: &str) -> String {
+    let fields: Vec<Cow<'_, str>> = msg
+        .sub_id()
+        .map(Cow::Borrowed)
+        .into_iter()
+        .chain((0..msg.field_count()).filter_map(|idx| {
+            let field = msg.get_field(idx)?;
+            Some(match msg.field_format(idx) {
+                Some((width, precision)) => field.format(width, precision),
+                None => field.as_string(),
+            })
+        }))
+        .collect();
+    let addr = format!(
+        "{talker_id}{}",
+        std::str::from_utf8(msg.get_addr().data).unwrap()
+    );
+    crate::encode_sentence(start, &addr, &fields)
+}
+
 /// Address field. May contain talker_id (e.g. 'GP' or 'GL').
 #[derive(PartialEq, Eq)]
 pub struct AddrField<'a> {
@@ -55,106 +109,367 @@ impl<'a> Hash for AddrField<'a> {
 /// This trait implemented to fix hash.Get_mut() issue and to get value by &[u8] to reduce lifetime.
 impl<'a> Borrow<[u8]> for AddrField<'a> {
     fn borrow(&self) -> &[u8] {
-        &self.data
+        self.data
     }
 }
 
+/// Proprietary messages sharing an address by sub ID.
+type SubMessages = HashMap<&'static str, Box<dyn MessageFields + 'static>>;
+
 /// Map of NMEA messages by address\message type.
+/// Proprietary messages sharing an address (e.g. '$PUBX,00' and '$PUBX,03') are dispatched by sub ID in the first field.
 pub struct MessagesMap {
     /// Key shall have references with lifetime 'static.
     /// Values shall be structs implementing MessageFields with lifetime 'static.
     pub msgs: HashMap<AddrField<'static>, Box<dyn MessageFields + 'static>>,
+    /// Proprietary messages with sub ID by address.
+    pub sub_msgs: HashMap<AddrField<'static>, SubMessages>,
+    /// Sub ID of the last parsed proprietary message.
+    sub_id: String,
 }
 
 impl MessagesMap {
-    pub fn get(&self, addr: &AddrField<'_>) -> Option<&Box<dyn MessageFields + 'static>> {
-        self.msgs.get(addr.data)
+    /// Adds message. Messages with sub ID are added to proprietary messages.
+    pub fn insert(&mut self, msg: Box<dyn MessageFields + 'static>) {
+        let addr = msg.get_addr();
+        match msg.sub_id() {
+            Some(sub_id) => {
+                self.sub_msgs.entry(addr).or_default().insert(sub_id, msg);
+            }
+            None => {
+                self.msgs.insert(addr, msg);
+            }
+        }
+    }
+
+    /// Returns message by address. For proprietary messages with sub ID returns the last parsed one.
+    pub fn get(&self, addr: &AddrField<'_>) -> Option<&(dyn MessageFields + 'static)> {
+        match self.sub_msgs.get(addr.data) {
+            Some(subs) => subs.get(self.sub_id.as_str()),
+            None => self.msgs.get(addr.data),
+        }
+        .map(|m| m.as_ref())
     }
 
     pub fn get_mut(
         &mut self,
         addr: &AddrField<'_>,
     ) -> Option<&mut Box<dyn MessageFields + 'static>> {
-        self.msgs.get_mut(addr.data)
+        match self.sub_msgs.get_mut(addr.data) {
+            Some(subs) => subs.get_mut(self.sub_id.as_str()),
+            None => self.msgs.get_mut(addr.data),
+        }
+    }
+
+    /// Sets field of message with the address. Message is cleared before the first field is set.
+    /// For proprietary messages with sub ID the first field selects message and is not stored.
+    /// Returns false if message is unknown.
+    pub fn set_field(&mut self, addr: &AddrField<'_>, field_idx: u8, value: &[u8]) -> bool {
+        let idx = if self.sub_msgs.contains_key(addr.data) {
+            if field_idx == 0 {
+                self.sub_id = String::from_utf8_lossy(value).into_owned();
+                return match self.get_mut(addr) {
+                    Some(msg) => {
+                        msg.clear();
+                        true
+                    }
+                    None => false,
+                };
+            }
+            field_idx - 1
+        } else {
+            field_idx
+        };
+
+        let Some(msg) = self.get_mut(addr) else {
+            return false;
+        };
+        if field_idx == 0 {
+            msg.clear();
+        }
+        msg.set_field(idx, value);
+        true
     }
 
     pub fn new() -> Self {
         MessagesMap {
             msgs: HashMap::new(),
+            sub_msgs: HashMap::new(),
+            sub_id: String::new(),
         }
     }
 }
 
+impl Default for MessagesMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 //************************ Common for all types used in NMEA   ************************************
 
 /// Trait for message fields to set field value regardless of message type.
 pub trait FromSlice {
     fn set_from_slice(&mut self, value: &[u8]);
-    fn as_string(&self) -> Cow<str>;
+    fn as_string(&self) -> Cow<'_, str>;
+
+    /// Formats numeric field with at least `width` integer digits and `precision` decimals.
+    fn format(&self, _width: usize, _precision: usize) -> Cow<'_, str> {
+        self.as_string()
+    }
+}
+
+/// Formats number with at least `width` integer digits (zero padded) and `precision` decimals.
+/// More decimals are kept if value has them, so that value is not rounded.
+fn format_number(value: f64, width: usize, precision: usize) -> String {
+    if !value.is_finite() {
+        return String::new();
+    }
+    let digits = value.abs().to_string();
+    let (int, frac) = digits.split_once('.').unwrap_or((&digits, ""));
+    let sign = if value.is_sign_negative() { "-" } else { "" };
+    let mut s = format!("{sign}{int:0>width$}");
+    if !frac.is_empty() || precision > 0 {
+        s.push('.');
+        s.push_str(&format!("{frac:0<precision$}"));
+    }
+    s
+}
+
+/// Parses numeric field. None if field is not valid UTF-8 or not a number, e.g. garbled by noise.
+fn parse_number<T: std::str::FromStr>(value: &[u8]) -> Option<T> {
+    std::str::from_utf8(value).ok()?.parse().ok()
 }
 
 impl FromSlice for Option<String> {
     fn set_from_slice(&mut self, value: &[u8]) {
-        *self = Some(String::from_utf8(value.to_vec()).unwrap());
+        *self = if value.is_empty() {
+            None
+        } else {
+            String::from_utf8(value.to_vec()).ok()
+        };
     }
 
-    fn as_string(&self) -> Cow<str> {
+    fn as_string(&self) -> Cow<'_, str> {
         match self {
             Some(v) => Cow::Borrowed(v),
-            None => Cow::Borrowed("-"),
+            None => Cow::Borrowed(""),
         }
     }
 }
 
 impl FromSlice for f64 {
     fn set_from_slice(&mut self, value: &[u8]) {
-        *self = std::str::from_utf8(value).unwrap().parse().unwrap();
+        // Malformed value is stored as NaN
+        *self = parse_number(value).unwrap_or(f64::NAN);
     }
 
-    fn as_string(&self) -> Cow<str> {
+    fn as_string(&self) -> Cow<'_, str> {
         Cow::Owned(self.to_string())
     }
+
+    fn format(&self, width: usize, precision: usize) -> Cow<'_, str> {
+        Cow::Owned(format_number(*self, width, precision))
+    }
 }
 
 impl FromSlice for Option<f64> {
     fn set_from_slice(&mut self, value: &[u8]) {
-        if value.len() == 0 {
-            *self = None
+        *self = if value.is_empty() {
+            None
+        } else {
+            parse_number(value)
         };
-        *self = Some(std::str::from_utf8(value).unwrap().parse().unwrap());
     }
 
-    fn as_string(&self) -> Cow<str> {
+    fn as_string(&self) -> Cow<'_, str> {
         match *self {
             Some(v) => Cow::Owned(v.to_string()),
             None => Cow::Borrowed(""),
         }
     }
+
+    fn format(&self, width: usize, precision: usize) -> Cow<'_, str> {
+        match *self {
+            Some(v) => Cow::Owned(format_number(v, width, precision)),
+            None => Cow::Borrowed(""),
+        }
+    }
 }
 
 impl FromSlice for u8 {
     fn set_from_slice(&mut self, value: &[u8]) {
-        assert!(value.len() == 1);
-        *self = value[0];
+        if let [v] = value {
+            *self = *v;
+        }
     }
 
-    fn as_string(&self) -> Cow<str> {
-        Cow::Owned(self.to_string())
+    fn as_string(&self) -> Cow<'_, str> {
+        Cow::Owned(char::from(*self).to_string())
     }
 }
 
 impl FromSlice for Option<u8> {
     fn set_from_slice(&mut self, value: &[u8]) {
-        if value.len() == 0 {
-            *self = None
+        *self = value.first().copied();
+    }
+
+    fn as_string(&self) -> Cow<'_, str> {
+        match *self {
+            Some(v) => Cow::Owned(char::from(v).to_string()),
+            None => Cow::Borrowed(""),
+        }
+    }
+}
+
+impl FromSlice for Option<u32> {
+    fn set_from_slice(&mut self, value: &[u8]) {
+        *self = if value.is_empty() {
+            None
+        } else {
+            parse_number(value)
         };
-        *self = Some(value[0]);
     }
 
-    fn as_string(&self) -> Cow<str> {
+    fn as_string(&self) -> Cow<'_, str> {
         match *self {
             Some(v) => Cow::Owned(v.to_string()),
             None => Cow::Borrowed(""),
         }
     }
+
+    fn format(&self, width: usize, precision: usize) -> Cow<'_, str> {
+        match *self {
+            Some(v) => Cow::Owned(format_number(v as f64, width, precision)),
+            None => Cow::Borrowed(""),
+        }
+    }
+}
+
+impl FromSlice for Option<i32> {
+    fn set_from_slice(&mut self, value: &[u8]) {
+        *self = if value.is_empty() {
+            None
+        } else {
+            parse_number(value)
+        };
+    }
+
+    fn as_string(&self) -> Cow<'_, str> {
+        match *self {
+            Some(v) => Cow::Owned(v.to_string()),
+            None => Cow::Borrowed(""),
+        }
+    }
+
+    fn format(&self, width: usize, precision: usize) -> Cow<'_, str> {
+        match *self {
+            Some(v) => Cow::Owned(format_number(v as f64, width, precision)),
+            None => Cow::Borrowed(""),
+        }
+    }
+}
+
+/// Repeated group of fields at the end of a message (e.g. satellites in GSV).
+/// Each field is appended as a raw string value.
+impl FromSlice for Vec<String> {
+    fn set_from_slice(&mut self, value: &[u8]) {
+        self.push(String::from_utf8_lossy(value).into_owned());
+    }
+
+    fn as_string(&self) -> Cow<'_, str> {
+        Cow::Owned(self.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FromSlice;
+
+    #[test]
+    fn empty_optional_fields() {
+        let mut f: Option<f64> = Some(1.0);
+        f.set_from_slice(b"");
+        assert_eq!(f, None);
+
+        let mut c: Option<u8> = Some(b'A');
+        c.set_from_slice(b"");
+        assert_eq!(c, None);
+
+        let mut n: Option<u32> = Some(1);
+        n.set_from_slice(b"");
+        assert_eq!(n, None);
+
+        let mut t: Option<String> = Some("A".to_string());
+        t.set_from_slice(b"");
+        assert_eq!(t, None);
+    }
+
+    #[test]
+    fn malformed_numeric_fields() {
+        let mut f: Option<f64> = Some(1.0);
+        f.set_from_slice(b"1.2.3");
+        assert_eq!(f, None);
+
+        let mut n: Option<u32> = Some(1);
+        n.set_from_slice(b"1.5");
+        assert_eq!(n, None);
+
+        let mut i: Option<i32> = Some(1);
+        i.set_from_slice(b"\xff");
+        assert_eq!(i, None);
+
+        let mut v = 1.0f64;
+        v.set_from_slice(b"abc");
+        assert!(v.is_nan());
+    }
+
+    #[test]
+    fn number_format() {
+        assert_eq!(Some(46.34).format(5, 2), "00046.34");
+        assert_eq!(Some(4916.45).format(4, 2), "4916.45");
+        assert_eq!(Some(4916.4).format(4, 2), "4916.40");
+        assert_eq!(Some(3723.46587704).format(4, 2), "3723.46587704");
+        assert_eq!(Some(0.0).format(1, 1), "0.0");
+        assert_eq!(Some(-3.5).format(3, 0), "-003.5");
+        assert_eq!(Some(11u32).format(3, 0), "011");
+        assert_eq!(Some(-3i32).format(2, 0), "-03");
+        assert_eq!(None::<f64>.format(4, 2), "");
+        assert_eq!(f64::NAN.format(4, 2), "");
+    }
+
+    #[test]
+    fn optional_u32_field() {
+        let mut n: Option<u32> = None;
+        n.set_from_slice(b"08");
+        assert_eq!(n, Some(8));
+        assert_eq!(n.as_string(), "8");
+    }
+
+    #[test]
+    fn optional_i32_field() {
+        let mut n: Option<i32> = None;
+        n.set_from_slice(b"-05");
+        assert_eq!(n, Some(-5));
+        n.set_from_slice(b"+03");
+        assert_eq!(n, Some(3));
+    }
+
+    #[test]
+    fn char_fields_as_string() {
+        assert_eq!(b'N'.as_string(), "N");
+        assert_eq!(Some(b'A').as_string(), "A");
+        let none: Option<String> = None;
+        assert_eq!(none.as_string(), "");
+    }
+
+    #[test]
+    fn repeated_field() {
+        let mut r: Vec<String> = Vec::new();
+        r.set_from_slice(b"23");
+        r.set_from_slice(b"");
+        r.set_from_slice(b"41");
+        assert_eq!(r, vec!["23", "", "41"]);
+        assert_eq!(r.as_string(), "23,,41");
+    }
 }
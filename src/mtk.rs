@@ -0,0 +1,314 @@
+//! MediaTek PMTK commands: output rate and sentence selection builders, restarts, acknowledge tracking.
+
+use std::collections::VecDeque;
+
+use crate::{
+    fields::PmtkAckFlag,
+    generated::nmea3::{NmeaPmtk001Message, NmeaPmtk220Message, NmeaPmtk314Message},
+    messages::MessageFields,
+};
+
+/// Sentences selected by PMTK314, with their field index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PmtkSentence {
+    Gll = 0,
+    Rmc = 1,
+    Vtg = 2,
+    Gga = 3,
+    Gsa = 4,
+    Gsv = 5,
+    Grs = 6,
+    Gst = 7,
+    Zda = 17,
+    /// MTK channel status.
+    Mchn = 18,
+}
+
+/// Restart commands (hot, warm, cold, full cold). They are answered by PMTK010 system message,
+/// not by PMTK001.
+const RESTART_COMMANDS: [u32; 4] = [101, 102, 103, 104];
+
+/// Number of output rate fields in PMTK314.
+const PMTK314_RATES: usize = 19;
+
+impl NmeaPmtk220Message {
+    /// Command setting position fix interval, milliseconds.
+    pub fn set_interval(interval: u32) -> Self {
+        NmeaPmtk220Message {
+            interval: Some(interval),
+        }
+    }
+
+    /// Command setting update rate, Hz. Returns None if rate is not positive or is too low
+    /// for the interval to fit into u32.
+    pub fn set_rate(hz: f64) -> Option<Self> {
+        if !hz.is_finite() || hz <= 0.0 {
+            return None;
+        }
+        let interval = (1000.0 / hz).round();
+        (interval <= u32::MAX as f64).then(|| Self::set_interval(interval as u32))
+    }
+}
+
+impl NmeaPmtk314Message {
+    /// Command disabling output of all sentences.
+    pub fn disable_all() -> Self {
+        NmeaPmtk314Message {
+            rates: vec!["0".to_string(); PMTK314_RATES],
+        }
+    }
+
+    /// Command restoring default output.
+    pub fn defaults() -> Self {
+        NmeaPmtk314Message {
+            rates: vec!["-1".to_string()],
+        }
+    }
+
+    /// Sets output rate of a sentence, in position fixes (0 disables it, 1 - every fix, up to 5).
+    pub fn with_rate(mut self, sentence: PmtkSentence, rate: u32) -> Self {
+        if self.is_defaults() {
+            self.rates.clear();
+        }
+        let idx = sentence as usize;
+        if self.rates.len() <= idx {
+            self.rates
+                .resize(PMTK314_RATES.max(idx + 1), "0".to_string());
+        }
+        self.rates[idx] = rate.to_string();
+        self
+    }
+
+    /// Output rate of a sentence. None if command restores defaults.
+    pub fn rate(&self, sentence: PmtkSentence) -> Option<u32> {
+        if self.is_defaults() {
+            return None;
+        }
+        self.rates
+            .get(sentence as usize)
+            .map(|r| r.parse().unwrap_or(0))
+    }
+
+    pub fn is_defaults(&self) -> bool {
+        self.rates.first().is_some_and(|r| r == "-1")
+    }
+}
+
+impl NmeaPmtk001Message {
+    pub fn is_success(&self) -> bool {
+        self.flag == Some(PmtkAckFlag::Succeeded)
+    }
+}
+
+/// PMTK command number from its address (e.g. 220 for PMTK220).
+pub fn command_number(msg: &dyn MessageFields) -> Option<u32> {
+    std::str::from_utf8(msg.get_addr().data.strip_prefix(b"PMTK")?)
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// Result of a sent command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandResult {
+    Acknowledged(PmtkAckFlag),
+    /// No acknowledge received in time.
+    TimedOut,
+}
+
+/// Sent command waiting for acknowledge.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PendingCommand {
+    command: u32,
+    sent_at: f64,
+}
+
+/// Correlates PMTK commands with PMTK001 acknowledges.
+/// Commands with the same number are acknowledged in order they were sent.
+#[derive(Debug)]
+pub struct CommandTracker {
+    /// Acknowledge timeout, seconds.
+    timeout: f64,
+    pending: VecDeque<PendingCommand>,
+}
+
+impl CommandTracker {
+    pub fn new(timeout: f64) -> Self {
+        CommandTracker {
+            timeout,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Encodes command and starts waiting for its acknowledge. `time` is in seconds.
+    /// Restarts are not tracked since they are not acknowledged with PMTK001.
+    /// Returns None for non-PMTK messages.
+    pub fn send(&mut self, msg: &dyn MessageFields, time: f64) -> Option<String> {
+        let command = command_number(msg)?;
+        if !RESTART_COMMANDS.contains(&command) {
+            self.pending.push_back(PendingCommand {
+                command,
+                sent_at: time,
+            });
+        }
+        Some(msg.to_sentence(""))
+    }
+
+    /// Matches acknowledge to the oldest pending command with the same number.
+    /// Returns command number and result, None for unexpected acknowledges.
+    pub fn update(&mut self, ack: &NmeaPmtk001Message, time: f64) -> Option<(u32, CommandResult)> {
+        let command = ack.command?;
+        let flag = ack.flag?;
+        let idx = self
+            .pending
+            .iter()
+            .position(|p| p.command == command && time - p.sent_at <= self.timeout)?;
+        self.pending.remove(idx);
+        Some((command, CommandResult::Acknowledged(flag)))
+    }
+
+    /// Removes commands not acknowledged within timeout. Returns their numbers with
+    /// [`CommandResult::TimedOut`].
+    pub fn expire(&mut self, time: f64) -> Vec<(u32, CommandResult)> {
+        let mut expired = Vec::new();
+        self.pending.retain(|p| {
+            let keep = time - p.sent_at <= self.timeout;
+            if !keep {
+                expired.push((p.command, CommandResult::TimedOut));
+            }
+            keep
+        });
+        expired
+    }
+
+    /// Number of commands waiting for acknowledge.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fields::PmtkAckFlag,
+        generated::nmea3::*,
+        messages::{AddrField, MessageFields, MessagesMap},
+        test_util::parse,
+    };
+
+    use super::{command_number, CommandResult, CommandTracker, PmtkSentence};
+
+    #[test]
+    fn pmtk220() {
+        assert_eq!(
+            NmeaPmtk220Message::set_interval(1000).to_sentence(""),
+            "$PMTK220,1000*1F\r\n"
+        );
+        assert_eq!(
+            NmeaPmtk220Message::set_rate(5.0).unwrap().to_sentence(""),
+            "$PMTK220,200*2C\r\n"
+        );
+        for hz in [0.0, -1.0, f64::NAN, f64::INFINITY, 1e-9] {
+            assert!(NmeaPmtk220Message::set_rate(hz).is_none(), "{hz}");
+        }
+    }
+
+    #[test]
+    fn pmtk314() {
+        let cmd = NmeaPmtk314Message::disable_all()
+            .with_rate(PmtkSentence::Rmc, 1)
+            .with_rate(PmtkSentence::Gga, 1)
+            .with_rate(PmtkSentence::Gsa, 1)
+            .with_rate(PmtkSentence::Gsv, 5);
+        assert_eq!(
+            cmd.to_sentence(""),
+            "$PMTK314,0,1,0,1,1,5,0,0,0,0,0,0,0,0,0,0,0,0,0*2C\r\n"
+        );
+
+        let parsed: NmeaPmtk314Message = parse("$PMTK314,0,1,0,1,1,5,0,0,0,0,0,0,0,0,0,0,0,0,0*2C");
+        assert_eq!(parsed.rate(PmtkSentence::Gsv), Some(5));
+        assert_eq!(parsed.rate(PmtkSentence::Zda), Some(0));
+
+        let defaults = NmeaPmtk314Message::defaults();
+        assert_eq!(defaults.to_sentence(""), "$PMTK314,-1*04\r\n");
+        assert_eq!(defaults.rate(PmtkSentence::Rmc), None);
+        let cmd = defaults.with_rate(PmtkSentence::Zda, 1);
+        assert_eq!(cmd.rate(PmtkSentence::Zda), Some(1));
+        assert_eq!(cmd.rate(PmtkSentence::Gll), Some(0));
+    }
+
+    #[test]
+    fn restarts() {
+        assert_eq!(NmeaPmtk101Message::new().to_sentence(""), "$PMTK101*32\r\n");
+        assert_eq!(NmeaPmtk103Message::new().to_sentence(""), "$PMTK103*30\r\n");
+        assert_eq!(command_number(&NmeaPmtk103Message::new()), Some(103));
+        let _: NmeaPmtk101Message = parse("$PMTK101*32");
+    }
+
+    #[test]
+    fn pmtk001() {
+        let ack: NmeaPmtk001Message = parse("$PMTK001,220,3*30");
+        assert_eq!(ack.command, Some(220));
+        assert!(ack.is_success());
+        let nak: NmeaPmtk001Message = parse("$PMTK001,604,1*30");
+        assert_eq!(nak.flag, Some(PmtkAckFlag::UnsupportedCommand));
+        assert!(!nak.is_success());
+    }
+
+    #[test]
+    fn messages_map_dispatch() {
+        let mut map = MessagesMap::new();
+        map.add_all_messages();
+        let addr = AddrField::new(b"PMTK001");
+        assert_eq!(addr.talker_id, "");
+        assert!(map.set_field(&addr, 0, b"314"));
+        assert!(map.set_field(&addr, 1, b"2"));
+        let msg = map.get(&addr).unwrap();
+        assert_eq!(msg.message_type(), NmeaMessages::PMTK001);
+        let ack = msg.as_any().downcast_ref::<NmeaPmtk001Message>().unwrap();
+        assert_eq!(ack.flag, Some(PmtkAckFlag::Failed));
+    }
+
+    #[test]
+    fn tracker() {
+        let mut tracker = CommandTracker::new(1.0);
+        assert_eq!(
+            tracker
+                .send(&NmeaPmtk220Message::set_interval(1000), 0.0)
+                .as_deref(),
+            Some("$PMTK220,1000*1F\r\n")
+        );
+        tracker.send(&NmeaPmtk314Message::defaults(), 0.1).unwrap();
+        assert_eq!(
+            tracker.send(&NmeaPmtk101Message::new(), 0.2).as_deref(),
+            Some("$PMTK101*32\r\n")
+        );
+        assert!(tracker.send(&NmeaGgaMessage::new(), 0.2).is_none());
+        assert_eq!(tracker.pending(), 2);
+
+        let ack: NmeaPmtk001Message = parse("$PMTK001,314,3*36");
+        assert_eq!(
+            tracker.update(&ack, 0.5),
+            Some((314, CommandResult::Acknowledged(PmtkAckFlag::Succeeded)))
+        );
+        assert_eq!(tracker.update(&ack, 0.5), None);
+
+        let ack: NmeaPmtk001Message = parse("$PMTK001,220,2*31");
+        assert_eq!(
+            tracker.update(&ack, 0.8),
+            Some((220, CommandResult::Acknowledged(PmtkAckFlag::Failed)))
+        );
+
+        assert_eq!(tracker.pending(), 0);
+        assert!(tracker.expire(1.3).is_empty());
+    }
+
+    #[test]
+    fn late_ack_is_ignored() {
+        let mut tracker = CommandTracker::new(1.0);
+        tracker.send(&NmeaPmtk220Message::set_interval(200), 0.0);
+        let ack: NmeaPmtk001Message = parse("$PMTK001,220,3*30");
+        assert_eq!(tracker.update(&ack, 2.0), None);
+        assert_eq!(tracker.expire(2.0), vec![(220, CommandResult::TimedOut)]);
+    }
+}
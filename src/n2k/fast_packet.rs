@@ -0,0 +1,201 @@
+//! Fast-packet reassembly of PGNs longer than a CAN frame.
+
+use std::collections::{HashMap, HashSet};
+
+use super::N2kPacket;
+
+/// True for standard PGNs transmitted as fast packets.
+pub fn is_fast_packet(pgn: u32) -> bool {
+    matches!(
+        pgn,
+        126208
+            | 126464
+            | 126720
+            | 126996
+            | 126998
+            | 127233
+            | 127237
+            | 127489
+            | 127496..=127498
+            | 127503..=127504
+            | 127506..=127507
+            | 127509..=127514
+            | 128275
+            | 128520
+            | 129029
+            | 129038..=129041
+            | 129044..=129045
+            | 129284..=129285
+            | 129301..=129302
+            | 129538
+            | 129540..=129542
+            | 129545
+            | 129547
+            | 129549
+            | 129551
+            | 129556
+            | 129792..=129812
+            | 130060..=130061
+            | 130064..=130074
+            | 130320..=130324
+            | 130567
+            | 130577..=130578
+            // Manufacturer proprietary fast-packet range
+            | 130816..=131071
+    )
+}
+
+/// Pending fast-packet data.
+#[derive(Debug)]
+struct PendingPacket {
+    len: usize,
+    data: Vec<u8>,
+    next_frame: u8,
+}
+
+/// Reassembles fast-packet PGNs from CAN frames. Frame groups of different sources and
+/// sequence counters may be interleaved.
+#[derive(Debug, Default)]
+pub struct FastPacketAssembler {
+    /// Proprietary or non-standard PGNs transmitted as fast packets.
+    extra_pgns: HashSet<u32>,
+    pending: HashMap<(u32, u8, u8), PendingPacket>,
+}
+
+impl FastPacketAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treats PGN as fast packet in addition to standard ones.
+    pub fn register(&mut self, pgn: u32) {
+        self.extra_pgns.insert(pgn);
+    }
+
+    /// Adds packet. Returns packets of single frame PGNs and packets already reassembled by
+    /// gateway unchanged, fast packets when the last frame is received.
+    /// Frames received out of order drop the packet being assembled.
+    pub fn update(&mut self, packet: N2kPacket) -> Option<N2kPacket> {
+        let fast = is_fast_packet(packet.pgn) || self.extra_pgns.contains(&packet.pgn);
+        if !fast || !packet.single_frame {
+            return Some(packet);
+        }
+        let header = *packet.data.first()?;
+        let sequence = header >> 5;
+        let frame = header & 0x1F;
+        let key = (packet.pgn, packet.source, sequence);
+        if frame == 0 {
+            let len = *packet.data.get(1)? as usize;
+            self.pending.insert(
+                key,
+                PendingPacket {
+                    len,
+                    data: Vec::with_capacity(len),
+                    next_frame: 0,
+                },
+            );
+        }
+        let pending = self.pending.get_mut(&key)?;
+        if pending.next_frame != frame {
+            self.pending.remove(&key);
+            return None;
+        }
+        let start = if frame == 0 { 2 } else { 1 };
+        pending.data.extend_from_slice(packet.data.get(start..)?);
+        pending.next_frame += 1;
+
+        if pending.data.len() >= pending.len {
+            let mut pending = self.pending.remove(&key)?;
+            pending.data.truncate(pending.len);
+            return Some(N2kPacket {
+                data: pending.data,
+                single_frame: false,
+                ..packet
+            });
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FastPacketAssembler, N2kPacket};
+
+    /// Splits data into fast-packet frames with sequence counter.
+    fn frames(pgn: u32, sequence: u8, data: &[u8]) -> Vec<N2kPacket> {
+        let mut frames = vec![[&[sequence << 5, data.len() as u8], &data[..6]].concat()];
+        for (i, chunk) in data[6..].chunks(7).enumerate() {
+            let mut frame = vec![(sequence << 5) | (i as u8 + 1)];
+            frame.extend_from_slice(chunk);
+            frame.resize(8, 0xFF);
+            frames.push(frame);
+        }
+        frames
+            .into_iter()
+            .map(|data| N2kPacket {
+                priority: Some(3),
+                pgn,
+                source: 7,
+                destination: 255,
+                data,
+                single_frame: true,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reassembly() {
+        let data: Vec<u8> = (0..43).collect();
+        let mut assembler = FastPacketAssembler::new();
+        let frames = frames(129029, 2, &data);
+        assert_eq!(frames.len(), 7);
+        for frame in &frames[..6] {
+            assert!(assembler.update(frame.clone()).is_none());
+        }
+        let packet = assembler.update(frames[6].clone()).unwrap();
+        assert_eq!(packet.pgn, 129029);
+        assert_eq!(packet.source, 7);
+        assert_eq!(packet.data, data);
+    }
+
+    #[test]
+    fn interleaved_and_out_of_order() {
+        let data: Vec<u8> = (0..20).collect();
+        let mut assembler = FastPacketAssembler::new();
+        let a = frames(129029, 1, &data);
+        let b = frames(129029, 2, &data);
+        assert!(assembler.update(a[0].clone()).is_none());
+        assert!(assembler.update(b[0].clone()).is_none());
+        assert!(assembler.update(a[1].clone()).is_none());
+        // Frame 2 of group b is lost
+        assert!(assembler.update(b[2].clone()).is_none());
+        assert_eq!(assembler.update(a[2].clone()).unwrap().data, data);
+        assert!(assembler.update(b[1].clone()).is_none());
+    }
+
+    #[test]
+    fn complete_packet() {
+        // Actisense and $PCDIN forward fast packets reassembled, even if they fit into 8 bytes
+        let packet = N2kPacket {
+            priority: Some(3),
+            pgn: 129029,
+            source: 7,
+            destination: 255,
+            data: vec![0x20, 0x2B, 0, 1, 2, 3],
+            single_frame: false,
+        };
+        let mut assembler = FastPacketAssembler::new();
+        assert_eq!(assembler.update(packet.clone()), Some(packet));
+    }
+
+    #[test]
+    fn registered_pgn() {
+        let data: Vec<u8> = (0..10).collect();
+        let mut assembler = FastPacketAssembler::new();
+        let f = frames(65280, 0, &data);
+        assert_eq!(assembler.update(f[0].clone()).unwrap(), f[0]);
+        assembler.register(65280);
+        assert!(assembler.update(f[0].clone()).is_none());
+        assert_eq!(assembler.update(f[1].clone()).unwrap().data, data);
+    }
+}
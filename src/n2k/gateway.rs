@@ -0,0 +1,135 @@
+//! Text formats of NMEA 2000 gateways: Yacht Devices RAW, Actisense ASCII and SeaSmart `$PCDIN`.
+
+use crate::{generated::nmea3::NmeaPcdinMessage, parse_into};
+
+use super::{N2kPacket, GLOBAL_ADDRESS};
+
+/// Parses hex digit pairs, optionally separated by whitespace.
+fn hex_bytes(s: &str) -> Option<Vec<u8>> {
+    let digits: Vec<u8> = s.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    if !digits.len().is_multiple_of(2) {
+        return None;
+    }
+    digits
+        .chunks(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
+
+/// Parses Yacht Devices RAW line of a CAN frame, e.g.
+/// `17:33:21.107 R 09F80123 87 1C D6 23 4F 18 3D F1`.
+/// Direction is R for received and T for transmitted frames.
+pub fn parse_ydraw(line: &str) -> Option<N2kPacket> {
+    let mut parts = line.split_whitespace();
+    let _time = parts.next()?;
+    if !matches!(parts.next()?, "R" | "T") {
+        return None;
+    }
+    let id = u32::from_str_radix(parts.next()?, 16).ok()?;
+    let data = parts
+        .map(|b| u8::from_str_radix(b, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    if id > 0x1FFF_FFFF || data.len() > 8 {
+        return None;
+    }
+    Some(N2kPacket::from_can_id(id, data))
+}
+
+/// Parses Actisense ASCII line of a complete PGN, e.g.
+/// `A173321.107 23FF7 1F513 012F3070002F30709F`.
+/// Second group holds source, destination and priority as hex digits SSDDP.
+pub fn parse_actisense(line: &str) -> Option<N2kPacket> {
+    let mut parts = line.split_whitespace();
+    parts.next()?.strip_prefix('A')?;
+    let header = parts.next()?;
+    if header.len() != 5 {
+        return None;
+    }
+    let pgn = u32::from_str_radix(parts.next()?, 16).ok()?;
+    let data = hex_bytes(parts.next()?)?;
+    Some(N2kPacket {
+        priority: Some(u8::from_str_radix(header.get(4..5)?, 16).ok()?),
+        pgn,
+        source: u8::from_str_radix(header.get(0..2)?, 16).ok()?,
+        destination: u8::from_str_radix(header.get(2..4)?, 16).ok()?,
+        data,
+        single_frame: false,
+    })
+}
+
+impl NmeaPcdinMessage {
+    /// Complete PGN. Priority and destination are not transmitted.
+    pub fn packet(&self) -> Option<N2kPacket> {
+        Some(N2kPacket {
+            priority: None,
+            pgn: u32::from_str_radix(self.pgn.as_deref()?, 16).ok()?,
+            source: u8::from_str_radix(self.source.as_deref()?, 16).ok()?,
+            destination: GLOBAL_ADDRESS,
+            data: hex_bytes(self.data.as_deref()?)?,
+            single_frame: false,
+        })
+    }
+}
+
+/// Parses line of any supported gateway format. `$PCDIN` sentences shall have valid checksum.
+pub fn parse_line(line: &str) -> Option<N2kPacket> {
+    let line = line.trim();
+    if line.starts_with("$PCDIN") {
+        // The sentence parser expects a checksummed sentence of more than 10 bytes
+        let has_crc = line.len() > 10 && line.as_bytes()[line.len() - 3] == b'*';
+        if !has_crc {
+            return None;
+        }
+        let mut pcdin = NmeaPcdinMessage::default();
+        let (_, m) = parse_into(line.as_bytes(), &mut pcdin);
+        if !m.crc_ok {
+            return None;
+        }
+        return pcdin.packet();
+    }
+    if line.starts_with('A') {
+        return parse_actisense(line);
+    }
+    parse_ydraw(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_actisense, parse_line, parse_ydraw};
+
+    #[test]
+    fn ydraw() {
+        let p = parse_ydraw("17:33:21.107 R 09F80123 87 1C D6 23 4F 18 3D F1").unwrap();
+        assert_eq!(p.pgn, 129025);
+        assert_eq!(p.priority, Some(2));
+        assert_eq!(p.source, 0x23);
+        assert_eq!(p.data, [0x87, 0x1C, 0xD6, 0x23, 0x4F, 0x18, 0x3D, 0xF1]);
+        assert!(p.single_frame);
+        assert!(parse_ydraw("17:33:21.107 X 09F80123 87").is_none());
+        assert!(parse_ydraw("17:33:21.107 R 09F80123 87 1C D6 23 4F 18 3D F1 00").is_none());
+    }
+
+    #[test]
+    fn actisense() {
+        let p = parse_actisense("A173321.107 23FF3 1F50B 01D20400000CFEFF").unwrap();
+        assert_eq!(p.pgn, 128267);
+        assert_eq!(p.priority, Some(3));
+        assert_eq!(p.source, 0x23);
+        assert_eq!(p.destination, 0xFF);
+        assert_eq!(p.data, [0x01, 0xD2, 0x04, 0x00, 0x00, 0x0C, 0xFE, 0xFF]);
+        assert!(!p.single_frame);
+        assert!(parse_actisense("A173321.107 23FF3 1F50B 01D").is_none());
+    }
+
+    #[test]
+    fn pcdin() {
+        let p = parse_line("$PCDIN,01FD02,000C72EA,09,000802AE1EFAFFFF*57\r\n").unwrap();
+        assert_eq!(p.pgn, 130306);
+        assert_eq!(p.priority, None);
+        assert_eq!(p.source, 9);
+        assert_eq!(p.data.len(), 8);
+        assert!(parse_line("$PCDIN,01FD02,000C72EA,09,000802AE1EFAFFFF*58").is_none());
+        assert!(parse_line("$PCDIN").is_none());
+        assert!(parse_line("$PCDIN,01FD02,000C72EA,09,000802AE1EFAFFFF").is_none());
+    }
+}
@@ -0,0 +1,145 @@
+//! NMEA 2000 PGNs forwarded by gateways as text (Yacht Devices RAW, Actisense ASCII, `$PCDIN`):
+//! fast-packet reassembly and decoding into typed quantities.
+
+mod fast_packet;
+mod gateway;
+mod pgn;
+
+pub use fast_packet::{is_fast_packet, FastPacketAssembler};
+pub use gateway::{parse_actisense, parse_line, parse_ydraw};
+pub use pgn::{
+    CogSogRapidUpdate, PositionRapidUpdate, VesselHeading, WaterDepth, WindData, WindDataReference,
+};
+
+/// Broadcast destination address.
+pub const GLOBAL_ADDRESS: u8 = 255;
+
+/// PGN data with its CAN header fields. Data of fast-packet PGNs received as CAN frames
+/// (Yacht Devices RAW) is a single frame until reassembled by [FastPacketAssembler].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct N2kPacket {
+    /// Priority (0-7). `None` if gateway format does not transmit it.
+    pub priority: Option<u8>,
+    pub pgn: u32,
+    pub source: u8,
+    pub destination: u8,
+    pub data: Vec<u8>,
+    /// Data is a single CAN frame, which is a part of fast packet for fast-packet PGNs.
+    /// False for complete PGNs (Actisense, `$PCDIN`) and reassembled fast packets.
+    pub single_frame: bool,
+}
+
+impl N2kPacket {
+    /// Packet of a single CAN frame. Splits 29-bit CAN identifier into priority, PGN, source
+    /// and destination. PDU1 PGNs (PF < 240) carry destination address in the PS byte.
+    pub fn from_can_id(id: u32, data: Vec<u8>) -> Self {
+        let pf = (id >> 16) & 0xFF;
+        let ps = (id >> 8) & 0xFF;
+        let (pgn, destination) = if pf < 240 {
+            ((id >> 8) & 0x3FF00, ps as u8)
+        } else {
+            ((id >> 8) & 0x3FFFF, GLOBAL_ADDRESS)
+        };
+        N2kPacket {
+            priority: Some(((id >> 26) & 0x7) as u8),
+            pgn,
+            source: (id & 0xFF) as u8,
+            destination,
+            data,
+            single_frame: true,
+        }
+    }
+}
+
+/// Decoded PGN.
+#[derive(Debug, Clone, PartialEq)]
+pub enum N2kMessage {
+    /// PGN 129025.
+    PositionRapidUpdate(PositionRapidUpdate),
+    /// PGN 129026.
+    CogSogRapidUpdate(CogSogRapidUpdate),
+    /// PGN 127250.
+    VesselHeading(VesselHeading),
+    /// PGN 128267.
+    WaterDepth(WaterDepth),
+    /// PGN 130306.
+    WindData(WindData),
+}
+
+impl N2kMessage {
+    pub fn pgn(&self) -> u32 {
+        match self {
+            N2kMessage::PositionRapidUpdate(_) => 129025,
+            N2kMessage::CogSogRapidUpdate(_) => 129026,
+            N2kMessage::VesselHeading(_) => 127250,
+            N2kMessage::WaterDepth(_) => 128267,
+            N2kMessage::WindData(_) => 130306,
+        }
+    }
+}
+
+/// Decodes complete PGN data. None for unsupported PGNs and malformed data.
+pub fn decode(packet: &N2kPacket) -> Option<N2kMessage> {
+    let data = &packet.data;
+    match packet.pgn {
+        129025 => PositionRapidUpdate::decode(data).map(N2kMessage::PositionRapidUpdate),
+        129026 => CogSogRapidUpdate::decode(data).map(N2kMessage::CogSogRapidUpdate),
+        127250 => VesselHeading::decode(data).map(N2kMessage::VesselHeading),
+        128267 => WaterDepth::decode(data).map(N2kMessage::WaterDepth),
+        130306 => WindData::decode(data).map(N2kMessage::WindData),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, parse_line, FastPacketAssembler, N2kMessage, N2kPacket};
+
+    #[test]
+    fn can_id() {
+        let p = N2kPacket::from_can_id(0x09F80123, vec![]);
+        assert_eq!(p.priority, Some(2));
+        assert_eq!(p.pgn, 129025);
+        assert_eq!(p.source, 0x23);
+        assert_eq!(p.destination, 255);
+
+        // ISO request (PDU1) addressed to 0x05
+        let p = N2kPacket::from_can_id(0x18EA0523, vec![]);
+        assert_eq!(p.priority, Some(6));
+        assert_eq!(p.pgn, 59904);
+        assert_eq!(p.destination, 0x05);
+    }
+
+    #[test]
+    fn same_quantities_from_all_gateways() {
+        let mut assembler = FastPacketAssembler::new();
+        let lines = [
+            "17:33:21.107 R 09F80223 01 FC 21 54 69 02 FF FF",
+            "A173321.107 23FF2 1F802 01FC21546902FFFF",
+            "$PCDIN,01F802,000C72EA,23,01FC21546902FFFF*55",
+        ];
+        for line in lines {
+            let packet = assembler.update(parse_line(line).unwrap()).unwrap();
+            assert_eq!(packet.source, 0x23);
+            let Some(N2kMessage::CogSogRapidUpdate(m)) = decode(&packet) else {
+                panic!("{line}")
+            };
+            assert!((m.cog.unwrap() - 123.4).abs() < 0.01);
+            assert!((m.sog.unwrap().knots() - 11.99).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn short_fast_packet_from_gateway() {
+        // Proprietary fast-packet PGN 130816 which fits into a single frame
+        let mut assembler = FastPacketAssembler::new();
+        for line in [
+            "A173321.107 23FF3 1FF00 3F9F0102",
+            "$PCDIN,01FF00,000C72EA,23,3F9F0102*2B",
+        ] {
+            let packet = assembler.update(parse_line(line).unwrap()).unwrap();
+            assert_eq!(packet.pgn, 130816);
+            assert_eq!(packet.data, [0x3F, 0x9F, 0x01, 0x02], "{line}");
+        }
+    }
+}
@@ -0,0 +1,312 @@
+//! Decoders of navigation PGNs. Fields with all bits set (not available) or the next lower
+//! value (out of range) decode to None.
+
+use crate::fields::{Distance, DistanceUnit, HeadingReference, Speed, SpeedUnit, WindReference};
+
+fn u8_at(data: &[u8], i: usize) -> Option<u8> {
+    data.get(i).copied().filter(|v| *v < 0xFE)
+}
+
+fn u16_at(data: &[u8], i: usize) -> Option<u16> {
+    let v = u16::from_le_bytes(data.get(i..i + 2)?.try_into().ok()?);
+    (v < 0xFFFE).then_some(v)
+}
+
+fn i16_at(data: &[u8], i: usize) -> Option<i16> {
+    let v = i16::from_le_bytes(data.get(i..i + 2)?.try_into().ok()?);
+    (v < 0x7FFE).then_some(v)
+}
+
+fn u32_at(data: &[u8], i: usize) -> Option<u32> {
+    let v = u32::from_le_bytes(data.get(i..i + 4)?.try_into().ok()?);
+    (v < 0xFFFF_FFFE).then_some(v)
+}
+
+fn i32_at(data: &[u8], i: usize) -> Option<i32> {
+    let v = i32::from_le_bytes(data.get(i..i + 4)?.try_into().ok()?);
+    (v < 0x7FFF_FFFE).then_some(v)
+}
+
+/// Angle of 1e-4 radians resolution in degrees.
+fn degrees(raw: f64) -> f64 {
+    (raw * 1e-4).to_degrees()
+}
+
+/// True or magnetic reference of 2 bits.
+fn heading_reference(value: u8) -> Option<HeadingReference> {
+    match value & 0x03 {
+        0 => Some(HeadingReference::True),
+        1 => Some(HeadingReference::Magnetic),
+        _ => None,
+    }
+}
+
+/// PGN 129025: position, rapid update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionRapidUpdate {
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+}
+
+impl PositionRapidUpdate {
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        if data.len() < 8 {
+            return None;
+        }
+        Some(PositionRapidUpdate {
+            latitude: i32_at(data, 0).map(|v| v as f64 * 1e-7),
+            longitude: i32_at(data, 4).map(|v| v as f64 * 1e-7),
+        })
+    }
+
+    /// Position as (latitude, longitude) in decimal degrees.
+    pub fn position(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+}
+
+/// PGN 129026: course and speed over ground, rapid update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CogSogRapidUpdate {
+    /// Sequence identifier relating PGNs of the same measurement.
+    pub sid: Option<u8>,
+    pub reference: Option<HeadingReference>,
+    /// Course over ground, degrees.
+    pub cog: Option<f64>,
+    pub sog: Option<Speed>,
+}
+
+impl CogSogRapidUpdate {
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        if data.len() < 6 {
+            return None;
+        }
+        Some(CogSogRapidUpdate {
+            sid: u8_at(data, 0),
+            reference: heading_reference(data[1]),
+            cog: u16_at(data, 2).map(|v| degrees(v as f64)),
+            sog: u16_at(data, 4).map(|v| Speed::new(v as f64 * 0.01, SpeedUnit::MetersPerSecond)),
+        })
+    }
+}
+
+/// PGN 127250: vessel heading.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VesselHeading {
+    pub sid: Option<u8>,
+    /// Heading, degrees.
+    pub heading: Option<f64>,
+    /// Magnetic deviation, degrees. Positive is easterly.
+    pub deviation: Option<f64>,
+    /// Magnetic variation, degrees. Positive is easterly.
+    pub variation: Option<f64>,
+    pub reference: Option<HeadingReference>,
+}
+
+impl VesselHeading {
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        if data.len() < 8 {
+            return None;
+        }
+        Some(VesselHeading {
+            sid: u8_at(data, 0),
+            heading: u16_at(data, 1).map(|v| degrees(v as f64)),
+            deviation: i16_at(data, 3).map(|v| degrees(v as f64)),
+            variation: i16_at(data, 5).map(|v| degrees(v as f64)),
+            reference: heading_reference(data[7]),
+        })
+    }
+
+    /// True heading, applying deviation and variation to magnetic heading.
+    pub fn true_heading(&self) -> Option<f64> {
+        match self.reference? {
+            HeadingReference::True => self.heading,
+            HeadingReference::Magnetic => Some(
+                (self.heading? + self.deviation.unwrap_or(0.0) + self.variation?).rem_euclid(360.0),
+            ),
+        }
+    }
+}
+
+/// PGN 128267: water depth.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaterDepth {
+    pub sid: Option<u8>,
+    /// Depth below transducer.
+    pub depth: Option<Distance>,
+    /// Distance from transducer to waterline (positive) or keel (negative).
+    pub offset: Option<Distance>,
+    /// Maximum depth range of the sounder.
+    pub range: Option<Distance>,
+}
+
+impl WaterDepth {
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        if data.len() < 7 {
+            return None;
+        }
+        let meters = |v: f64| Distance::new(v, DistanceUnit::Meters);
+        Some(WaterDepth {
+            sid: u8_at(data, 0),
+            depth: u32_at(data, 1).map(|v| meters(v as f64 * 0.01)),
+            offset: i16_at(data, 5).map(|v| meters(v as f64 * 0.001)),
+            range: u8_at(data, 7).map(|v| meters(v as f64 * 10.0)),
+        })
+    }
+
+    /// Depth below surface. Available if offset is positive or zero, as for DPT.
+    pub fn depth_below_surface(&self) -> Option<Distance> {
+        let offset = self.offset.filter(|o| o.meters() >= 0.0)?;
+        Some(Distance::new(
+            self.depth?.meters() + offset.meters(),
+            DistanceUnit::Meters,
+        ))
+    }
+
+    /// Depth below keel. Available if offset is negative, as for DPT.
+    pub fn depth_below_keel(&self) -> Option<Distance> {
+        let offset = self.offset.filter(|o| o.meters() < 0.0)?;
+        Some(Distance::new(
+            self.depth?.meters() + offset.meters(),
+            DistanceUnit::Meters,
+        ))
+    }
+}
+
+/// Wind reference of PGN 130306.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindDataReference {
+    /// True wind direction relative to true north.
+    TrueNorth,
+    /// True wind direction relative to magnetic north.
+    MagneticNorth,
+    /// Apparent wind relative to vessel.
+    Apparent,
+    /// True wind relative to vessel, calculated using speed over ground.
+    TrueGround,
+    /// True wind relative to vessel, calculated using speed through water.
+    TrueWater,
+}
+
+impl WindDataReference {
+    pub fn from_value(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(WindDataReference::TrueNorth),
+            1 => Some(WindDataReference::MagneticNorth),
+            2 => Some(WindDataReference::Apparent),
+            3 => Some(WindDataReference::TrueGround),
+            4 => Some(WindDataReference::TrueWater),
+            _ => None,
+        }
+    }
+
+    /// Reference of MWV sentence. None for directions relative to north (MWD).
+    pub fn wind_reference(&self) -> Option<WindReference> {
+        match self {
+            WindDataReference::Apparent => Some(WindReference::Relative),
+            WindDataReference::TrueGround | WindDataReference::TrueWater => {
+                Some(WindReference::True)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// PGN 130306: wind data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindData {
+    pub sid: Option<u8>,
+    pub speed: Option<Speed>,
+    /// Wind angle relative to reference, degrees.
+    pub angle: Option<f64>,
+    pub reference: Option<WindDataReference>,
+}
+
+impl WindData {
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        if data.len() < 6 {
+            return None;
+        }
+        Some(WindData {
+            sid: u8_at(data, 0),
+            speed: u16_at(data, 1).map(|v| Speed::new(v as f64 * 0.01, SpeedUnit::MetersPerSecond)),
+            angle: u16_at(data, 3).map(|v| degrees(v as f64)),
+            reference: WindDataReference::from_value(data[5] & 0x07),
+        })
+    }
+
+    /// True for apparent (relative to vessel) wind, as for MWV.
+    pub fn is_apparent(&self) -> bool {
+        self.reference == Some(WindDataReference::Apparent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fields::{HeadingReference, WindReference};
+
+    use super::{
+        CogSogRapidUpdate, PositionRapidUpdate, VesselHeading, WaterDepth, WindData,
+        WindDataReference,
+    };
+
+    #[test]
+    fn position_rapid_update() {
+        let m =
+            PositionRapidUpdate::decode(&[0x87, 0x1C, 0xD6, 0x23, 0x4F, 0x18, 0x3D, 0xF1]).unwrap();
+        let (lat, lon) = m.position().unwrap();
+        assert!((lat - 60.1234567).abs() < 1e-9);
+        assert!((lon + 24.7654321).abs() < 1e-9);
+        let m = PositionRapidUpdate::decode(&[0xFF, 0xFF, 0xFF, 0x7F, 0, 0, 0, 0]).unwrap();
+        assert_eq!(m.latitude, None);
+        assert_eq!(m.position(), None);
+    }
+
+    #[test]
+    fn cog_sog() {
+        let m =
+            CogSogRapidUpdate::decode(&[0x01, 0xFC, 0x21, 0x54, 0x69, 0x02, 0xFF, 0xFF]).unwrap();
+        assert_eq!(m.sid, Some(1));
+        assert_eq!(m.reference, Some(HeadingReference::True));
+        assert!((m.cog.unwrap() - 123.4).abs() < 0.01);
+        assert!((m.sog.unwrap().meters_per_second() - 6.17).abs() < 1e-9);
+    }
+
+    #[test]
+    fn vessel_heading() {
+        let m = VesselHeading::decode(&[0xFF, 0x6B, 0xB8, 0xFF, 0x7F, 0xD1, 0xFD, 0xFD]).unwrap();
+        assert_eq!(m.sid, None);
+        assert_eq!(m.reference, Some(HeadingReference::Magnetic));
+        assert!((m.heading.unwrap() - 270.5).abs() < 0.01);
+        assert_eq!(m.deviation, None);
+        assert!((m.variation.unwrap() + 3.2).abs() < 0.01);
+        assert!((m.true_heading().unwrap() - 267.3).abs() < 0.01);
+    }
+
+    #[test]
+    fn water_depth() {
+        let m = WaterDepth::decode(&[0x01, 0xD2, 0x04, 0x00, 0x00, 0x0C, 0xFE, 0xFF]).unwrap();
+        assert!((m.depth.unwrap().meters() - 12.34).abs() < 1e-9);
+        assert!((m.offset.unwrap().meters() + 0.5).abs() < 1e-9);
+        assert_eq!(m.range, None);
+        assert!((m.depth_below_keel().unwrap().meters() - 11.84).abs() < 1e-9);
+        assert_eq!(m.depth_below_surface(), None);
+
+        let m = WaterDepth::decode(&[0x01, 0xD2, 0x04, 0x00, 0x00, 0x00, 0x00, 0xFF]).unwrap();
+        assert!((m.depth_below_surface().unwrap().meters() - 12.34).abs() < 1e-9);
+        assert_eq!(m.depth_below_keel(), None);
+    }
+
+    #[test]
+    fn wind_data() {
+        let m = WindData::decode(&[0x00, 0x08, 0x02, 0xAE, 0x1E, 0xFA, 0xFF, 0xFF]).unwrap();
+        assert!((m.speed.unwrap().meters_per_second() - 5.2).abs() < 1e-9);
+        assert!((m.angle.unwrap() - 45.0).abs() < 0.01);
+        assert_eq!(m.reference, Some(WindDataReference::Apparent));
+        assert_eq!(
+            m.reference.unwrap().wind_reference(),
+            Some(WindReference::Relative)
+        );
+        assert!(m.is_apparent());
+    }
+}
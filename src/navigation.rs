@@ -0,0 +1,353 @@
+//! Route and waypoint navigation helpers: waypoint positions, cross-track error and routes assembled from RTE.
+
+use crate::{
+    fields::{nmea_to_degrees, DataStatus, Distance, DistanceUnit, RouteType, SteerDirection},
+    generated::nmea3::{
+        NmeaAamMessage, NmeaApbMessage, NmeaBwcMessage, NmeaRmbMessage, NmeaRteMessage,
+        NmeaWplMessage, NmeaXteMessage,
+    },
+    messages::MessageFields,
+};
+
+/// Max sentence length including start char and CRLF.
+const MAX_SENTENCE_LEN: usize = 82;
+
+/// Converts coordinate fields to signed decimal degrees.
+fn degrees(value: Option<f64>, dir: Option<u8>) -> Option<f64> {
+    Some(nmea_to_degrees(value?, dir?))
+}
+
+/// Cross-track error with sign: negative if vessel shall steer left (it is right of the track).
+fn signed_xte(
+    xte: Option<f64>,
+    steer: Option<SteerDirection>,
+    unit: DistanceUnit,
+) -> Option<Distance> {
+    let xte = match steer? {
+        SteerDirection::Left => -xte?,
+        SteerDirection::Right => xte?,
+    };
+    Some(Distance::new(xte, unit))
+}
+
+impl NmeaWplMessage {
+    /// Waypoint position as (latitude, longitude) in decimal degrees.
+    pub fn position(&self) -> Option<(f64, f64)> {
+        Some((
+            degrees(self.latitude, self.latitude_dir)?,
+            degrees(self.longitude, self.longitude_dir)?,
+        ))
+    }
+}
+
+impl NmeaRmbMessage {
+    /// Destination waypoint position as (latitude, longitude) in decimal degrees.
+    pub fn destination(&self) -> Option<(f64, f64)> {
+        Some((
+            degrees(self.destination_latitude, self.destination_latitude_dir)?,
+            degrees(self.destination_longitude, self.destination_longitude_dir)?,
+        ))
+    }
+
+    /// Signed cross-track error, see [`NmeaXteMessage::xte`].
+    pub fn xte(&self) -> Option<Distance> {
+        signed_xte(self.xte, self.steer, DistanceUnit::NauticalMiles)
+    }
+
+    pub fn range(&self) -> Option<Distance> {
+        Some(Distance::new(self.range?, DistanceUnit::NauticalMiles))
+    }
+
+    pub fn is_arrived(&self) -> bool {
+        self.arrival_status == Some(DataStatus::Valid)
+    }
+}
+
+impl NmeaApbMessage {
+    /// Signed cross-track error, see [`NmeaXteMessage::xte`].
+    pub fn xte(&self) -> Option<Distance> {
+        signed_xte(self.xte, self.steer, self.xte_unit?)
+    }
+}
+
+impl NmeaXteMessage {
+    /// Signed cross-track error: negative if vessel shall steer left. `None` if any status is warning.
+    pub fn xte(&self) -> Option<Distance> {
+        if self.status_warning != Some(DataStatus::Valid)
+            || self.status_lock != Some(DataStatus::Valid)
+        {
+            return None;
+        }
+        signed_xte(self.xte, self.steer, self.xte_unit?)
+    }
+}
+
+impl NmeaBwcMessage {
+    /// Waypoint position as (latitude, longitude) in decimal degrees.
+    pub fn waypoint(&self) -> Option<(f64, f64)> {
+        Some((
+            degrees(self.waypoint_latitude, self.waypoint_latitude_dir)?,
+            degrees(self.waypoint_longitude, self.waypoint_longitude_dir)?,
+        ))
+    }
+
+    pub fn distance(&self) -> Option<Distance> {
+        Distance::from_fields(self.distance, self.distance_unit)
+    }
+}
+
+impl NmeaAamMessage {
+    pub fn radius(&self) -> Option<Distance> {
+        Distance::from_fields(self.radius, self.radius_unit)
+    }
+
+    /// Arrival circle entered or perpendicular passed.
+    pub fn is_arrived(&self) -> bool {
+        self.arrival_circle == Some(DataStatus::Valid)
+            || self.perpendicular_passed == Some(DataStatus::Valid)
+    }
+}
+
+/// Route with list of waypoint IDs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub id: Option<String>,
+    pub route_type: RouteType,
+    pub waypoints: Vec<String>,
+}
+
+impl Route {
+    /// Encodes route into RTE sentences. Waypoints are split so each sentence fits 82 chars.
+    pub fn to_sentences(&self, talker_id: &str) -> Vec<String> {
+        let mut parts: Vec<Vec<String>> = vec![Vec::new()];
+        // Sentence without waypoints: "$ttRTE,n,n,c,id*hh\r\n", message numbers up to 2 digits
+        let base_len =
+            1 + talker_id.len() + 3 + 6 + 2 + 1 + self.id.as_deref().unwrap_or("").len() + 5;
+        let mut len = base_len;
+        for wp in &self.waypoints {
+            let last = parts.last_mut().unwrap();
+            if len + 1 + wp.len() > MAX_SENTENCE_LEN && !last.is_empty() {
+                parts.push(Vec::new());
+                len = base_len;
+            }
+            parts.last_mut().unwrap().push(wp.clone());
+            len += 1 + wp.len();
+        }
+
+        let total = parts.len() as u32;
+        parts
+            .into_iter()
+            .enumerate()
+            .map(|(i, waypoints)| {
+                let mut rte = NmeaRteMessage::new();
+                rte.total_messages = Some(total);
+                rte.message_number = Some(i as u32 + 1);
+                rte.route_type = Some(self.route_type);
+                rte.route_id = self.id.clone();
+                rte.waypoints = waypoints;
+                rte.to_sentence(talker_id)
+            })
+            .collect()
+    }
+}
+
+/// Assembles routes from multi-sentence RTE groups.
+#[derive(Debug, Default)]
+pub struct RouteAssembler {
+    pending: Option<Route>,
+    /// Number of next expected message in group.
+    next_message: u32,
+}
+
+impl RouteAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds RTE part. Returns route when the last part is received.
+    /// Parts received out of order drop the route being assembled.
+    pub fn update(&mut self, rte: &NmeaRteMessage) -> Option<Route> {
+        let (Some(total), Some(number)) = (rte.total_messages, rte.message_number) else {
+            return None;
+        };
+
+        if number == 1 {
+            self.pending = Some(Route {
+                id: rte.route_id.clone(),
+                route_type: rte.route_type.unwrap_or(RouteType::Complete),
+                waypoints: Vec::new(),
+            });
+        } else if number != self.next_message
+            || self.pending.as_ref().map(|r| &r.id) != Some(&rte.route_id)
+        {
+            self.pending = None;
+            return None;
+        }
+
+        let route = self.pending.as_mut()?;
+        route.waypoints.extend(rte.waypoints.iter().cloned());
+        self.next_message = number + 1;
+
+        if number == total {
+            return self.pending.take();
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fields::{
+            DataStatus, DistanceUnit, HeadingReference, PositionMode, RouteType, SteerDirection,
+        },
+        generated::nmea3::*,
+        messages::MessageFields,
+        test_util::parse,
+    };
+
+    use super::{Route, RouteAssembler};
+
+    #[test]
+    fn rmb() {
+        let rmb: NmeaRmbMessage =
+            parse("$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20");
+        assert_eq!(rmb.status, Some(DataStatus::Valid));
+        assert_eq!(rmb.steer, Some(SteerDirection::Left));
+        assert_eq!(rmb.origin_id.as_deref(), Some("003"));
+        assert_eq!(rmb.destination_id.as_deref(), Some("004"));
+        let (lat, lon) = rmb.destination().unwrap();
+        assert!((lat - 49.287333).abs() < 1e-6);
+        assert!((lon + 123.1595).abs() < 1e-6);
+        assert_eq!(rmb.xte().unwrap().value, -0.66);
+        assert!((rmb.range().unwrap().meters() - 1.3 * 1852.0).abs() < 1e-6);
+        assert_eq!(rmb.bearing, Some(52.5));
+        assert_eq!(rmb.closing_velocity, Some(0.5));
+        assert!(!rmb.is_arrived());
+        assert_eq!(rmb.mode, None);
+    }
+
+    #[test]
+    fn apb() {
+        let apb: NmeaApbMessage = parse("$GPAPB,A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M*3C");
+        assert_eq!(apb.xte().unwrap().value, 0.1);
+        assert_eq!(apb.xte_unit, Some(DistanceUnit::NauticalMiles));
+        assert_eq!(
+            apb.bearing_origin_to_destination_ref,
+            Some(HeadingReference::Magnetic)
+        );
+        assert_eq!(apb.destination_id.as_deref(), Some("DEST"));
+        assert_eq!(apb.heading_to_steer, Some(11.0));
+    }
+
+    #[test]
+    fn bod() {
+        let bod: NmeaBodMessage = parse("$GPBOD,099.3,T,105.6,M,POINTB,POINTA*45");
+        assert_eq!(bod.bearing_true, Some(99.3));
+        assert_eq!(bod.bearing_magnetic, Some(105.6));
+        assert_eq!(bod.destination_id.as_deref(), Some("POINTB"));
+        assert_eq!(bod.origin_id.as_deref(), Some("POINTA"));
+    }
+
+    #[test]
+    fn bwc() {
+        let bwc: NmeaBwcMessage =
+            parse("$GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*21");
+        let (lat, lon) = bwc.waypoint().unwrap();
+        assert!((lat - 51.500333).abs() < 1e-6);
+        assert!((lon + 0.772333).abs() < 1e-6);
+        assert_eq!(bwc.bearing_true, Some(213.8));
+        assert_eq!(bwc.distance().unwrap().unit, DistanceUnit::NauticalMiles);
+        assert_eq!(bwc.waypoint_id.as_deref(), Some("EGLM"));
+    }
+
+    #[test]
+    fn xte() {
+        let xte: NmeaXteMessage = parse("$GPXTE,A,A,0.67,L,N*6F");
+        assert_eq!(xte.xte().unwrap().value, -0.67);
+        let xte: NmeaXteMessage = parse("$GPXTE,V,A,0.67,L,N,A*15");
+        assert_eq!(xte.xte(), None);
+        assert_eq!(xte.mode, Some(PositionMode::Autonomous));
+    }
+
+    #[test]
+    fn wpl() {
+        let wpl: NmeaWplMessage = parse("$GPWPL,4917.16,N,12310.64,W,003*65");
+        let (lat, lon) = wpl.position().unwrap();
+        assert!((lat - 49.286).abs() < 1e-6);
+        assert!((lon + 123.177333).abs() < 1e-6);
+        assert_eq!(wpl.waypoint_id.as_deref(), Some("003"));
+    }
+
+    #[test]
+    fn aam() {
+        let aam: NmeaAamMessage = parse("$GPAAM,A,A,0.10,N,WPTNME*32");
+        assert!(aam.is_arrived());
+        assert_eq!(aam.radius().unwrap().value, 0.1);
+        assert_eq!(aam.waypoint_id.as_deref(), Some("WPTNME"));
+    }
+
+    #[test]
+    fn re_emit_sentences() {
+        let wpl: NmeaWplMessage = parse("$GPWPL,4917.16,N,12310.64,W,003*65");
+        assert_eq!(
+            wpl.to_sentence("GP"),
+            "$GPWPL,4917.16,N,12310.64,W,003*65\r\n"
+        );
+
+        // Numbers keep fixed decimals of the sentence format
+        let aam: NmeaAamMessage = parse("$GPAAM,A,A,0.10,N,WPTNME*32");
+        assert_eq!(aam.to_sentence("EC"), "$ECAAM,A,A,0.10,N,WPTNME*23\r\n");
+
+        let bod: NmeaBodMessage = parse("$GPBOD,099.3,T,105.6,M,POINTB,POINTA*45");
+        let reparsed: NmeaBodMessage = parse(&bod.to_sentence("GP"));
+        assert_eq!(reparsed.bearing_true, bod.bearing_true);
+        assert_eq!(reparsed.origin_id, bod.origin_id);
+    }
+
+    #[test]
+    fn route_assembled_from_parts() {
+        let mut assembler = RouteAssembler::new();
+        let rte: NmeaRteMessage = parse(
+            "$GPRTE,2,1,c,0,PBRCPK,PBRTO,PTELGR,PPLAND,PYAMBU,PPFAIR,PWARRN,PMORTL,PLISMR*73",
+        );
+        assert_eq!(assembler.update(&rte), None);
+        let rte: NmeaRteMessage = parse("$GPRTE,2,2,c,0,PCRESY,GRYRIE,GCORIO,GWERR,GWESTG,7FED*34");
+        let route = assembler.update(&rte).unwrap();
+        assert_eq!(route.id.as_deref(), Some("0"));
+        assert_eq!(route.route_type, RouteType::Complete);
+        assert_eq!(route.waypoints.len(), 15);
+        assert_eq!(route.waypoints[0], "PBRCPK");
+        assert_eq!(route.waypoints[14], "7FED");
+    }
+
+    #[test]
+    fn route_part_out_of_order() {
+        let mut assembler = RouteAssembler::new();
+        let rte: NmeaRteMessage = parse("$GPRTE,2,2,c,0,PCRESY,GRYRIE,GCORIO,GWERR,GWESTG,7FED*34");
+        assert_eq!(assembler.update(&rte), None);
+        let rte: NmeaRteMessage = parse("$GPRTE,1,1,w,R1,A,B*43");
+        let route = assembler.update(&rte).unwrap();
+        assert_eq!(route.route_type, RouteType::Working);
+        assert_eq!(route.waypoints, vec!["A", "B"]);
+    }
+
+    #[test]
+    fn route_to_sentences() {
+        let route = Route {
+            id: Some("0".to_string()),
+            route_type: RouteType::Complete,
+            waypoints: (0..30).map(|i| format!("WAYPT{i:02}")).collect(),
+        };
+        let sentences = route.to_sentences("GP");
+        assert!(sentences.len() > 1);
+
+        let mut assembler = RouteAssembler::new();
+        let mut assembled = None;
+        for s in &sentences {
+            assert!(s.len() <= 82, "{s}");
+            assembled = assembler.update(&parse::<NmeaRteMessage>(s));
+        }
+        assert_eq!(assembled, Some(route));
+    }
+}
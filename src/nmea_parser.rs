@@ -12,21 +12,22 @@ use nmeaParseTest::{get_message_body, HandleField};
 pub struct NmeaParser {}
 
 /// Message parsed callback.
-/// Parameter can be downcasted to concrete message class based on msg_type:
+/// Parameter can be downcasted to concrete message class based on msg_type.
+/// Talker id (e.g. 'GP') is empty for messages without talker.
 ///
 /// # Example
 /// ```no_run
-///    fn callback(msg_type: NmeaMessages, msg: &dyn Any) -> () {
+///    fn callback(msg_type: NmeaMessages, talker_id: &str, msg: &dyn Any) {
 ///    match msg_type {
 ///        NmeaMessages::GLL => {
 ///            let gll = msg.downcast_ref::<NmeaGllMessage>().unwrap();
-///            println!("{:?}", gll);
+///            println!("{talker_id} {:?}", gll);
 ///        }
-///        _ => panic!(),
+///        _ => {}
 ///    }
 ///}
 /// ```
-type FnMsgParsed = dyn Fn(NmeaMessages, &dyn Any) -> ();
+type FnMsgParsed = dyn Fn(NmeaMessages, &str, &dyn Any);
 
 pub struct FieldParseHandler<'a> {
     all_messages: &'a mut MessagesMap,
@@ -47,7 +48,7 @@ impl NmeaParser {
                 if buf.is_empty() {
                     break;
                 };
-                let (consume_amt, msg) = get_message_body(&buf, &mut h);
+                let (consume_amt, msg) = get_message_body(buf, &mut h);
                 println!("Consumed {consume_amt} chars. CRC ok: {:?}", msg.crc_ok);
                 consume_amt
             };
@@ -69,20 +70,18 @@ impl<'a> FieldParseHandler<'a> {
 
 /// Trait as a callback for field parsing events.
 /// Shall detect message type by addr field and set it's field value.
+/// Messages not present in the map are skipped.
 impl<'a> HandleField for FieldParseHandler<'a> {
     fn handle(&mut self, addr_field: &AddrField<'_>, field_idx: u8, field: &[u8]) {
-        let boxed_msg = self.all_messages.get_mut(addr_field).unwrap();
-
-        boxed_msg.set_field(field_idx, field);
-
-        println!("Field {:?} from {:?}", field_idx, boxed_msg.field_count());
+        self.all_messages.set_field(addr_field, field_idx, field);
+    }
 
-        if field_idx == boxed_msg.field_count() - 1 {
-            // Last field parsed, notify listeners
-            let boxed_msg = self.all_messages.get(&addr_field).unwrap();
+    fn message_end(&mut self, addr_field: &AddrField<'_>, _crc_ok: bool) {
+        // All fields parsed, notify listeners
+        if let Some(boxed_msg) = self.all_messages.get(addr_field) {
             let msg_type = boxed_msg.message_type();
             let orig_msg: &dyn Any = boxed_msg.as_any();
-            (self.callback)(msg_type, orig_msg);
+            (self.callback)(msg_type, addr_field.talker_id, orig_msg);
         }
     }
 }
@@ -0,0 +1,245 @@
+//! Steering and propulsion helpers: valid rudder angles, RPM and demands by engine, shaft or thruster.
+
+use crate::{
+    fields::{DataStatus, DemandMode, PitchMode, RpmSource},
+    generated::nmea3::{
+        NmeaEtlMessage, NmeaPrcMessage, NmeaRorMessage, NmeaRpmMessage, NmeaRsaMessage,
+        NmeaTrcMessage,
+    },
+};
+
+/// Value if status is valid.
+fn valid(value: Option<f64>, status: Option<DataStatus>) -> Option<f64> {
+    match status? {
+        DataStatus::Valid => value,
+        DataStatus::Invalid => None,
+    }
+}
+
+/// Side of engine or shaft derived from its number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// Single or centre-line unit (number 0).
+    Centre,
+    /// Odd numbers.
+    Starboard,
+    /// Even numbers.
+    Port,
+}
+
+impl Side {
+    pub fn from_number(number: u32) -> Self {
+        match number {
+            0 => Side::Centre,
+            n if n % 2 == 1 => Side::Starboard,
+            _ => Side::Port,
+        }
+    }
+}
+
+/// Propulsion unit identified by number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PropulsionUnit {
+    Engine(u32),
+    Shaft(u32),
+    /// Odd numbers are bow thrusters, even - stern thrusters.
+    Thruster(u32),
+}
+
+impl PropulsionUnit {
+    pub fn number(&self) -> u32 {
+        match self {
+            PropulsionUnit::Engine(n) | PropulsionUnit::Shaft(n) | PropulsionUnit::Thruster(n) => {
+                *n
+            }
+        }
+    }
+}
+
+impl NmeaRsaMessage {
+    /// Starboard (or single) rudder angle, degrees. Negative when bow turns to port.
+    pub fn starboard_angle(&self) -> Option<f64> {
+        valid(self.starboard, self.starboard_status)
+    }
+
+    /// Port rudder angle, degrees.
+    pub fn port_angle(&self) -> Option<f64> {
+        valid(self.port, self.port_status)
+    }
+}
+
+impl NmeaRorMessage {
+    /// Starboard (or single) rudder order, degrees. Negative when bow turns to port.
+    pub fn starboard_order(&self) -> Option<f64> {
+        valid(self.starboard, self.starboard_status)
+    }
+
+    /// Port rudder order, degrees.
+    pub fn port_order(&self) -> Option<f64> {
+        valid(self.port, self.port_status)
+    }
+}
+
+impl NmeaRpmMessage {
+    pub fn unit(&self) -> Option<PropulsionUnit> {
+        let number = self.number?;
+        Some(match self.source? {
+            RpmSource::Engine => PropulsionUnit::Engine(number),
+            RpmSource::Shaft => PropulsionUnit::Shaft(number),
+        })
+    }
+
+    pub fn side(&self) -> Option<Side> {
+        self.number.map(Side::from_number)
+    }
+
+    /// Revolutions per minute if status is valid. Negative when counter-clockwise.
+    pub fn rpm(&self) -> Option<f64> {
+        valid(self.speed, self.status)
+    }
+
+    /// Propeller pitch, % of max, if status is valid. Negative when astern.
+    pub fn pitch_percent(&self) -> Option<f64> {
+        valid(self.pitch, self.status)
+    }
+}
+
+/// Demand value unless mode is invalid.
+fn demand<M: Copy + PartialEq>(
+    value: Option<f64>,
+    mode: Option<M>,
+    invalid: M,
+) -> Option<(f64, M)> {
+    let mode = mode.filter(|m| *m != invalid)?;
+    Some((value?, mode))
+}
+
+impl NmeaPrcMessage {
+    pub fn side(&self) -> Option<Side> {
+        self.number.map(Side::from_number)
+    }
+
+    /// Lever position, % (-100..100), if status is valid. Negative when astern.
+    pub fn lever_position(&self) -> Option<f64> {
+        valid(self.lever, self.lever_status)
+    }
+
+    pub fn rpm(&self) -> Option<(f64, DemandMode)> {
+        demand(self.rpm_demand, self.rpm_mode, DemandMode::Invalid)
+    }
+
+    pub fn pitch(&self) -> Option<(f64, PitchMode)> {
+        demand(self.pitch_demand, self.pitch_mode, PitchMode::Invalid)
+    }
+}
+
+impl NmeaTrcMessage {
+    pub fn unit(&self) -> Option<PropulsionUnit> {
+        self.number.map(PropulsionUnit::Thruster)
+    }
+
+    pub fn rpm(&self) -> Option<(f64, DemandMode)> {
+        demand(self.rpm_demand, self.rpm_mode, DemandMode::Invalid)
+    }
+
+    pub fn pitch(&self) -> Option<(f64, PitchMode)> {
+        demand(self.pitch_demand, self.pitch_mode, PitchMode::Invalid)
+    }
+}
+
+impl NmeaEtlMessage {
+    pub fn side(&self) -> Option<Side> {
+        self.number.map(Side::from_number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fields::{
+            DemandMode, OperatingLocation, PitchMode, SentenceStatus, SubTelegraph,
+            TelegraphMessageType, TelegraphPosition,
+        },
+        generated::nmea3::*,
+        messages::MessageFields,
+        test_util::parse,
+    };
+
+    use super::{PropulsionUnit, Side};
+
+    #[test]
+    fn rsa_ror() {
+        let rsa: NmeaRsaMessage = parse("$IIRSA,10.5,A,-3.2,A*58");
+        assert_eq!(rsa.starboard_angle(), Some(10.5));
+        assert_eq!(rsa.port_angle(), Some(-3.2));
+        let rsa: NmeaRsaMessage = parse("$IIRSA,-7.0,A,,V*53");
+        assert_eq!(rsa.starboard_angle(), Some(-7.0));
+        assert_eq!(rsa.port_angle(), None);
+
+        let ror: NmeaRorMessage = parse("$AGROR,-12.0,A,,V,B*00");
+        assert_eq!(ror.starboard_order(), Some(-12.0));
+        assert_eq!(ror.port_order(), None);
+        assert_eq!(ror.location, Some(OperatingLocation::Bridge));
+    }
+
+    #[test]
+    fn rpm() {
+        let rpm: NmeaRpmMessage = parse("$IIRPM,E,1,2418.2,10.5,A*5F");
+        assert_eq!(rpm.unit(), Some(PropulsionUnit::Engine(1)));
+        assert_eq!(rpm.side(), Some(Side::Starboard));
+        assert_eq!(rpm.rpm(), Some(2418.2));
+        assert_eq!(rpm.pitch_percent(), Some(10.5));
+
+        let rpm: NmeaRpmMessage = parse("$IIRPM,S,2,-95.0,-40.0,A*4B");
+        assert_eq!(rpm.unit(), Some(PropulsionUnit::Shaft(2)));
+        assert_eq!(rpm.side(), Some(Side::Port));
+        assert_eq!(rpm.rpm(), Some(-95.0));
+        assert_eq!(rpm.pitch_percent(), Some(-40.0));
+
+        let rpm: NmeaRpmMessage = parse("$IIRPM,S,0,0.0,,V*78");
+        assert_eq!(rpm.side(), Some(Side::Centre));
+        assert_eq!(rpm.rpm(), None);
+    }
+
+    #[test]
+    fn prc() {
+        let prc: NmeaPrcMessage = parse("$IIPRC,45.0,A,80.0,P,12.5,D,B,2*75");
+        assert_eq!(prc.lever_position(), Some(45.0));
+        assert_eq!(prc.rpm(), Some((80.0, DemandMode::Percent)));
+        assert_eq!(prc.pitch(), Some((12.5, PitchMode::Degrees)));
+        assert_eq!(prc.location, Some(OperatingLocation::Bridge));
+        assert_eq!(prc.side(), Some(Side::Port));
+
+        let prc: NmeaPrcMessage = parse("$IIPRC,-30.0,A,,V,,V,E,0*45");
+        assert_eq!(prc.lever_position(), Some(-30.0));
+        assert_eq!(prc.rpm(), None);
+        assert_eq!(prc.pitch(), None);
+    }
+
+    #[test]
+    fn trc() {
+        let trc: NmeaTrcMessage = parse("$IITRC,1,60.0,P,10.0,D,270.0,B,R*5C");
+        assert_eq!(trc.unit(), Some(PropulsionUnit::Thruster(1)));
+        assert_eq!(trc.rpm(), Some((60.0, DemandMode::Percent)));
+        assert_eq!(trc.pitch(), Some((10.0, PitchMode::Degrees)));
+        assert_eq!(trc.azimuth_demand, Some(270.0));
+        assert_eq!(trc.sentence_status, Some(SentenceStatus::Reply));
+    }
+
+    #[test]
+    fn etl() {
+        let etl: NmeaEtlMessage = parse("$IIETL,083015.00,O,03,20,B,1*41");
+        assert_eq!(etl.message_type, Some(TelegraphMessageType::Order));
+        assert_eq!(etl.position, Some(TelegraphPosition::AheadHalf));
+        assert_eq!(etl.position.unwrap().step(), 3);
+        assert_eq!(etl.sub_position, Some(SubTelegraph::StandBy));
+        assert_eq!(etl.side(), Some(Side::Starboard));
+
+        let etl: NmeaEtlMessage = parse("$IIETL,083020.00,A,15,,E,1*4B");
+        assert_eq!(etl.message_type, Some(TelegraphMessageType::AnswerBack));
+        assert_eq!(etl.position.unwrap().step(), -5);
+        assert_eq!(etl.sub_position, None);
+        assert_eq!(TelegraphPosition::Stop.step(), 0);
+        assert_eq!(etl.to_sentence("II"), "$IIETL,083020.00,A,15,,E,1*4B\r\n");
+    }
+}
@@ -0,0 +1,295 @@
+//! Radar\ARPA helpers and table of tracked targets assembled from TTM and TLL messages.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    fields::{
+        nmea_to_degrees, AcquisitionType, BearingReference, DataStatus, Distance, Speed, SpeedUnit,
+        TargetReference, TargetStatus,
+    },
+    generated::nmea3::{NmeaOsdMessage, NmeaRsdMessage, NmeaTllMessage, NmeaTtmMessage},
+};
+
+impl NmeaTtmMessage {
+    pub fn distance(&self) -> Option<Distance> {
+        Distance::from_fields(self.distance, self.unit)
+    }
+
+    pub fn speed(&self) -> Option<Speed> {
+        Speed::from_fields(self.speed, SpeedUnit::per_hour(self.unit?))
+    }
+
+    /// Distance of closest point of approach.
+    pub fn cpa(&self) -> Option<Distance> {
+        Distance::from_fields(self.cpa, self.unit)
+    }
+}
+
+impl NmeaTllMessage {
+    /// Target position as (latitude, longitude) in decimal degrees.
+    pub fn position(&self) -> Option<(f64, f64)> {
+        Some((
+            nmea_to_degrees(self.latitude?, self.latitude_dir?),
+            nmea_to_degrees(self.longitude?, self.longitude_dir?),
+        ))
+    }
+}
+
+impl NmeaOsdMessage {
+    /// Heading if status is valid.
+    pub fn valid_heading(&self) -> Option<f64> {
+        match self.heading_status? {
+            DataStatus::Valid => self.heading,
+            DataStatus::Invalid => None,
+        }
+    }
+
+    pub fn speed(&self) -> Option<Speed> {
+        Speed::from_fields(self.speed, self.speed_unit)
+    }
+
+    pub fn drift(&self) -> Option<Speed> {
+        Speed::from_fields(self.drift, self.speed_unit)
+    }
+}
+
+impl NmeaRsdMessage {
+    pub fn range_scale(&self) -> Option<Distance> {
+        Distance::from_fields(self.range_scale, self.range_unit)
+    }
+}
+
+/// Radar target state merged from TTM and TLL messages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Target {
+    pub number: u32,
+    pub name: Option<String>,
+    pub status: TargetStatus,
+    pub reference: bool,
+    pub acquisition: Option<AcquisitionType>,
+    pub distance: Option<Distance>,
+    pub bearing: Option<f64>,
+    pub bearing_ref: Option<BearingReference>,
+    pub speed: Option<Speed>,
+    pub course: Option<f64>,
+    pub course_ref: Option<BearingReference>,
+    pub cpa: Option<Distance>,
+    /// Time to CPA, minutes.
+    pub tcpa: Option<f64>,
+    /// Position as (latitude, longitude) in decimal degrees. Is set by TLL.
+    pub position: Option<(f64, f64)>,
+    /// Time of last update, hhmmss.ss.
+    pub utc: Option<String>,
+}
+
+impl Target {
+    fn new(number: u32, status: TargetStatus) -> Self {
+        Target {
+            number,
+            name: None,
+            status,
+            reference: false,
+            acquisition: None,
+            distance: None,
+            bearing: None,
+            bearing_ref: None,
+            speed: None,
+            course: None,
+            course_ref: None,
+            cpa: None,
+            tcpa: None,
+            position: None,
+            utc: None,
+        }
+    }
+}
+
+/// Table of radar targets by target number.
+/// Targets are added by the first TTM\TLL message and removed when radar reports them as lost.
+#[derive(Debug, Default)]
+pub struct TargetTable {
+    targets: BTreeMap<u32, Target>,
+}
+
+impl TargetTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates target from TTM. Returns updated target or `None` if target is lost.
+    pub fn update_ttm(&mut self, ttm: &NmeaTtmMessage) -> Option<&Target> {
+        let target = self.entry(ttm.target_number?, ttm.status)?;
+        if ttm.name.is_some() {
+            target.name.clone_from(&ttm.name);
+        }
+        target.reference = ttm.reference == Some(TargetReference::Reference);
+        target.acquisition = ttm.acquisition.or(target.acquisition);
+        target.distance = ttm.distance();
+        target.bearing = ttm.bearing;
+        target.bearing_ref = ttm.bearing_ref;
+        target.speed = ttm.speed();
+        target.course = ttm.course;
+        target.course_ref = ttm.course_ref;
+        target.cpa = ttm.cpa();
+        target.tcpa = ttm.tcpa;
+        if ttm.utc.is_some() {
+            target.utc.clone_from(&ttm.utc);
+        }
+        Some(target)
+    }
+
+    /// Updates target position from TLL. Returns updated target or `None` if target is lost.
+    pub fn update_tll(&mut self, tll: &NmeaTllMessage) -> Option<&Target> {
+        let target = self.entry(tll.target_number?, tll.status)?;
+        if tll.name.is_some() {
+            target.name.clone_from(&tll.name);
+        }
+        target.reference = tll.reference == Some(TargetReference::Reference);
+        target.position = tll.position();
+        if tll.utc.is_some() {
+            target.utc.clone_from(&tll.utc);
+        }
+        Some(target)
+    }
+
+    /// Returns target for update, or removes it if status is lost.
+    fn entry(&mut self, number: u32, status: Option<TargetStatus>) -> Option<&mut Target> {
+        let status = status.unwrap_or(TargetStatus::Tracking);
+        if status == TargetStatus::Lost {
+            self.targets.remove(&number);
+            return None;
+        }
+        let target = self
+            .targets
+            .entry(number)
+            .or_insert_with(|| Target::new(number, status));
+        target.status = status;
+        Some(target)
+    }
+
+    pub fn get(&self, number: u32) -> Option<&Target> {
+        self.targets.get(&number)
+    }
+
+    /// Targets sorted by number.
+    pub fn targets(&self) -> impl Iterator<Item = &Target> {
+        self.targets.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fields::{
+            AcquisitionType, BearingReference, DisplayRotation, DistanceUnit, SpeedReference,
+            SpeedUnit, TargetStatus,
+        },
+        generated::nmea3::{NmeaOsdMessage, NmeaRsdMessage, NmeaTllMessage, NmeaTtmMessage},
+        test_util::parse,
+    };
+
+    use super::TargetTable;
+
+    #[test]
+    fn ttm() {
+        let ttm: NmeaTtmMessage =
+            parse("$RATTM,11,0.78,355.4,T,2.7,190.7,T,0.49,10.9,N,TGT11,T,,160511.00,A*75");
+        assert_eq!(ttm.target_number, Some(11));
+        assert_eq!(ttm.bearing_ref, Some(BearingReference::True));
+        assert_eq!(ttm.distance().unwrap().unit, DistanceUnit::NauticalMiles);
+        assert_eq!(ttm.speed().unwrap().unit, SpeedUnit::Knots);
+        assert_eq!(ttm.speed().unwrap().value, 2.7);
+        assert_eq!(ttm.cpa().unwrap().value, 0.49);
+        assert_eq!(ttm.tcpa, Some(10.9));
+        assert_eq!(ttm.name.as_deref(), Some("TGT11"));
+        assert_eq!(ttm.status, Some(TargetStatus::Tracking));
+        assert_eq!(ttm.reference, None);
+        assert_eq!(ttm.acquisition, Some(AcquisitionType::Automatic));
+    }
+
+    #[test]
+    fn tll() {
+        let tll: NmeaTllMessage = parse("$RATLL,01,5603.370,N,00948.680,E,TGT01,161229.00,T,*6D");
+        let (lat, lon) = tll.position().unwrap();
+        assert!((lat - 56.0561667).abs() < 1e-6);
+        assert!((lon - 9.8113333).abs() < 1e-6);
+        assert_eq!(tll.status, Some(TargetStatus::Tracking));
+    }
+
+    #[test]
+    fn osd() {
+        let osd: NmeaOsdMessage = parse("$RAOSD,35.1,A,36.0,P,10.2,P,15.3,0.0,N*40");
+        assert_eq!(osd.valid_heading(), Some(35.1));
+        assert_eq!(osd.course_ref, Some(SpeedReference::Positioning));
+        assert_eq!(osd.speed().unwrap().knots(), 10.2);
+        assert_eq!(osd.set, Some(15.3));
+        assert_eq!(osd.drift().unwrap().value, 0.0);
+    }
+
+    #[test]
+    fn rsd() {
+        let rsd: NmeaRsdMessage =
+            parse("$RARSD,0.00,0.00,2.50,005.0,0.00,0.00,4.50,355.0,1.1,82.3,6.0,N,N*6B");
+        assert_eq!(rsd.vrm1, Some(2.5));
+        assert_eq!(rsd.ebl2, Some(355.0));
+        assert_eq!(rsd.cursor_bearing, Some(82.3));
+        assert_eq!(rsd.range_scale().unwrap().unit, DistanceUnit::NauticalMiles);
+        assert_eq!(rsd.rotation, Some(DisplayRotation::NorthUp));
+    }
+
+    #[test]
+    fn target_table_merges_updates() {
+        let mut table = TargetTable::new();
+        let target = table
+            .update_ttm(&parse(
+                "$RATTM,01,1.20,045.0,T,5.1,270.0,T,0.30,12.5,N,,Q,,120000.00,M*3A",
+            ))
+            .unwrap();
+        assert_eq!(target.status, TargetStatus::Query);
+        assert_eq!(target.name, None);
+
+        table.update_ttm(&parse(
+            "$RATTM,01,1.10,046.0,T,5.2,271.0,T,0.25,11.0,N,FERRY,T,R,120010.00,M*36",
+        ));
+        table.update_tll(&parse("$RATLL,01,5603.370,N,00948.680,E,,120010.00,T,R*74"));
+        table.update_ttm(&parse(
+            "$RATTM,02,3.00,180.0,T,0.0,000.0,T,3.00,0.0,N,BUOY,T,,120010.00,A*0F",
+        ));
+        assert_eq!(table.len(), 2);
+
+        let target = table.get(1).unwrap();
+        assert_eq!(target.status, TargetStatus::Tracking);
+        assert_eq!(target.name.as_deref(), Some("FERRY"));
+        assert!(target.reference);
+        assert_eq!(target.acquisition, Some(AcquisitionType::Manual));
+        assert_eq!(target.distance.unwrap().value, 1.1);
+        assert_eq!(target.course, Some(271.0));
+        assert!(target.position.is_some());
+        assert_eq!(target.utc.as_deref(), Some("120010.00"));
+
+        let numbers: Vec<u32> = table.targets().map(|t| t.number).collect();
+        assert_eq!(numbers, vec![1, 2]);
+    }
+
+    #[test]
+    fn lost_target_removed() {
+        let mut table = TargetTable::new();
+        table.update_ttm(&parse(
+            "$RATTM,02,3.00,180.0,T,0.0,000.0,T,3.00,0.0,N,BUOY,T,,120010.00,A*0F",
+        ));
+        assert!(table
+            .update_ttm(&parse(
+                "$RATTM,02,3.00,180.0,T,0.0,000.0,T,3.00,0.0,N,BUOY,L,,120020.00,A*14",
+            ))
+            .is_none());
+        assert!(table.is_empty());
+    }
+}
@@ -0,0 +1,436 @@
+//! Satellites in view assembled from GSV groups and GSA active satellites.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    fields::GnssSystem,
+    generated::nmea3::{NmeaGsaMessage, NmeaGsvMessage},
+};
+
+/// Satellite info from a GSV message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GsvSatellite {
+    pub prn: u32,
+    /// Elevation, degrees (0-90).
+    pub elevation: Option<i32>,
+    /// Azimuth, degrees True (0-359).
+    pub azimuth: Option<u32>,
+    /// Signal to noise ratio, dB-Hz. Empty when satellite is not tracked.
+    pub snr: Option<u32>,
+}
+
+impl NmeaGsvMessage {
+    /// Satellites listed in this message (up to 4).
+    pub fn satellites(&self) -> Vec<GsvSatellite> {
+        self.satellites
+            .chunks_exact(4)
+            .filter_map(|s| {
+                Some(GsvSatellite {
+                    prn: s[0].parse().ok()?,
+                    elevation: s[1].parse().ok(),
+                    azimuth: s[2].parse().ok(),
+                    snr: s[3].parse().ok(),
+                })
+            })
+            .collect()
+    }
+
+    /// Signal ID (NMEA v4.10+), transmitted as a hex digit after the satellites.
+    pub fn signal_id(&self) -> Option<u8> {
+        if self.satellites.len() % 4 != 1 {
+            return None;
+        }
+        u8::from_str_radix(self.satellites.last()?, 16).ok()
+    }
+}
+
+impl NmeaGsaMessage {
+    /// IDs of satellites used in fix.
+    pub fn prns(&self) -> Vec<u32> {
+        [
+            self.prn_1,
+            self.prn_2,
+            self.prn_3,
+            self.prn_4,
+            self.prn_5,
+            self.prn_6,
+            self.prn_7,
+            self.prn_8,
+            self.prn_9,
+            self.prn_10,
+            self.prn_11,
+            self.prn_12,
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// Satellite in a sky view snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Satellite {
+    pub prn: u32,
+    pub constellation: GnssSystem,
+    /// Signal ID (NMEA v4.10+). `None` for older receivers.
+    pub signal_id: Option<u8>,
+    pub elevation: Option<i32>,
+    pub azimuth: Option<u32>,
+    pub snr: Option<u32>,
+    /// Satellite is listed in GSA of the same constellation.
+    pub used_in_fix: bool,
+}
+
+/// GSV groups are sent separately for each constellation and (since NMEA v4.10) signal.
+type GroupKey = (GnssSystem, Option<u8>);
+
+/// GSV group being received. Constellation is `None` for combined GN talker.
+#[derive(Debug)]
+struct PendingGroup {
+    satellites: Vec<GsvSatellite>,
+    next_message: u32,
+}
+
+/// Satellites in view by constellation.
+/// Feed with GSV and GSA messages as they come, read consistent snapshots after GSV group is complete.
+/// Report cycle ends when a group (or GSA of a constellation) is received again: groups and
+/// constellations not reported within the cycle are removed.
+#[derive(Debug, Default)]
+pub struct SkyView {
+    /// Satellites from last complete GSV group.
+    in_view: HashMap<GroupKey, Vec<GsvSatellite>>,
+    /// GSV groups being received.
+    pending: HashMap<(Option<GnssSystem>, Option<u8>), PendingGroup>,
+    /// GSV groups completed in current report cycle.
+    in_view_reported: HashSet<GroupKey>,
+    /// Satellites used in fix by constellation.
+    used: HashMap<GnssSystem, Vec<u32>>,
+    /// Constellations of GSA received in current report cycle.
+    /// `None` stands for GN talker GSA without system id and satellites.
+    used_reported: HashSet<Option<GnssSystem>>,
+}
+
+impl SkyView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates satellites used in fix.
+    /// Constellation is taken from system id (NMEA v4.10+) or talker id.
+    /// For combined GN talker without system id satellites are split by PRN ranges.
+    pub fn update_gsa(&mut self, talker_id: &str, gsa: &NmeaGsaMessage) {
+        let prns = gsa.prns();
+        let mut by_system: HashMap<GnssSystem, Vec<u32>> = HashMap::new();
+        match gsa.system_id.or(GnssSystem::from_talker_id(talker_id)) {
+            Some(system) => {
+                by_system.insert(system, prns);
+            }
+            None => {
+                for prn in prns {
+                    if let Some(system) = GnssSystem::from_nmea_prn(prn) {
+                        by_system.entry(system).or_default().push(prn);
+                    }
+                }
+            }
+        }
+
+        let mut reported: Vec<Option<GnssSystem>> = by_system.keys().copied().map(Some).collect();
+        if reported.is_empty() {
+            reported.push(None);
+        }
+        if reported.iter().any(|r| self.used_reported.contains(r)) {
+            let cycle = std::mem::take(&mut self.used_reported);
+            self.used.retain(|system, _| cycle.contains(&Some(*system)));
+        }
+        self.used_reported.extend(reported);
+        self.used.extend(by_system);
+    }
+
+    /// Adds satellites from GSV message to current group.
+    /// Returns true when the last message of a group is received and snapshot is updated.
+    /// Messages received out of order drop the group being assembled.
+    /// For combined GN talker satellites are split by PRN ranges.
+    pub fn update_gsv(&mut self, talker_id: &str, gsv: &NmeaGsvMessage) -> bool {
+        let (Some(number), Some(total)) = (gsv.message_number, gsv.total_messages) else {
+            return false;
+        };
+        let system = GnssSystem::from_talker_id(talker_id);
+        let signal_id = gsv.signal_id();
+        let pending_key = (system, signal_id);
+
+        if number == 1 {
+            self.pending.insert(
+                pending_key,
+                PendingGroup {
+                    satellites: Vec::new(),
+                    next_message: 1,
+                },
+            );
+        }
+        let Some(pending) = self.pending.get_mut(&pending_key) else {
+            return false;
+        };
+        if pending.next_message != number {
+            self.pending.remove(&pending_key);
+            return false;
+        }
+        pending.satellites.extend(gsv.satellites());
+        pending.next_message = number + 1;
+        if number < total {
+            return false;
+        }
+        let Some(group) = self.pending.remove(&pending_key) else {
+            return false;
+        };
+
+        let mut groups: HashMap<GroupKey, Vec<GsvSatellite>> = HashMap::new();
+        match system {
+            Some(system) => {
+                groups.insert((system, signal_id), group.satellites);
+            }
+            None => {
+                for sat in group.satellites {
+                    if let Some(system) = GnssSystem::from_nmea_prn(sat.prn) {
+                        groups.entry((system, signal_id)).or_default().push(sat);
+                    }
+                }
+            }
+        }
+        if groups.keys().any(|key| self.in_view_reported.contains(key)) {
+            let cycle = std::mem::take(&mut self.in_view_reported);
+            self.in_view.retain(|key, _| cycle.contains(key));
+        }
+        self.in_view_reported.extend(groups.keys().copied());
+        self.in_view.extend(groups);
+        true
+    }
+
+    /// Constellations with satellites in view.
+    pub fn constellations(&self) -> Vec<GnssSystem> {
+        let mut res: Vec<GnssSystem> = Vec::new();
+        for (system, _) in self.in_view.keys() {
+            if !res.contains(system) {
+                res.push(*system);
+            }
+        }
+        res
+    }
+
+    /// Satellites in view of the constellation sorted by PRN and signal ID.
+    pub fn snapshot(&self, constellation: GnssSystem) -> Vec<Satellite> {
+        let used = self.used.get(&constellation);
+        let mut res: Vec<Satellite> = self
+            .in_view
+            .iter()
+            .filter(|((system, _), _)| *system == constellation)
+            .flat_map(|((_, signal_id), sats)| {
+                sats.iter().map(move |s| Satellite {
+                    prn: s.prn,
+                    constellation,
+                    signal_id: *signal_id,
+                    elevation: s.elevation,
+                    azimuth: s.azimuth,
+                    snr: s.snr,
+                    used_in_fix: used.is_some_and(|u| u.contains(&s.prn)),
+                })
+            })
+            .collect();
+        res.sort_by_key(|s| (s.prn, s.signal_id));
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fields::GnssSystem,
+        generated::nmea3::{NmeaGsaMessage, NmeaGsvMessage},
+        test_util::{parse, parse_with_talker},
+    };
+
+    use super::{GsvSatellite, SkyView};
+
+    fn feed(view: &mut SkyView, sentences: &[&str]) -> bool {
+        let mut complete = false;
+        for s in sentences {
+            if s.get(3..6) == Some("GSV") {
+                let (gsv, talker_id) = parse_with_talker::<NmeaGsvMessage>(s);
+                complete = view.update_gsv(&talker_id, &gsv);
+            } else {
+                let (gsa, talker_id) = parse_with_talker::<NmeaGsaMessage>(s);
+                view.update_gsa(&talker_id, &gsa);
+            }
+        }
+        complete
+    }
+
+    #[test]
+    fn gsv_satellites_and_signal_id() {
+        let gsv: NmeaGsvMessage = parse("$GPGSV,3,3,10,27,10,302,,16,04,036,,1*66");
+        assert_eq!(gsv.total_messages, Some(3));
+        assert_eq!(gsv.message_number, Some(3));
+        assert_eq!(gsv.satellites_in_view, Some(10));
+        assert_eq!(
+            gsv.satellites(),
+            vec![
+                GsvSatellite {
+                    prn: 27,
+                    elevation: Some(10),
+                    azimuth: Some(302),
+                    snr: None
+                },
+                GsvSatellite {
+                    prn: 16,
+                    elevation: Some(4),
+                    azimuth: Some(36),
+                    snr: None
+                },
+            ]
+        );
+        assert_eq!(gsv.signal_id(), Some(1));
+    }
+
+    #[test]
+    fn gsv_without_signal_id() {
+        let gsv: NmeaGsvMessage = parse("$GPGSV,1,1,02,02,45,120,38,05,12,310,21*77");
+        assert_eq!(gsv.satellites().len(), 2);
+        assert_eq!(gsv.signal_id(), None);
+    }
+
+    #[test]
+    fn sky_view_nmea_4_10() {
+        let mut view = SkyView::new();
+        let complete = feed(
+            &mut view,
+            &[
+                "$GNGSA,A,3,23,29,07,08,09,18,26,,,,,,1.94,1.18,1.54,1*04",
+                "$GNGSA,A,3,67,68,,,,,,,,,,,1.94,1.18,1.54,2*09",
+                "$GPGSV,3,1,10,23,38,230,44,29,71,156,47,07,29,116,41,08,09,081,36,1*62",
+                "$GPGSV,3,2,10,09,27,198,42,18,51,303,46,26,52,046,45,10,04,175,,1*67",
+            ],
+        );
+        assert!(!complete);
+        assert!(view.snapshot(GnssSystem::Gps).is_empty());
+
+        assert!(feed(
+            &mut view,
+            &["$GPGSV,3,3,10,27,10,302,,16,04,036,,1*66"]
+        ));
+        let gps = view.snapshot(GnssSystem::Gps);
+        assert_eq!(gps.len(), 10);
+        assert_eq!(gps.iter().filter(|s| s.used_in_fix).count(), 7);
+        let sv23 = gps.iter().find(|s| s.prn == 23).unwrap();
+        assert_eq!(sv23.elevation, Some(38));
+        assert_eq!(sv23.azimuth, Some(230));
+        assert_eq!(sv23.snr, Some(44));
+        assert_eq!(sv23.signal_id, Some(1));
+        assert!(sv23.used_in_fix);
+        let sv27 = gps.iter().find(|s| s.prn == 27).unwrap();
+        assert!(!sv27.used_in_fix);
+        assert_eq!(sv27.snr, None);
+
+        assert!(feed(
+            &mut view,
+            &["$GLGSV,1,1,02,67,48,278,40,68,37,336,38,1*79"]
+        ));
+        let glonass = view.snapshot(GnssSystem::Glonass);
+        assert_eq!(glonass.len(), 2);
+        assert!(glonass.iter().all(|s| s.used_in_fix));
+        assert_eq!(view.constellations().len(), 2);
+    }
+
+    #[test]
+    fn gsa_without_system_id() {
+        let mut view = SkyView::new();
+        feed(
+            &mut view,
+            &[
+                "$GNGSA,A,3,02,05,70,,,,,,,,,,2.5,1.3,2.1*2A",
+                "$GPGSV,1,1,02,02,45,120,38,05,12,310,21*77",
+                "$GLGSV,1,1,01,70,33,050,30*55",
+            ],
+        );
+        assert!(view.snapshot(GnssSystem::Gps).iter().all(|s| s.used_in_fix));
+        assert!(view.snapshot(GnssSystem::Glonass)[0].used_in_fix);
+    }
+
+    #[test]
+    fn constellation_no_longer_reported() {
+        let mut view = SkyView::new();
+        let cycle = [
+            "$GNGSA,A,3,02,05,70,,,,,,,,,,2.5,1.3,2.1*2A",
+            "$GPGSV,1,1,02,02,45,120,38,05,12,310,21*77",
+            "$GLGSV,1,1,01,70,33,050,30*55",
+        ];
+        feed(&mut view, &cycle);
+        feed(&mut view, &cycle);
+        assert_eq!(view.constellations().len(), 2);
+
+        // GLONASS is lost: it is kept until GPS completes another cycle
+        let gps_only = [
+            "$GNGSA,A,3,02,05,,,,,,,,,,,2.5,1.3,2.1*2D",
+            "$GPGSV,1,1,02,02,45,120,38,05,12,310,21*77",
+        ];
+        feed(&mut view, &gps_only);
+        assert_eq!(view.constellations().len(), 2);
+        feed(&mut view, &gps_only);
+        assert_eq!(view.constellations(), vec![GnssSystem::Gps]);
+        assert!(view.snapshot(GnssSystem::Gps).iter().all(|s| s.used_in_fix));
+
+        // GLONASS is back but not used in fix
+        feed(&mut view, &["$GLGSV,1,1,01,70,33,050,30*55"]);
+        assert!(!view.snapshot(GnssSystem::Glonass)[0].used_in_fix);
+
+        // No fix: GPS is not used after a report cycle without it
+        feed(&mut view, &["$GNGSA,A,1,,,,,,,,,,,,,,*2C"; 3]);
+        assert!(view
+            .snapshot(GnssSystem::Gps)
+            .iter()
+            .all(|s| !s.used_in_fix));
+    }
+
+    #[test]
+    fn navic_talker() {
+        let mut view = SkyView::new();
+        assert!(feed(&mut view, &["$GIGSV,1,1,01,05,40,100,35*57"]));
+        assert_eq!(view.snapshot(GnssSystem::NavIc).len(), 1);
+    }
+
+    #[test]
+    fn missing_parts_drop_group() {
+        let mut view = SkyView::new();
+        assert!(!feed(
+            &mut view,
+            &["$GPGSV,3,3,10,27,10,302,,16,04,036,,1*66"]
+        ));
+        assert!(!feed(
+            &mut view,
+            &[
+                "$GPGSV,3,1,10,23,38,230,44,29,71,156,47,07,29,116,41,08,09,081,36,1*62",
+                "$GPGSV,3,3,10,27,10,302,,16,04,036,,1*66",
+            ]
+        ));
+        assert!(view.constellations().is_empty());
+    }
+
+    #[test]
+    fn gn_talker_split_by_prn() {
+        let mut view = SkyView::new();
+        assert!(feed(
+            &mut view,
+            &["$GNGSV,1,1,03,02,45,120,38,05,12,310,21,70,33,050,30*59"]
+        ));
+        assert_eq!(view.snapshot(GnssSystem::Gps).len(), 2);
+        assert_eq!(view.snapshot(GnssSystem::Glonass)[0].prn, 70);
+    }
+
+    #[test]
+    fn new_group_replaces_previous() {
+        let mut view = SkyView::new();
+        feed(&mut view, &["$GPGSV,1,1,02,02,45,120,38,05,12,310,21*77"]);
+        feed(&mut view, &["$GPGSV,1,1,01,02,46,121,39*40"]);
+        let gps = view.snapshot(GnssSystem::Gps);
+        assert_eq!(gps.len(), 1);
+        assert_eq!(gps[0].elevation, Some(46));
+    }
+}
@@ -0,0 +1,27 @@
+//! Helpers shared by unit tests of sentence and payload decoders.
+
+use crate::{
+    messages::{AddrField, MessageFields},
+    parse_into,
+};
+
+/// Parses sentence into a new message, checks CRC and that the whole sentence is consumed.
+/// Returns message with talker id.
+pub(crate) fn parse_with_talker<T: MessageFields + Default>(sentence: &str) -> (T, String) {
+    let mut msg = T::default();
+    let (consumed, m) = parse_into(sentence.as_bytes(), &mut msg);
+    assert_eq!(consumed, sentence.len());
+    assert!(m.crc_ok, "CRC mismatch in {sentence}");
+    let talker_id = AddrField::new(m.addr_field).talker_id.to_string();
+    (msg, talker_id)
+}
+
+/// Parses sentence into a new message and checks CRC.
+pub(crate) fn parse<T: MessageFields + Default>(sentence: &str) -> T {
+    parse_with_talker(sentence).0
+}
+
+/// Asserts decoded value matches expected one within 1e-6.
+pub(crate) fn assert_near(value: Option<f64>, expected: f64) {
+    assert!((value.unwrap() - expected).abs() < 1e-6, "{value:?}");
+}
@@ -0,0 +1,161 @@
+//! Text messages: ^-escaped text fields and TXT messages assembled from several sentences.
+
+use std::{borrow::Cow, collections::HashMap};
+
+use crate::generated::nmea3::NmeaTxtMessage;
+
+/// Decodes reserved chars escaped as `^hh` (hex code). Invalid escapes are left as is.
+pub fn unescape(text: &str) -> Cow<'_, str> {
+    if !text.contains('^') {
+        return Cow::Borrowed(text);
+    }
+    let bytes = text.as_bytes();
+    let mut res = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'^' {
+            if let Some(c) = text
+                .get(i + 1..i + 3)
+                .and_then(|h| u8::from_str_radix(h, 16).ok())
+            {
+                res.push(char::from(c));
+                i += 3;
+                continue;
+            }
+        }
+        let c = text[i..].chars().next().unwrap();
+        res.push(c);
+        i += c.len_utf8();
+    }
+    Cow::Owned(res)
+}
+
+impl NmeaTxtMessage {
+    /// Text with escaped chars decoded.
+    pub fn decoded_text(&self) -> Option<Cow<'_, str>> {
+        self.text.as_deref().map(unescape)
+    }
+}
+
+/// Complete text message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextMessage {
+    /// Text identifier. For u-blox receivers: 0-error, 1-warning, 2-notice, 7-user.
+    pub id: Option<u32>,
+    pub text: String,
+}
+
+/// Pending multi-sentence text.
+#[derive(Debug)]
+struct PendingText {
+    text: String,
+    next_message: u32,
+}
+
+/// Assembles text messages from multi-sentence TXT groups.
+/// Groups with different text identifiers may be interleaved.
+#[derive(Debug, Default)]
+pub struct TextAssembler {
+    pending: HashMap<Option<u32>, PendingText>,
+}
+
+impl TextAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds TXT part. Returns text when the last part is received.
+    /// Parts received out of order drop the text being assembled.
+    pub fn update(&mut self, txt: &NmeaTxtMessage) -> Option<TextMessage> {
+        let total = txt.total_messages.unwrap_or(1);
+        let number = txt.message_number.unwrap_or(1);
+        let part = txt.decoded_text().unwrap_or_default();
+
+        if number == 1 {
+            self.pending.insert(
+                txt.text_id,
+                PendingText {
+                    text: String::new(),
+                    next_message: 1,
+                },
+            );
+        }
+        let pending = self.pending.get_mut(&txt.text_id)?;
+        if pending.next_message != number {
+            self.pending.remove(&txt.text_id);
+            return None;
+        }
+        pending.text.push_str(&part);
+        pending.next_message = number + 1;
+
+        if number >= total {
+            let pending = self.pending.remove(&txt.text_id)?;
+            return Some(TextMessage {
+                id: txt.text_id,
+                text: pending.text,
+            });
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generated::nmea3::NmeaTxtMessage, messages::MessageFields, test_util::parse};
+
+    use super::{unescape, TextAssembler, TextMessage};
+
+    #[test]
+    fn unescape_text() {
+        assert_eq!(unescape("ANTSTATUS=OK"), "ANTSTATUS=OK");
+        assert_eq!(unescape("A^2CB^2A^5e"), "A,B*^");
+        assert_eq!(unescape("100^B0C"), "100°C");
+        assert_eq!(unescape("bad ^G1 and end ^4"), "bad ^G1 and end ^4");
+    }
+
+    #[test]
+    fn single_sentence() {
+        let txt: NmeaTxtMessage = parse("$GPTXT,01,01,02,ANTSTATUS=OK*3B");
+        assert_eq!(txt.text_id, Some(2));
+        let mut assembler = TextAssembler::new();
+        assert_eq!(
+            assembler.update(&txt),
+            Some(TextMessage {
+                id: Some(2),
+                text: "ANTSTATUS=OK".to_string()
+            })
+        );
+        assert_eq!(txt.to_sentence("GP"), "$GPTXT,01,01,02,ANTSTATUS=OK*3B\r\n");
+    }
+
+    #[test]
+    fn multi_sentence() {
+        let mut assembler = TextAssembler::new();
+        assert_eq!(
+            assembler.update(&parse("$GNTXT,02,01,02,u-blox AG - www.u-blox.com*4D")),
+            None
+        );
+        assert_eq!(
+            assembler.update(&parse("$GNTXT,01,01,01,ANTENNA OPEN*3B")),
+            Some(TextMessage {
+                id: Some(1),
+                text: "ANTENNA OPEN".to_string()
+            })
+        );
+        assert_eq!(
+            assembler
+                .update(&parse("$GNTXT,02,02,02,^2C HW 00080000*6B"))
+                .unwrap()
+                .text,
+            "u-blox AG - www.u-blox.com, HW 00080000"
+        );
+    }
+
+    #[test]
+    fn out_of_order_dropped() {
+        let mut assembler = TextAssembler::new();
+        assert_eq!(assembler.update(&parse("$GPTXT,03,01,05,part 1*4E")), None);
+        assert_eq!(assembler.update(&parse("$GPTXT,03,03,05,part 3*4E")), None);
+        assert_eq!(assembler.update(&parse("$GPTXT,03,02,05,part 2*4E")), None);
+    }
+}
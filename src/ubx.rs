@@ -0,0 +1,288 @@
+//! u-blox proprietary PUBX messages: position, satellites and time decoding, configuration command builders.
+
+use crate::{
+    fields::{
+        field_enum, nmea_to_degrees, Distance, DistanceUnit, Speed, SpeedUnit, UbxNavStatus,
+        UbxPort, UbxProtocols,
+    },
+    generated::nmea3::{
+        NmeaPubx00Message, NmeaPubx03Message, NmeaPubx04Message, NmeaPubx40Message,
+        NmeaPubx41Message,
+    },
+};
+
+impl NmeaPubx00Message {
+    /// Position as (latitude, longitude) in decimal degrees.
+    pub fn position(&self) -> Option<(f64, f64)> {
+        Some((
+            nmea_to_degrees(self.latitude?, self.latitude_dir?),
+            nmea_to_degrees(self.longitude?, self.longitude_dir?),
+        ))
+    }
+
+    /// True if receiver reports a position fix.
+    pub fn has_fix(&self) -> bool {
+        !matches!(
+            self.nav_status,
+            None | Some(UbxNavStatus::NoFix) | Some(UbxNavStatus::TimeOnly)
+        )
+    }
+
+    /// Altitude above user datum ellipsoid.
+    pub fn altitude(&self) -> Option<Distance> {
+        Some(Distance::new(self.altitude?, DistanceUnit::Meters))
+    }
+
+    pub fn horizontal_accuracy(&self) -> Option<Distance> {
+        Some(Distance::new(self.h_acc?, DistanceUnit::Meters))
+    }
+
+    pub fn vertical_accuracy(&self) -> Option<Distance> {
+        Some(Distance::new(self.v_acc?, DistanceUnit::Meters))
+    }
+
+    pub fn speed(&self) -> Option<Speed> {
+        Some(Speed::new(self.speed?, SpeedUnit::KilometersPerHour))
+    }
+}
+
+field_enum! {
+    /// Satellite status (PUBX,03).
+    pub enum UbxSatStatus {
+        NotUsed = "-",
+        Used = "U",
+        /// Ephemeris available, but not used for navigation.
+        EphemerisAvailable = "e",
+    }
+}
+
+/// Satellite info from PUBX,03.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UbxSatellite {
+    pub id: u32,
+    pub status: Option<UbxSatStatus>,
+    /// Azimuth, degrees (0-359).
+    pub azimuth: Option<u32>,
+    /// Elevation, degrees (0-90).
+    pub elevation: Option<i32>,
+    /// Signal strength (C/N0), dB-Hz.
+    pub cno: Option<u32>,
+    /// Satellite carrier lock time, seconds. 0 - code lock only.
+    pub lock_time: Option<u32>,
+}
+
+impl NmeaPubx03Message {
+    /// Satellites listed in this message.
+    pub fn satellites(&self) -> Vec<UbxSatellite> {
+        self.satellites
+            .chunks_exact(6)
+            .filter_map(|s| {
+                Some(UbxSatellite {
+                    id: s[0].parse().ok()?,
+                    status: UbxSatStatus::from_code(s[1].as_bytes()),
+                    azimuth: s[2].parse().ok(),
+                    elevation: s[3].parse().ok(),
+                    cno: s[4].parse().ok(),
+                    lock_time: s[5].parse().ok(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl NmeaPubx04Message {
+    /// Leap seconds (GPS-UTC offset).
+    pub fn leap_seconds(&self) -> Option<i32> {
+        self.leap_seconds
+            .as_deref()?
+            .trim_end_matches('D')
+            .parse()
+            .ok()
+    }
+
+    /// True if leap seconds are the firmware default, not yet received from satellites.
+    pub fn is_leap_seconds_default(&self) -> bool {
+        self.leap_seconds
+            .as_deref()
+            .is_some_and(|l| l.ends_with('D'))
+    }
+}
+
+impl NmeaPubx40Message {
+    /// Command setting output rate of NMEA message (e.g. "GSV") on all ports.
+    /// Rate is relative to navigation solution rate, 0 disables the message.
+    pub fn set_rate(msg_id: &str, rate: u32) -> Self {
+        NmeaPubx40Message {
+            msg_id: Some(msg_id.to_string()),
+            rate_ddc: Some(rate),
+            rate_usart1: Some(rate),
+            rate_usart2: Some(rate),
+            rate_usb: Some(rate),
+            rate_spi: Some(rate),
+            reserved: Some(0),
+        }
+    }
+
+    /// Changes output rate on a single port.
+    pub fn with_port_rate(mut self, port: UbxPort, rate: u32) -> Self {
+        let field = match port {
+            UbxPort::Ddc => &mut self.rate_ddc,
+            UbxPort::Usart1 => &mut self.rate_usart1,
+            UbxPort::Usart2 => &mut self.rate_usart2,
+            UbxPort::Usb => &mut self.rate_usb,
+            UbxPort::Spi => &mut self.rate_spi,
+        };
+        *field = Some(rate);
+        self
+    }
+}
+
+impl NmeaPubx41Message {
+    /// Command setting port protocols and baudrate. Autobauding is disabled.
+    pub fn set_port(
+        port: UbxPort,
+        in_proto: UbxProtocols,
+        out_proto: UbxProtocols,
+        baudrate: u32,
+    ) -> Self {
+        NmeaPubx41Message {
+            port: Some(port),
+            in_proto: Some(in_proto),
+            out_proto: Some(out_proto),
+            baudrate: Some(baudrate),
+            autobauding: Some(0),
+        }
+    }
+
+    pub fn with_autobauding(mut self, enabled: bool) -> Self {
+        self.autobauding = Some(u32::from(enabled));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fields::{UbxNavStatus, UbxPort, UbxProtocols},
+        generated::nmea3::*,
+        messages::{AddrField, MessageFields, MessagesMap},
+        test_util::parse,
+    };
+
+    use super::{UbxSatStatus, UbxSatellite};
+
+    #[test]
+    fn pubx00() {
+        let pubx: NmeaPubx00Message = parse(
+            "$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F",
+        );
+        assert_eq!(pubx.utc.as_deref(), Some("081350.00"));
+        let (lat, lon) = pubx.position().unwrap();
+        assert!((lat - 47.2852202).abs() < 1e-6);
+        assert!((lon - 8.5652531).abs() < 1e-6);
+        assert_eq!(pubx.nav_status, Some(UbxNavStatus::Standalone3D));
+        assert!(pubx.has_fix());
+        assert_eq!(pubx.altitude().unwrap().meters(), 546.589);
+        assert_eq!(pubx.horizontal_accuracy().unwrap().meters(), 2.1);
+        assert!((pubx.speed().unwrap().meters_per_second() - 0.007 / 3.6).abs() < 1e-9);
+        assert_eq!(pubx.diff_age, None);
+        assert_eq!(pubx.satellites, Some(9));
+        assert_eq!(pubx.dr_used, Some(0));
+    }
+
+    #[test]
+    fn pubx03() {
+        let pubx: NmeaPubx03Message = parse(
+            "$PUBX,03,04,23,-,,,45,010,29,-,,,46,013,08,U,067,31,42,025,10,e,195,33,46,026*22",
+        );
+        assert_eq!(pubx.satellites_count, Some(4));
+        let sats = pubx.satellites();
+        assert_eq!(sats.len(), 4);
+        assert_eq!(
+            sats[0],
+            UbxSatellite {
+                id: 23,
+                status: Some(UbxSatStatus::NotUsed),
+                azimuth: None,
+                elevation: None,
+                cno: Some(45),
+                lock_time: Some(10),
+            }
+        );
+        assert_eq!(sats[2].status, Some(UbxSatStatus::Used));
+        assert_eq!(sats[2].azimuth, Some(67));
+        assert_eq!(sats[2].elevation, Some(31));
+        assert_eq!(sats[3].status, Some(UbxSatStatus::EphemerisAvailable));
+    }
+
+    #[test]
+    fn pubx04() {
+        let pubx: NmeaPubx04Message =
+            parse("$PUBX,04,073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43,*5D");
+        assert_eq!(pubx.date.as_deref(), Some("091202"));
+        assert_eq!(pubx.utc_tow, Some(113851.0));
+        assert_eq!(pubx.utc_week, Some(1196));
+        assert_eq!(pubx.leap_seconds(), Some(15));
+        assert!(pubx.is_leap_seconds_default());
+        assert_eq!(pubx.clock_drift, Some(-2660.664));
+        assert_eq!(pubx.tp_granularity, Some(43));
+    }
+
+    #[test]
+    fn sub_id_mismatch_is_skipped() {
+        let pubx: NmeaPubx04Message = parse("$PUBX,40,GLL,1,0,0,0,0,0*5D");
+        assert_eq!(pubx.utc, None);
+    }
+
+    #[test]
+    fn messages_map_dispatch() {
+        let mut map = MessagesMap::new();
+        map.add_all_messages();
+        let addr = AddrField::new(b"PUBX");
+        for (idx, field) in ["04", "073731.00", "091202"].iter().enumerate() {
+            assert!(map.set_field(&addr, idx as u8, field.as_bytes()));
+        }
+        let msg = map.get(&addr).unwrap();
+        assert_eq!(msg.message_type(), NmeaMessages::PUBX04);
+        let pubx = msg.as_any().downcast_ref::<NmeaPubx04Message>().unwrap();
+        assert_eq!(pubx.date.as_deref(), Some("091202"));
+
+        assert!(!map.set_field(&addr, 0, b"99"));
+        assert!(map.get(&addr).is_none());
+    }
+
+    #[test]
+    fn pubx40_builder() {
+        let cmd = NmeaPubx40Message::set_rate("GLL", 0).with_port_rate(UbxPort::Usart1, 1);
+        assert_eq!(cmd.to_sentence(""), "$PUBX,40,GLL,0,1,0,0,0,0*5D\r\n");
+        assert_eq!(
+            NmeaPubx40Message::set_rate("GSV", 5).to_sentence(""),
+            "$PUBX,40,GSV,5,5,5,5,5,0*5C\r\n"
+        );
+
+        let parsed: NmeaPubx40Message = parse("$PUBX,40,GLL,1,0,0,0,0,0*5D");
+        assert_eq!(parsed.msg_id.as_deref(), Some("GLL"));
+        assert_eq!(parsed.rate_ddc, Some(1));
+    }
+
+    #[test]
+    fn pubx41_builder() {
+        let cmd = NmeaPubx41Message::set_port(
+            UbxPort::Usart1,
+            UbxProtocols::UBX | UbxProtocols::NMEA | UbxProtocols::RTCM,
+            UbxProtocols::UBX | UbxProtocols::NMEA,
+            19200,
+        );
+        assert_eq!(cmd.to_sentence(""), "$PUBX,41,1,0007,0003,19200,0*25\r\n");
+
+        let parsed: NmeaPubx41Message = parse("$PUBX,41,1,0007,0003,19200,0*25");
+        assert_eq!(parsed.port, Some(UbxPort::Usart1));
+        assert!(parsed.in_proto.unwrap().contains(UbxProtocols::RTCM));
+        assert!(!parsed.out_proto.unwrap().contains(UbxProtocols::RTCM));
+        assert_eq!(parsed.baudrate, Some(19200));
+        assert_eq!(
+            parsed.with_autobauding(true).to_sentence(""),
+            "$PUBX,41,1,0007,0003,19200,1*24\r\n"
+        );
+    }
+}
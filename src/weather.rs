@@ -0,0 +1,266 @@
+//! Wind, weather and generic transducer helpers for MWV, MWD, MDA, MTW and XDR messages.
+
+use crate::{
+    fields::{DataStatus, Pressure, Speed, Temperature, TemperatureUnit, WindReference},
+    generated::nmea3::{
+        NmeaMdaMessage, NmeaMtwMessage, NmeaMwdMessage, NmeaMwvMessage, NmeaXdrMessage,
+    },
+};
+
+impl NmeaMwvMessage {
+    /// Wind speed if status is valid.
+    pub fn speed(&self) -> Option<Speed> {
+        if self.status != Some(DataStatus::Valid) {
+            return None;
+        }
+        Speed::from_fields(self.speed, self.speed_unit)
+    }
+
+    /// True for apparent (relative to vessel) wind.
+    pub fn is_apparent(&self) -> bool {
+        self.reference == Some(WindReference::Relative)
+    }
+}
+
+impl NmeaMwdMessage {
+    /// Wind speed. Knots are preferred if both speeds are present.
+    pub fn speed(&self) -> Option<Speed> {
+        Speed::from_fields(self.speed_knots, self.speed_knots_unit)
+            .or(Speed::from_fields(self.speed_ms, self.speed_ms_unit))
+    }
+}
+
+impl NmeaMdaMessage {
+    /// Barometric pressure. Bars are preferred if both pressures are present.
+    pub fn pressure(&self) -> Option<Pressure> {
+        Pressure::from_fields(self.pressure_bar, self.pressure_bar_unit).or(Pressure::from_fields(
+            self.pressure_inhg,
+            self.pressure_inhg_unit,
+        ))
+    }
+
+    pub fn air_temperature(&self) -> Option<Temperature> {
+        Temperature::from_fields(self.air_temperature, self.air_temperature_unit)
+    }
+
+    pub fn water_temperature(&self) -> Option<Temperature> {
+        Temperature::from_fields(self.water_temperature, self.water_temperature_unit)
+    }
+
+    pub fn dew_point(&self) -> Option<Temperature> {
+        Temperature::from_fields(self.dew_point, self.dew_point_unit)
+    }
+
+    /// Wind speed. Knots are preferred if both speeds are present.
+    pub fn wind_speed(&self) -> Option<Speed> {
+        Speed::from_fields(self.wind_speed_knots, self.wind_speed_knots_unit).or(
+            Speed::from_fields(self.wind_speed_ms, self.wind_speed_ms_unit),
+        )
+    }
+}
+
+impl NmeaMtwMessage {
+    pub fn temperature(&self) -> Option<Temperature> {
+        Temperature::from_fields(self.temperature, self.temperature_unit)
+    }
+}
+
+crate::fields::field_enum! {
+    /// Transducer type (XDR).
+    pub enum TransducerType {
+        Angular = "A",
+        Temperature = "C",
+        Linear = "D",
+        Frequency = "F",
+        Generic = "G",
+        Humidity = "H",
+        Current = "I",
+        Force = "N",
+        Pressure = "P",
+        FlowRate = "R",
+        Switch = "S",
+        Tachometer = "T",
+        Voltage = "U",
+        Volume = "V",
+    }
+}
+
+/// Unit of transducer measurement. Unit codes depend on transducer type (e.g. 'P' is percent for humidity and pascal for pressure).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransducerUnit {
+    Degrees,
+    Celsius,
+    Meters,
+    Hertz,
+    Percent,
+    Amperes,
+    Newtons,
+    Bars,
+    Pascals,
+    LitersPerSecond,
+    Rpm,
+    Volts,
+    CubicMeters,
+    /// Unit not defined for the type or empty.
+    None,
+}
+
+impl TransducerUnit {
+    pub fn from_code(kind: TransducerType, code: &str) -> Self {
+        match (kind, code) {
+            (TransducerType::Angular, "D") => TransducerUnit::Degrees,
+            (TransducerType::Temperature, "C") => TransducerUnit::Celsius,
+            (TransducerType::Linear, "M") => TransducerUnit::Meters,
+            (TransducerType::Frequency, "H") => TransducerUnit::Hertz,
+            (TransducerType::Humidity, "P") => TransducerUnit::Percent,
+            (TransducerType::Current, "A") => TransducerUnit::Amperes,
+            (TransducerType::Force, "N") => TransducerUnit::Newtons,
+            (TransducerType::Pressure, "B") => TransducerUnit::Bars,
+            (TransducerType::Pressure, "P") => TransducerUnit::Pascals,
+            (TransducerType::FlowRate, "l") => TransducerUnit::LitersPerSecond,
+            (TransducerType::Tachometer, "R") => TransducerUnit::Rpm,
+            (TransducerType::Voltage, "V") => TransducerUnit::Volts,
+            (TransducerType::Volume, "M") => TransducerUnit::CubicMeters,
+            _ => TransducerUnit::None,
+        }
+    }
+}
+
+/// Single transducer measurement from XDR.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransducerReading {
+    pub kind: TransducerType,
+    pub value: Option<f64>,
+    pub unit: TransducerUnit,
+    /// Transducer name (e.g. 'TempAir' or 'Barometer').
+    pub name: String,
+}
+
+impl TransducerReading {
+    /// Temperature reading as a typed quantity.
+    pub fn temperature(&self) -> Option<Temperature> {
+        match self.unit {
+            TransducerUnit::Celsius => {
+                Some(Temperature::new(self.value?, TemperatureUnit::Celsius))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl NmeaXdrMessage {
+    /// Transducer readings. Groups with unknown transducer type are skipped.
+    pub fn readings(&self) -> Vec<TransducerReading> {
+        self.measurements
+            .chunks_exact(4)
+            .filter_map(|m| {
+                let kind = TransducerType::from_code(m[0].as_bytes())?;
+                Some(TransducerReading {
+                    kind,
+                    value: m[1].parse().ok(),
+                    unit: TransducerUnit::from_code(kind, &m[2]),
+                    name: m[3].clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Reading by transducer name.
+    pub fn reading(&self, name: &str) -> Option<TransducerReading> {
+        self.readings().into_iter().find(|r| r.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fields::{PressureUnit, Speed, SpeedUnit},
+        generated::nmea3::{
+            NmeaMdaMessage, NmeaMtwMessage, NmeaMwdMessage, NmeaMwvMessage, NmeaXdrMessage,
+        },
+        test_util::parse,
+    };
+
+    use super::{TransducerType, TransducerUnit};
+
+    #[test]
+    fn mwv_apparent_wind() {
+        let mwv: NmeaMwvMessage = parse("$WIMWV,214.8,R,0.1,K,A*28");
+        assert_eq!(mwv.angle, Some(214.8));
+        assert!(mwv.is_apparent());
+        assert_eq!(
+            mwv.speed(),
+            Some(Speed::new(0.1, SpeedUnit::KilometersPerHour))
+        );
+    }
+
+    #[test]
+    fn mwv_invalid() {
+        let mwv: NmeaMwvMessage = parse("$IIMWV,017,T,12.5,N,V*02");
+        assert!(!mwv.is_apparent());
+        assert_eq!(mwv.speed_unit, Some(SpeedUnit::Knots));
+        assert_eq!(mwv.speed(), None);
+    }
+
+    #[test]
+    fn mwd() {
+        let mwd: NmeaMwdMessage = parse("$WIMWD,10.1,T,10.1,M,12,N,40,M*5D");
+        assert_eq!(mwd.direction_true, Some(10.1));
+        assert_eq!(mwd.speed(), Some(Speed::new(12.0, SpeedUnit::Knots)));
+        assert_eq!(mwd.speed_ms_unit, Some(SpeedUnit::MetersPerSecond));
+    }
+
+    #[test]
+    fn mda() {
+        let mda: NmeaMdaMessage =
+            parse("$WIMDA,29.7544,I,1.0076,B,35.5,C,,,42.1,,20.6,C,116.4,T,107.7,M,1.2,N,0.6,M*66");
+        let p = mda.pressure().unwrap();
+        assert_eq!(p.unit, PressureUnit::Bars);
+        assert!((p.pascals() - 100_760.0).abs() < 1e-6);
+        assert!((p.to(PressureUnit::InchesOfMercury) - 29.7544).abs() < 1e-3);
+        assert_eq!(mda.air_temperature().unwrap().celsius(), 35.5);
+        assert_eq!(mda.water_temperature(), None);
+        assert_eq!(mda.relative_humidity, Some(42.1));
+        assert_eq!(mda.absolute_humidity, None);
+        assert_eq!(mda.dew_point().unwrap().celsius(), 20.6);
+        assert_eq!(mda.wind_direction_magnetic, Some(107.7));
+        assert_eq!(mda.wind_speed(), Some(Speed::new(1.2, SpeedUnit::Knots)));
+    }
+
+    #[test]
+    fn mtw() {
+        let mtw: NmeaMtwMessage = parse("$YXMTW,15.2,C*14");
+        assert_eq!(mtw.temperature().unwrap().celsius(), 15.2);
+    }
+
+    #[test]
+    fn xdr_readings() {
+        let xdr: NmeaXdrMessage =
+            parse("$IIXDR,C,19.52,C,TempAir,P,1.02481,B,Barometer,H,65.2,P,Humidity*40");
+        let readings = xdr.readings();
+        assert_eq!(readings.len(), 3);
+        assert_eq!(readings[0].kind, TransducerType::Temperature);
+        assert_eq!(readings[0].unit, TransducerUnit::Celsius);
+        assert_eq!(readings[0].temperature().unwrap().celsius(), 19.52);
+        assert_eq!(readings[1].kind, TransducerType::Pressure);
+        assert_eq!(readings[1].value, Some(1.02481));
+        assert_eq!(readings[1].unit, TransducerUnit::Bars);
+        assert_eq!(readings[1].name, "Barometer");
+        let humidity = xdr.reading("Humidity").unwrap();
+        assert_eq!(humidity.unit, TransducerUnit::Percent);
+        assert_eq!(humidity.temperature(), None);
+    }
+
+    #[test]
+    fn xdr_attitude() {
+        let xdr: NmeaXdrMessage = parse("$YXXDR,A,-1.8,D,PITCH,A,0.9,D,ROLL,G,,,ENGINE#0*63");
+        let readings = xdr.readings();
+        assert_eq!(readings.len(), 3);
+        assert_eq!(readings[0].value, Some(-1.8));
+        assert_eq!(readings[0].unit, TransducerUnit::Degrees);
+        assert_eq!(readings[1].name, "ROLL");
+        assert_eq!(readings[2].kind, TransducerType::Generic);
+        assert_eq!(readings[2].value, None);
+        assert_eq!(readings[2].unit, TransducerUnit::None);
+    }
+}